@@ -1,8 +1,456 @@
+use std::{cmp::Ordering, collections::HashSet};
+
 use happs_integrity::*;
 use hdk::prelude::*;
 
+use crate::{
+    happ::{get_latest_happ, get_original_happ},
+    happ_unpublished::{retain_listed_happs, CatalogQueryOptions},
+};
+
+pub const MAX_HAPPS_PAGE_SIZE: u32 = 100;
+
+fn all_happs_paths() -> Vec<Path> {
+    let mut paths: Vec<Path> = (0..ALL_HAPPS_SHARDS)
+        .map(|shard| Path::from(format!("all_happs.{shard}")))
+        .collect();
+    paths.push(Path::from(LEGACY_ALL_HAPPS_PATH));
+    paths
+}
+
+/// Returns the catalog links, leaving out unpublished hApps unless asked otherwise
 #[hdk_extern]
-pub fn get_all_happs() -> ExternResult<Vec<Link>> {
-    let path = Path::from("all_happs");
-    get_links(GetLinksInputBuilder::try_new(path.path_entry_hash()?, LinkTypes::AllHapps)?.build())
+pub fn get_all_happs(options: Option<CatalogQueryOptions>) -> ExternResult<Vec<Link>> {
+    let links = get_all_happs_links()?;
+    retain_listed_happs(links, options.unwrap_or_default().include_unpublished)
+}
+
+/// Returns the catalog links from all the shards, including the ones for unpublished hApps
+pub fn get_all_happs_links() -> ExternResult<Vec<Link>> {
+    let inputs = all_happs_paths()
+        .into_iter()
+        .map(|path| {
+            Ok(
+                GetLinksInputBuilder::try_new(path.path_entry_hash()?, LinkTypes::AllHapps)?
+                    .build(),
+            )
+        })
+        .collect::<ExternResult<Vec<GetLinksInput>>>()?;
+    let links = HDK.with(|hdk| hdk.borrow().get_links(inputs))?;
+    Ok(links.into_iter().flatten().collect())
+}
+
+/// Moves the catalog link for the given hApp from the legacy anchor to its shard, if it was created by us
+pub fn migrate_legacy_all_happs_link(happ_hash: &ActionHash) -> ExternResult<()> {
+    let my_pub_key = agent_info()?.agent_initial_pubkey;
+    let legacy_path = Path::from(LEGACY_ALL_HAPPS_PATH);
+    let links = get_links(
+        GetLinksInputBuilder::try_new(legacy_path.path_entry_hash()?, LinkTypes::AllHapps)?.build(),
+    )?;
+    for link in links {
+        if link.author.ne(&my_pub_key)
+            || link.target.clone().into_action_hash().as_ref() != Some(happ_hash)
+        {
+            continue;
+        }
+        create_link(
+            all_happs_shard_path(happ_hash).path_entry_hash()?,
+            happ_hash.clone(),
+            LinkTypes::AllHapps,
+            (),
+        )?;
+        delete_link(link.create_link_hash)?;
+    }
+    Ok(())
+}
+
+/// Number of buckets of a sort index whose links are fetched at once when getting a page
+const SORT_INDEX_BUCKETS_PER_FETCH: usize = 4;
+
+fn get_sort_index_links(paths: &[Path]) -> ExternResult<Vec<Vec<Link>>> {
+    let inputs = paths
+        .iter()
+        .map(|path| {
+            Ok(
+                GetLinksInputBuilder::try_new(path.path_entry_hash()?, LinkTypes::HappsSortIndex)?
+                    .build(),
+            )
+        })
+        .collect::<ExternResult<Vec<GetLinksInput>>>()?;
+    HDK.with(|hdk| hdk.borrow().get_links(inputs))
+}
+
+fn get_sort_index_bucket_links(sort_key: HappsSortKey) -> ExternResult<Vec<Link>> {
+    get_links(
+        GetLinksInputBuilder::try_new(
+            happ_sort_index_buckets_path(sort_key).path_entry_hash()?,
+            LinkTypes::HappsSortIndexBuckets,
+        )?
+        .build(),
+    )
+}
+
+/// Returns the time buckets of the sort index that have hApps in them, from newest to oldest
+fn get_sort_index_time_buckets(sort_key: HappsSortKey) -> ExternResult<Vec<String>> {
+    let mut buckets: Vec<i64> = get_sort_index_bucket_links(sort_key)?
+        .into_iter()
+        .filter_map(|link| String::from_utf8(link.tag.into_inner()).ok()?.parse().ok())
+        .collect();
+    buckets.sort_by(|a, b| b.cmp(a));
+    buckets.dedup();
+    Ok(buckets
+        .into_iter()
+        .map(|bucket| bucket.to_string())
+        .collect())
+}
+
+fn ensure_sort_index_bucket_link(sort_key: HappsSortKey, bucket: i64) -> ExternResult<()> {
+    let tag = LinkTag::new(bucket.to_string());
+    if get_sort_index_bucket_links(sort_key)?
+        .iter()
+        .any(|link| link.tag.eq(&tag))
+    {
+        return Ok(());
+    }
+    create_link(
+        happ_sort_index_buckets_path(sort_key).path_entry_hash()?,
+        happ_sort_index_path(sort_key, &bucket.to_string()).path_entry_hash()?,
+        LinkTypes::HappsSortIndexBuckets,
+        tag,
+    )?;
+    Ok(())
+}
+
+/// Returns the values the hApp is currently sorted by, read from its revisions and releases
+///
+/// The last update and latest release times are the times of the latest HappUpdates and HappToHappReleases links
+pub fn happ_sort_values(happ_hash: &ActionHash) -> ExternResult<Vec<HappSortIndexTag>> {
+    let original_happ = get_original_happ(happ_hash.clone())?.ok_or(wasm_error!(
+        WasmErrorInner::Guest("Could not find the Happ".to_string())
+    ))?;
+    let created_at = original_happ.action().timestamp();
+    let latest_link_timestamp = |link_type: LinkTypes| -> ExternResult<Timestamp> {
+        let links =
+            get_links(GetLinksInputBuilder::try_new(happ_hash.clone(), link_type)?.build())?;
+        Ok(links
+            .into_iter()
+            .map(|link| link.timestamp)
+            .max()
+            .unwrap_or(created_at))
+    };
+    let latest_happ = match get_latest_happ(happ_hash.clone())? {
+        Some(record) => Happ::try_from(record)?,
+        None => Happ::try_from(original_happ)?,
+    };
+    Ok(vec![
+        HappSortIndexTag {
+            sort_key: HappsSortKey::CreatedTime,
+            sort_value: HappSortValue::Timestamp(created_at),
+        },
+        HappSortIndexTag {
+            sort_key: HappsSortKey::LastUpdated,
+            sort_value: HappSortValue::Timestamp(latest_link_timestamp(LinkTypes::HappUpdates)?),
+        },
+        HappSortIndexTag {
+            sort_key: HappsSortKey::LatestReleaseTime,
+            sort_value: HappSortValue::Timestamp(latest_link_timestamp(
+                LinkTypes::HappToHappReleases,
+            )?),
+        },
+        HappSortIndexTag {
+            sort_key: HappsSortKey::Name,
+            sort_value: HappSortValue::name(&latest_happ.name),
+        },
+    ])
+}
+
+/// Makes the sort index point to the hApp with its current value, removing the link with its previous value
+///
+/// Like the search index, the link is created if it's missing even if the value didn't change, so that
+/// the hApps created before the sort indexes existed are indexed the next time they are updated
+fn index_happ_sort_value(
+    happ_hash: &ActionHash,
+    previous: Option<&HappSortIndexTag>,
+    current: &HappSortIndexTag,
+) -> ExternResult<()> {
+    let current_path = current.path();
+    let mut paths = vec![current_path.clone()];
+    if let Some(previous) = previous {
+        if previous.path().ne(&current_path) {
+            paths.push(previous.path());
+        }
+    }
+    let current_base: AnyLinkableHash = current_path.path_entry_hash()?.into();
+
+    let mut indexed = false;
+    for link in get_sort_index_links(&paths)?.into_iter().flatten() {
+        if link.target.clone().into_action_hash().as_ref() != Some(happ_hash) {
+            continue;
+        }
+        let is_current = HappSortIndexTag::from_link_tag(&link.tag).as_ref() == Some(current)
+            && link.base == current_base;
+        if is_current && !indexed {
+            indexed = true;
+        } else {
+            delete_link(link.create_link_hash)?;
+        }
+    }
+    if !indexed {
+        if let HappSortValue::Timestamp(timestamp) = &current.sort_value {
+            ensure_sort_index_bucket_link(current.sort_key, timestamp_bucket(timestamp))?;
+        }
+        create_link(
+            current_path.path_entry_hash()?,
+            happ_hash.clone(),
+            LinkTypes::HappsSortIndex,
+            current.link_tag()?,
+        )?;
+    }
+    Ok(())
+}
+
+/// Makes the sort indexes point to the hApp with the values that changed, given the values it had before the
+/// change that is being indexed
+///
+/// Validation derives the values from our own source chain, so only the values that our change set can be indexed
+pub fn index_happ_sort_values(
+    happ_hash: &ActionHash,
+    previous_values: &[HappSortIndexTag],
+    current_values: &[HappSortIndexTag],
+) -> ExternResult<()> {
+    for current in current_values {
+        let previous = previous_values
+            .iter()
+            .find(|previous| previous.sort_key == current.sort_key);
+        index_happ_sort_value(happ_hash, previous, current)?;
+    }
+    Ok(())
+}
+
+/// Removes the links from the sort indexes to the hApp
+pub fn unindex_happ_sort_values(happ_hash: &ActionHash) -> ExternResult<()> {
+    let paths: Vec<Path> = happ_sort_values(happ_hash)?
+        .iter()
+        .map(|tag| tag.path())
+        .collect();
+    for link in get_sort_index_links(&paths)?.into_iter().flatten() {
+        if link.target.clone().into_action_hash().as_ref() == Some(happ_hash) {
+            delete_link(link.create_link_hash)?;
+        }
+    }
+    Ok(())
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct HappsPageCursor {
+    pub happ_hash: ActionHash,
+    pub sort_value: HappSortValue,
+    /// Whether the page ended among the hApps that are missing from the sort index
+    #[serde(default)]
+    pub unindexed: bool,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct GetHappsPageInput {
+    pub sort_key: HappsSortKey,
+    pub page_size: u32,
+    pub cursor: Option<HappsPageCursor>,
+    #[serde(default)]
+    pub include_unpublished: bool,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct HappsPageItem {
+    pub original_happ_hash: ActionHash,
+    pub latest_happ: Record,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct HappsPage {
+    pub happs: Vec<HappsPageItem>,
+    pub next_cursor: Option<HappsPageCursor>,
+}
+
+/// Returns one page of the catalog, sorted by the given key
+///
+/// Timestamps are sorted from newest to oldest, and names alphabetically, with the names that don't start with
+/// an ASCII letter or digit last. Pass the `next_cursor` of a page to get the following one
+///
+/// The page is read from the buckets of the sort index in order, starting from the bucket of the cursor, and
+/// only until one more hApp than the page size is found. The hApps that are in the catalog but not in the sort
+/// index, like the ones created before it existed and not updated since, are listed after all the indexed ones,
+/// from the newest to the oldest catalog link
+#[hdk_extern]
+pub fn get_happs_page(input: GetHappsPageInput) -> ExternResult<HappsPage> {
+    if input.page_size == 0 || input.page_size > MAX_HAPPS_PAGE_SIZE {
+        return Err(wasm_error!(WasmErrorInner::Guest(format!(
+            "Page size must be between 1 and {MAX_HAPPS_PAGE_SIZE}"
+        ))));
+    }
+    let page_size = input.page_size as usize;
+
+    let mut happs = match &input.cursor {
+        Some(cursor) if cursor.unindexed => vec![],
+        _ => get_indexed_happs(&input, page_size + 1)?,
+    };
+    if happs.len() <= page_size {
+        let seen_happs: HashSet<ActionHash> =
+            happs.iter().map(|happ| happ.happ_hash.clone()).collect();
+        let unindexed_happs = get_unindexed_happs(input.sort_key, input.include_unpublished)?
+            .into_iter()
+            .filter(|happ| !seen_happs.contains(&happ.happ_hash))
+            .filter(|happ| match &input.cursor {
+                Some(cursor) if cursor.unindexed => compare_happs(happ, cursor).is_gt(),
+                _ => true,
+            });
+        happs.extend(unindexed_happs);
+    }
+
+    let next_cursor = match happs.len() > page_size {
+        true => happs.get(page_size - 1).cloned(),
+        false => None,
+    };
+    let mut page = vec![];
+    for happ in happs.into_iter().take(page_size) {
+        if let Some(latest_happ) = get_latest_happ(happ.happ_hash.clone())? {
+            page.push(HappsPageItem {
+                original_happ_hash: happ.happ_hash,
+                latest_happ,
+            });
+        }
+    }
+
+    Ok(HappsPage {
+        happs: page,
+        next_cursor,
+    })
+}
+
+/// Returns the hApps from the sort index that come after the cursor, in order, stopping once at least `limit`
+/// are found
+fn get_indexed_happs(
+    input: &GetHappsPageInput,
+    limit: usize,
+) -> ExternResult<Vec<HappsPageCursor>> {
+    let mut buckets = match input.sort_key {
+        HappsSortKey::Name => name_sort_index_buckets(),
+        sort_key => get_sort_index_time_buckets(sort_key)?,
+    };
+    if let Some(cursor) = &input.cursor {
+        let cursor_bucket = sort_index_bucket(&cursor.sort_value);
+        // Time buckets are sorted from newest to oldest, so the first one not newer than the cursor
+        let start = buckets
+            .iter()
+            .position(|bucket| match input.sort_key {
+                HappsSortKey::Name => bucket.eq(&cursor_bucket),
+                _ => bucket.parse::<i64>().ok() <= cursor_bucket.parse::<i64>().ok(),
+            })
+            .unwrap_or(buckets.len());
+        buckets.drain(..start);
+    }
+
+    let mut seen_happs = HashSet::new();
+    let mut happs: Vec<HappsPageCursor> = vec![];
+    for buckets in buckets.chunks(SORT_INDEX_BUCKETS_PER_FETCH) {
+        if happs.len() >= limit {
+            break;
+        }
+        let paths: Vec<Path> = buckets
+            .iter()
+            .map(|bucket| happ_sort_index_path(input.sort_key, bucket))
+            .collect();
+        let links: Vec<Link> = get_sort_index_links(&paths)?
+            .into_iter()
+            .flatten()
+            .collect();
+        let links = retain_listed_happs(links, input.include_unpublished)?;
+        let mut bucket_happs: Vec<HappsPageCursor> = links
+            .into_iter()
+            .filter_map(|link| {
+                let tag = HappSortIndexTag::from_link_tag(&link.tag)?;
+                Some(HappsPageCursor {
+                    happ_hash: link.target.into_action_hash()?,
+                    sort_value: tag.sort_value,
+                    unindexed: false,
+                })
+            })
+            .filter(|happ| match &input.cursor {
+                Some(cursor) => compare_happs(happ, cursor).is_gt(),
+                None => true,
+            })
+            .collect();
+        bucket_happs.sort_by(compare_happs);
+        // Concurrent updates by different maintainers can leave more than one link for the same hApp
+        happs.extend(
+            bucket_happs
+                .into_iter()
+                .filter(|happ| seen_happs.insert(happ.happ_hash.clone())),
+        );
+    }
+    Ok(happs)
+}
+
+/// Returns the hApps in the catalog that are missing from the sort index for the given key, sorted from the
+/// newest to the oldest catalog link
+///
+/// This reads all the buckets of the sort index, so it's only done once the indexed hApps run out
+fn get_unindexed_happs(
+    sort_key: HappsSortKey,
+    include_unpublished: bool,
+) -> ExternResult<Vec<HappsPageCursor>> {
+    let buckets = match sort_key {
+        HappsSortKey::Name => name_sort_index_buckets(),
+        sort_key => get_sort_index_time_buckets(sort_key)?,
+    };
+    let paths: Vec<Path> = buckets
+        .iter()
+        .map(|bucket| happ_sort_index_path(sort_key, bucket))
+        .collect();
+    let indexed_happs: HashSet<ActionHash> = get_sort_index_links(&paths)?
+        .into_iter()
+        .flatten()
+        .filter_map(|link| link.target.into_action_hash())
+        .collect();
+
+    let links: Vec<Link> = get_all_happs_links()?
+        .into_iter()
+        .filter(|link| {
+            link.target
+                .clone()
+                .into_action_hash()
+                .is_some_and(|happ_hash| !indexed_happs.contains(&happ_hash))
+        })
+        .collect();
+    let links = retain_listed_happs(links, include_unpublished)?;
+    let mut happs: Vec<HappsPageCursor> = links
+        .into_iter()
+        .filter_map(|link| {
+            Some(HappsPageCursor {
+                happ_hash: link.target.into_action_hash()?,
+                sort_value: HappSortValue::Timestamp(link.timestamp),
+                unindexed: true,
+            })
+        })
+        .collect();
+    happs.sort_by(compare_happs);
+    // A hApp being migrated from the legacy anchor can have a link in both, keep the newest one
+    let mut seen_happs = HashSet::new();
+    happs.retain(|happ| seen_happs.insert(happ.happ_hash.clone()));
+    Ok(happs)
+}
+
+fn compare_happs(a: &HappsPageCursor, b: &HappsPageCursor) -> Ordering {
+    let by_value = match (&a.sort_value, &b.sort_value) {
+        (HappSortValue::Timestamp(a), HappSortValue::Timestamp(b)) => b.cmp(a),
+        (HappSortValue::Name(name_a), HappSortValue::Name(name_b)) => {
+            let is_other =
+                |sort_value: &HappSortValue| sort_index_bucket(sort_value).eq(OTHER_NAMES_BUCKET);
+            is_other(&a.sort_value)
+                .cmp(&is_other(&b.sort_value))
+                .then_with(|| name_a.cmp(name_b))
+        }
+        (HappSortValue::Timestamp(_), HappSortValue::Name(_)) => Ordering::Less,
+        (HappSortValue::Name(_), HappSortValue::Timestamp(_)) => Ordering::Greater,
+    };
+    by_value.then_with(|| a.happ_hash.cmp(&b.happ_hash))
 }
@@ -0,0 +1,66 @@
+use happs_integrity::*;
+use hdk::prelude::*;
+
+/// Signs and publishes a claim for the given domain
+///
+/// The claim is only trusted by clients once the domain serves its signature in its `.well-known` file
+#[hdk_extern]
+pub fn claim_domain(domain: String) -> ExternResult<Record> {
+    let my_pub_key = agent_info()?.agent_initial_pubkey;
+    let signature = sign(
+        my_pub_key.clone(),
+        DomainClaimPayload {
+            domain: domain.clone(),
+            agent: my_pub_key.clone(),
+        },
+    )?;
+    let domain_claim_hash =
+        create_entry(&EntryTypes::DomainClaim(DomainClaim { domain, signature }))?;
+    create_link(
+        my_pub_key,
+        domain_claim_hash.clone(),
+        LinkTypes::PublisherDomainClaims,
+        (),
+    )?;
+    let record = get(domain_claim_hash, GetOptions::default())?.ok_or(wasm_error!(
+        WasmErrorInner::Guest("Could not find the newly created DomainClaim".to_string())
+    ))?;
+    Ok(record)
+}
+
+/// Returns the domain claims of the given agent that weren't deleted
+#[hdk_extern]
+pub fn get_domain_claims(agent: AgentPubKey) -> ExternResult<Vec<Record>> {
+    let links =
+        get_links(GetLinksInputBuilder::try_new(agent, LinkTypes::PublisherDomainClaims)?.build())?;
+    let get_input: Vec<GetInput> = links
+        .into_iter()
+        .map(|link| {
+            Ok(GetInput::new(
+                link.target
+                    .into_action_hash()
+                    .ok_or(wasm_error!(WasmErrorInner::Guest(
+                        "No action hash associated with link".to_string()
+                    )))?
+                    .into(),
+                GetOptions::default(),
+            ))
+        })
+        .collect::<ExternResult<Vec<GetInput>>>()?;
+    let records = HDK.with(|hdk| hdk.borrow().get(get_input))?;
+    Ok(records.into_iter().flatten().collect())
+}
+
+#[hdk_extern]
+pub fn delete_domain_claim(domain_claim_hash: ActionHash) -> ExternResult<ActionHash> {
+    let my_pub_key = agent_info()?.agent_initial_pubkey;
+    let links = get_links(
+        GetLinksInputBuilder::try_new(my_pub_key, LinkTypes::PublisherDomainClaims)?.build(),
+    )?;
+    for link in links {
+        if link.target.clone().into_action_hash().as_ref() == Some(&domain_claim_hash) {
+            delete_link(link.create_link_hash)?;
+        }
+    }
+    delete_entry(domain_claim_hash)
+}
@@ -1,13 +1,29 @@
 use happs_integrity::*;
 use hdk::prelude::*;
 
+use crate::{
+    all_happs::{
+        get_all_happs_links, happ_sort_values, index_happ_sort_values,
+        migrate_legacy_all_happs_link, unindex_happ_sort_values,
+    },
+    happ_categories::{index_happ, unindex_happ},
+    maintainers::ensure_maintainer_claim,
+    ownership::get_happ_owner,
+    publisher_credential::ensure_can_publish,
+    revision_heads::{check_is_canonical_head, resolve_revision_heads, RevisionHeads},
+    search::{index_happ_search, unindex_happ_search},
+    stored_files::check_icon_is_stored,
+};
+
 #[hdk_extern]
 pub fn create_happ(happ: Happ) -> ExternResult<Record> {
+    check_icon_is_stored(happ.icon.clone())?;
+    ensure_can_publish()?;
     let happ_hash = create_entry(&EntryTypes::Happ(happ.clone()))?;
     let record = get(happ_hash.clone(), GetOptions::default())?.ok_or(wasm_error!(
         WasmErrorInner::Guest("Could not find the newly created Happ".to_string())
     ))?;
-    let path = Path::from("all_happs");
+    let path = all_happs_shard_path(&happ_hash);
     create_link(
         path.path_entry_hash()?,
         happ_hash.clone(),
@@ -21,29 +37,27 @@ pub fn create_happ(happ: Happ) -> ExternResult<Record> {
         LinkTypes::PublisherHapps,
         (),
     )?;
+    index_happ(&happ_hash, None, &happ)?;
+    index_happ_search(&happ_hash, None, &happ)?;
+    index_happ_sort_values(&happ_hash, &[], &happ_sort_values(&happ_hash)?)?;
     Ok(record)
 }
 
 #[hdk_extern]
 pub fn get_latest_happ(original_happ_hash: ActionHash) -> ExternResult<Option<Record>> {
-    let links = get_links(
-        GetLinksInputBuilder::try_new(original_happ_hash.clone(), LinkTypes::HappUpdates)?.build(),
-    )?;
-    let latest_link = links
-        .into_iter()
-        .max_by(|link_a, link_b| link_a.timestamp.cmp(&link_b.timestamp));
-    let latest_happ_hash = match latest_link {
-        Some(link) => {
-            link.target
-                .clone()
-                .into_action_hash()
-                .ok_or(wasm_error!(WasmErrorInner::Guest(
-                    "No action hash associated with link".to_string()
-                )))?
-        }
-        None => original_happ_hash.clone(),
-    };
-    get(latest_happ_hash, GetOptions::default())
+    let heads = get_happ_heads(original_happ_hash)?;
+    Ok(heads.map(|heads| heads.canonical_head))
+}
+
+/// Returns the latest revision of the hApp, along with any other revisions that resulted from concurrent edits
+#[hdk_extern]
+pub fn get_happ_heads(original_happ_hash: ActionHash) -> ExternResult<Option<RevisionHeads>> {
+    let mut revisions = get_all_revisions_for_happ(original_happ_hash)?;
+    if revisions.is_empty() {
+        return Ok(None);
+    }
+    let original_record = revisions.remove(0);
+    Ok(Some(resolve_revision_heads(original_record, revisions)?))
 }
 
 #[hdk_extern]
@@ -96,13 +110,54 @@ pub struct UpdateHappInput {
 
 #[hdk_extern]
 pub fn update_happ(input: UpdateHappInput) -> ExternResult<Record> {
+    check_icon_is_stored(input.updated_happ.icon.clone())?;
+    let heads = get_happ_heads(input.original_happ_hash.clone())?.ok_or(wasm_error!(
+        WasmErrorInner::Guest("Could not find the Happ to update".to_string())
+    ))?;
+    let previous_happ = Happ::try_from(heads.canonical_head.clone())?;
+    check_is_canonical_head(heads, &input.previous_happ_hash)?;
+    ensure_maintainer_claim(input.original_happ_hash.clone())?;
+    let previous_sort_values = happ_sort_values(&input.original_happ_hash)?;
     let updated_happ_hash = update_entry(input.previous_happ_hash.clone(), &input.updated_happ)?;
-    create_link(
+    let update_link_hash = create_link(
         input.original_happ_hash.clone(),
         updated_happ_hash.clone(),
         LinkTypes::HappUpdates,
         (),
     )?;
+    migrate_legacy_all_happs_link(&input.original_happ_hash)?;
+    index_happ(
+        &input.original_happ_hash,
+        Some(&previous_happ),
+        &input.updated_happ,
+    )?;
+    index_happ_search(
+        &input.original_happ_hash,
+        Some(&previous_happ),
+        &input.updated_happ,
+    )?;
+    // Along with the creation time, so that hApps created before the sort indexes existed get indexed
+    let updated_at = must_get_action(update_link_hash)?.action().timestamp();
+    let current_sort_values: Vec<HappSortIndexTag> = previous_sort_values
+        .iter()
+        .filter(|value| value.sort_key == HappsSortKey::CreatedTime)
+        .cloned()
+        .chain([
+            HappSortIndexTag {
+                sort_key: HappsSortKey::LastUpdated,
+                sort_value: HappSortValue::Timestamp(updated_at),
+            },
+            HappSortIndexTag {
+                sort_key: HappsSortKey::Name,
+                sort_value: HappSortValue::name(&input.updated_happ.name),
+            },
+        ])
+        .collect();
+    index_happ_sort_values(
+        &input.original_happ_hash,
+        &previous_sort_values,
+        &current_sort_values,
+    )?;
     let record = get(updated_happ_hash.clone(), GetOptions::default())?.ok_or(wasm_error!(
         WasmErrorInner::Guest("Could not find the newly updated Happ".to_string())
     ))?;
@@ -111,10 +166,7 @@ pub fn update_happ(input: UpdateHappInput) -> ExternResult<Record> {
 
 #[hdk_extern]
 pub fn delete_happ(original_happ_hash: ActionHash) -> ExternResult<ActionHash> {
-    let path = Path::from("all_happs");
-    let links = get_links(
-        GetLinksInputBuilder::try_new(path.path_entry_hash()?, LinkTypes::AllHapps)?.build(),
-    )?;
+    let links = get_all_happs_links()?;
     for link in links {
         if let Some(hash) = link.target.into_action_hash() {
             if hash == original_happ_hash {
@@ -122,19 +174,11 @@ pub fn delete_happ(original_happ_hash: ActionHash) -> ExternResult<ActionHash> {
             }
         }
     }
-    let details = get_details(original_happ_hash.clone(), GetOptions::default())?.ok_or(
-        wasm_error!(WasmErrorInner::Guest("Happ not found".to_string())),
-    )?;
-    let record = match details {
-        Details::Record(details) => Ok(details.record),
-        _ => Err(wasm_error!(WasmErrorInner::Guest(
-            "Malformed get details response".to_string()
-        ))),
-    }?;
-    let links = get_links(
-        GetLinksInputBuilder::try_new(record.action().author().clone(), LinkTypes::PublisherHapps)?
-            .build(),
-    )?;
+    let owner = get_happ_owner(original_happ_hash.clone())?.ok_or(wasm_error!(
+        WasmErrorInner::Guest("Happ not found".to_string())
+    ))?;
+    let links =
+        get_links(GetLinksInputBuilder::try_new(owner, LinkTypes::PublisherHapps)?.build())?;
     for link in links {
         if let Some(hash) = link.target.into_action_hash() {
             if hash == original_happ_hash {
@@ -142,6 +186,12 @@ pub fn delete_happ(original_happ_hash: ActionHash) -> ExternResult<ActionHash> {
             }
         }
     }
+    if let Some(latest_happ) = get_latest_happ(original_happ_hash.clone())? {
+        let latest_happ = Happ::try_from(latest_happ)?;
+        unindex_happ(&original_happ_hash, &latest_happ)?;
+        unindex_happ_search(&original_happ_hash, &latest_happ)?;
+        unindex_happ_sort_values(&original_happ_hash)?;
+    }
     delete_entry(original_happ_hash)
 }
 
@@ -0,0 +1,138 @@
+use happs_integrity::*;
+use hdk::prelude::*;
+
+use crate::happ_unpublished::retain_listed_happs;
+
+/// The path and link type of every index entry of a hApp, along with the tag of its link
+fn index_entries(happ: &Happ) -> ExternResult<Vec<(EntryHash, LinkTypes, String)>> {
+    let mut entries = vec![(
+        category_path(&happ.category).path_entry_hash()?,
+        LinkTypes::CategoryToHapps,
+        happ.category.name().to_string(),
+    )];
+    for tag in &happ.tags {
+        entries.push((
+            tag_path(tag).path_entry_hash()?,
+            LinkTypes::TagToHapps,
+            tag.clone(),
+        ));
+    }
+    Ok(entries)
+}
+
+/// Returns the links from each of the given index entries to the hApp
+fn get_index_links(
+    happ_hash: &ActionHash,
+    entries: &[(EntryHash, LinkTypes, String)],
+) -> ExternResult<Vec<Vec<Link>>> {
+    let inputs = entries
+        .iter()
+        .map(|(path_hash, link_type, _)| {
+            Ok(GetLinksInputBuilder::try_new(path_hash.clone(), *link_type)?.build())
+        })
+        .collect::<ExternResult<Vec<GetLinksInput>>>()?;
+    let links = HDK.with(|hdk| hdk.borrow().get_links(inputs))?;
+    Ok(links
+        .into_iter()
+        .map(|links| {
+            links
+                .into_iter()
+                .filter(|link| link.target.clone().into_action_hash().as_ref() == Some(happ_hash))
+                .collect()
+        })
+        .collect())
+}
+
+/// Makes the category and tags indexes point to the hApp for its current category and tags,
+/// removing the links for the ones that the previous revision had and the current one doesn't
+///
+/// Missing links are created even if the category or the tags didn't change, so that the hApps
+/// created before the indexes existed are indexed the next time they are updated
+pub fn index_happ(
+    happ_hash: &ActionHash,
+    previous_happ: Option<&Happ>,
+    happ: &Happ,
+) -> ExternResult<()> {
+    let entries = index_entries(happ)?;
+    let existing_links = get_index_links(happ_hash, &entries)?;
+    for ((path_hash, link_type, tag), links) in entries.iter().zip(existing_links) {
+        if links.is_empty() {
+            create_link(
+                path_hash.clone(),
+                happ_hash.clone(),
+                *link_type,
+                LinkTag::new(tag.clone()),
+            )?;
+        }
+    }
+
+    let Some(previous_happ) = previous_happ else {
+        return Ok(());
+    };
+    let stale_entries: Vec<(EntryHash, LinkTypes, String)> = index_entries(previous_happ)?
+        .into_iter()
+        .filter(|entry| !entries.contains(entry))
+        .collect();
+    unindex_entries(happ_hash, &stale_entries)
+}
+
+/// Removes all the links from the category and tags indexes to the hApp
+pub fn unindex_happ(happ_hash: &ActionHash, happ: &Happ) -> ExternResult<()> {
+    unindex_entries(happ_hash, &index_entries(happ)?)
+}
+
+fn unindex_entries(
+    happ_hash: &ActionHash,
+    entries: &[(EntryHash, LinkTypes, String)],
+) -> ExternResult<()> {
+    for links in get_index_links(happ_hash, entries)? {
+        for link in links {
+            delete_link(link.create_link_hash)?;
+        }
+    }
+    Ok(())
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct GetHappsByCategoryInput {
+    pub category: HappCategory,
+    #[serde(default)]
+    pub include_unpublished: bool,
+}
+
+/// Returns the links to the hApps in the given category
+///
+/// hApps created before categories existed are only indexed once they are updated
+#[hdk_extern]
+pub fn get_happs_by_category(input: GetHappsByCategoryInput) -> ExternResult<Vec<Link>> {
+    let links = get_links(
+        GetLinksInputBuilder::try_new(
+            category_path(&input.category).path_entry_hash()?,
+            LinkTypes::CategoryToHapps,
+        )?
+        .build(),
+    )?;
+    retain_listed_happs(links, input.include_unpublished)
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct GetHappsByTagInput {
+    pub tag: String,
+    #[serde(default)]
+    pub include_unpublished: bool,
+}
+
+/// Returns the links to the hApps with the given tag
+#[hdk_extern]
+pub fn get_happs_by_tag(input: GetHappsByTagInput) -> ExternResult<Vec<Link>> {
+    let tag = input.tag.trim().to_lowercase();
+    // Tags with dots would be split into several path components, and no hApp can have them anyway
+    if tag.is_empty() || tag.contains('.') {
+        return Ok(vec![]);
+    }
+    let links = get_links(
+        GetLinksInputBuilder::try_new(tag_path(&tag).path_entry_hash()?, LinkTypes::TagToHapps)?
+            .build(),
+    )?;
+    retain_listed_happs(links, input.include_unpublished)
+}
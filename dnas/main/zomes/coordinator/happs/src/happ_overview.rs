@@ -0,0 +1,56 @@
+use hdk::prelude::*;
+
+use crate::{
+    happ::{get_latest_happ, get_original_happ},
+    happ_release::{get_happ_releases_for_happ, get_latest_happ_release},
+    happ_unpublished::get_happ_unpublished_links,
+    moderation::get_happ_hidden_links,
+    ownership::get_happ_owner,
+};
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct HappOverview {
+    pub original_happ_hash: ActionHash,
+    /// The current owner of the hApp, who might not be the agent that created it
+    pub publisher: AgentPubKey,
+    pub latest_happ: Record,
+    pub unpublished: bool,
+    /// Whether a moderator hid the hApp from the catalog
+    pub hidden: bool,
+    /// Latest revision of every release of the hApp, sorted by version from newest to oldest
+    pub releases: Vec<Record>,
+}
+
+/// Returns everything needed to render a hApp in a single call
+#[hdk_extern]
+pub fn get_happ_overview(original_happ_hash: ActionHash) -> ExternResult<Option<HappOverview>> {
+    let Some(original_happ) = get_original_happ(original_happ_hash.clone())? else {
+        return Ok(None);
+    };
+    let Some(latest_happ) = get_latest_happ(original_happ_hash.clone())? else {
+        return Ok(None);
+    };
+    let publisher = get_happ_owner(original_happ_hash.clone())?
+        .unwrap_or(original_happ.action().author().clone());
+    let unpublished = !get_happ_unpublished_links(original_happ_hash.clone())?.is_empty();
+    let hidden = !get_happ_hidden_links(original_happ_hash.clone())?.is_empty();
+
+    let mut releases = vec![];
+    for link in get_happ_releases_for_happ(original_happ_hash.clone())? {
+        let Some(happ_release_hash) = link.target.into_action_hash() else {
+            continue;
+        };
+        if let Some(release) = get_latest_happ_release(happ_release_hash)? {
+            releases.push(release);
+        }
+    }
+
+    Ok(Some(HappOverview {
+        original_happ_hash,
+        publisher,
+        latest_happ,
+        unpublished,
+        hidden,
+        releases,
+    }))
+}
@@ -1,14 +1,45 @@
+use std::collections::HashMap;
+
 use happs_integrity::*;
 use hdk::prelude::*;
 
+use crate::{
+    all_happs::{happ_sort_values, index_happ_sort_values},
+    happ_release_yank::is_happ_release_yanked,
+    maintainers::ensure_maintainer_claim,
+    publisher_credential::ensure_can_publish,
+    revision_heads::{check_is_canonical_head, resolve_revision_heads, RevisionHeads},
+    stored_files::check_web_happ_bundle_is_stored,
+};
+
 #[hdk_extern]
 pub fn create_happ_release(happ_release: HappRelease) -> ExternResult<Record> {
+    check_web_happ_bundle_is_stored(happ_release.web_happ_bundle_hash.clone())?;
+    ensure_maintainer_claim(happ_release.happ_hash.clone())?;
+    ensure_can_publish()?;
+    check_version_is_the_greatest_in_channel(&happ_release)?;
+    let previous_sort_values = happ_sort_values(&happ_release.happ_hash)?;
     let happ_release_hash = create_entry(&EntryTypes::HappRelease(happ_release.clone()))?;
-    create_link(
+    let release_link_hash = create_link(
         happ_release.happ_hash.clone(),
         happ_release_hash.clone(),
         LinkTypes::HappToHappReleases,
-        (),
+        LinkTag::new(happ_release.version.clone()),
+    )?;
+    create_link(
+        happ_release.happ_hash.clone(),
+        happ_release_hash.clone(),
+        LinkTypes::HappReleasesByChannel,
+        release_channel_link_tag(&happ_release.channel, &happ_release.version),
+    )?;
+    let released_at = must_get_action(release_link_hash)?.action().timestamp();
+    index_happ_sort_values(
+        &happ_release.happ_hash,
+        &previous_sort_values,
+        &[HappSortIndexTag {
+            sort_key: HappsSortKey::LatestReleaseTime,
+            sort_value: HappSortValue::Timestamp(released_at),
+        }],
     )?;
     let record = get(happ_release_hash.clone(), GetOptions::default())?.ok_or(wasm_error!(
         WasmErrorInner::Guest("Could not find the newly created HappRelease".to_string())
@@ -16,32 +47,47 @@ pub fn create_happ_release(happ_release: HappRelease) -> ExternResult<Record> {
     Ok(record)
 }
 
+/// Validation only compares the version of a new release with the previous releases of its author,
+/// so check it against the releases of all the maintainers of the hApp that we can get
+fn check_version_is_the_greatest_in_channel(happ_release: &HappRelease) -> ExternResult<()> {
+    let version = parse_happ_release_version(&happ_release.version)
+        .map_err(|err| wasm_error!(WasmErrorInner::Guest(err)))?;
+    let links = get_happ_releases_in_channel(HappReleaseChannelInput {
+        happ_hash: happ_release.happ_hash.clone(),
+        channel: happ_release.channel.clone(),
+    })?;
+    // The links are sorted from the newest version to the oldest
+    let greatest_version = links.iter().find_map(link_version);
+    if let Some(greatest_version) = greatest_version {
+        if version.cmp_precedence(&greatest_version).is_le() {
+            return Err(wasm_error!(WasmErrorInner::Guest(format!(
+                "The version of a new hApp release must be greater than the version of all the previous releases in its channel, but {greatest_version} was already released in the {} channel",
+                happ_release.channel.name()
+            ))));
+        }
+    }
+    Ok(())
+}
+
 #[hdk_extern]
 pub fn get_latest_happ_release(
     original_happ_release_hash: ActionHash,
 ) -> ExternResult<Option<Record>> {
-    let links = get_links(
-        GetLinksInputBuilder::try_new(
-            original_happ_release_hash.clone(),
-            LinkTypes::HappReleaseUpdates,
-        )?
-        .build(),
-    )?;
-    let latest_link = links
-        .into_iter()
-        .max_by(|link_a, link_b| link_a.timestamp.cmp(&link_b.timestamp));
-    let latest_happ_release_hash = match latest_link {
-        Some(link) => {
-            link.target
-                .clone()
-                .into_action_hash()
-                .ok_or(wasm_error!(WasmErrorInner::Guest(
-                    "No action hash associated with link".to_string()
-                )))?
-        }
-        None => original_happ_release_hash.clone(),
-    };
-    get(latest_happ_release_hash, GetOptions::default())
+    let heads = get_happ_release_heads(original_happ_release_hash)?;
+    Ok(heads.map(|heads| heads.canonical_head))
+}
+
+/// Returns the latest revision of the hApp release, along with any other revisions that resulted from concurrent edits
+#[hdk_extern]
+pub fn get_happ_release_heads(
+    original_happ_release_hash: ActionHash,
+) -> ExternResult<Option<RevisionHeads>> {
+    let mut revisions = get_all_revisions_for_happ_release(original_happ_release_hash)?;
+    if revisions.is_empty() {
+        return Ok(None);
+    }
+    let original_record = revisions.remove(0);
+    Ok(Some(resolve_revision_heads(original_record, revisions)?))
 }
 
 #[hdk_extern]
@@ -103,6 +149,13 @@ pub struct UpdateHappReleaseInput {
 
 #[hdk_extern]
 pub fn update_happ_release(input: UpdateHappReleaseInput) -> ExternResult<Record> {
+    check_web_happ_bundle_is_stored(input.updated_happ_release.web_happ_bundle_hash.clone())?;
+    let heads =
+        get_happ_release_heads(input.original_happ_release_hash.clone())?.ok_or(wasm_error!(
+            WasmErrorInner::Guest("Could not find the HappRelease to update".to_string())
+        ))?;
+    check_is_canonical_head(heads, &input.previous_happ_release_hash)?;
+    ensure_maintainer_claim(input.updated_happ_release.happ_hash.clone())?;
     let updated_happ_release_hash = update_entry(
         input.previous_happ_release_hash.clone(),
         &input.updated_happ_release,
@@ -120,7 +173,80 @@ pub fn update_happ_release(input: UpdateHappReleaseInput) -> ExternResult<Record
     Ok(record)
 }
 
+/// Returns the links to all the releases for the given hApp, sorted by their version from newest to oldest
 #[hdk_extern]
 pub fn get_happ_releases_for_happ(happ_hash: ActionHash) -> ExternResult<Vec<Link>> {
-    get_links(GetLinksInputBuilder::try_new(happ_hash, LinkTypes::HappToHappReleases)?.build())
+    let mut links = get_links(
+        GetLinksInputBuilder::try_new(happ_hash, LinkTypes::HappToHappReleases)?.build(),
+    )?;
+    links.sort_by(|link_a, link_b| {
+        let version_a = link_version(link_a);
+        let version_b = link_version(link_b);
+        match (version_a, version_b) {
+            (Some(version_a), Some(version_b)) => version_b.cmp_precedence(&version_a),
+            (Some(_), None) => std::cmp::Ordering::Less,
+            (None, Some(_)) => std::cmp::Ordering::Greater,
+            (None, None) => link_b.timestamp.cmp(&link_a.timestamp),
+        }
+    });
+    Ok(links)
+}
+
+pub fn link_version(link: &Link) -> Option<semver::Version> {
+    let version = String::from_utf8(link.tag.clone().into_inner()).ok()?;
+    parse_happ_release_version(&version).ok()
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct HappReleaseChannelInput {
+    pub happ_hash: ActionHash,
+    pub channel: ReleaseChannel,
+}
+
+/// Returns the links to the releases of the given hApp in the given channel, sorted by their version from newest to oldest
+///
+/// Releases created before channels existed are not indexed by channel, and are considered stable
+#[hdk_extern]
+pub fn get_happ_releases_in_channel(input: HappReleaseChannelInput) -> ExternResult<Vec<Link>> {
+    let channel_links = get_links(
+        GetLinksInputBuilder::try_new(input.happ_hash.clone(), LinkTypes::HappReleasesByChannel)?
+            .build(),
+    )?;
+    let release_channels: HashMap<AnyLinkableHash, String> = channel_links
+        .into_iter()
+        .filter_map(|link| {
+            let tag = String::from_utf8(link.tag.into_inner()).ok()?;
+            let (channel, _version) = tag.split_once(':')?;
+            Some((link.target, channel.to_string()))
+        })
+        .collect();
+
+    let links = get_happ_releases_for_happ(input.happ_hash)?
+        .into_iter()
+        .filter(|link| match release_channels.get(&link.target) {
+            Some(channel) => channel.eq(input.channel.name()),
+            None => input.channel.eq(&ReleaseChannel::Stable),
+        })
+        .collect();
+    Ok(links)
+}
+
+/// Returns the latest revision of the release with the greatest version in the given channel, skipping yanked releases
+#[hdk_extern]
+pub fn get_latest_release_in_channel(
+    input: HappReleaseChannelInput,
+) -> ExternResult<Option<Record>> {
+    for link in get_happ_releases_in_channel(input)? {
+        let happ_release_hash =
+            link.target
+                .into_action_hash()
+                .ok_or(wasm_error!(WasmErrorInner::Guest(
+                    "No action hash associated with link".to_string()
+                )))?;
+        if is_happ_release_yanked(happ_release_hash.clone())? {
+            continue;
+        }
+        return get_latest_happ_release(happ_release_hash);
+    }
+    Ok(None)
 }
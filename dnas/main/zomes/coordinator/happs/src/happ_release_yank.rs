@@ -0,0 +1,54 @@
+use happs_integrity::*;
+use hdk::prelude::*;
+
+use crate::maintainers::ensure_maintainer_claim;
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct YankHappReleaseInput {
+    pub happ_release_hash: ActionHash,
+    pub reason: String,
+    pub severity: Option<YankSeverity>,
+}
+
+/// Flags the given release as broken or insecure, so that it's not offered as the latest release anymore
+#[hdk_extern]
+pub fn yank_happ_release(input: YankHappReleaseInput) -> ExternResult<Record> {
+    let happ_release_record =
+        get(input.happ_release_hash.clone(), GetOptions::default())?.ok_or(wasm_error!(
+            WasmErrorInner::Guest("Could not find the HappRelease to yank".to_string())
+        ))?;
+    let happ_release = HappRelease::try_from(happ_release_record)?;
+    ensure_maintainer_claim(happ_release.happ_hash)?;
+    let happ_release_yank = HappReleaseYank {
+        happ_release_hash: input.happ_release_hash.clone(),
+        reason: input.reason,
+        severity: input.severity,
+    };
+    let happ_release_yank_hash = create_entry(&EntryTypes::HappReleaseYank(happ_release_yank))?;
+    create_link(
+        input.happ_release_hash,
+        happ_release_yank_hash.clone(),
+        LinkTypes::HappReleaseYanks,
+        (),
+    )?;
+    let record = get(happ_release_yank_hash, GetOptions::default())?.ok_or(wasm_error!(
+        WasmErrorInner::Guest("Could not find the newly created HappReleaseYank".to_string())
+    ))?;
+    Ok(record)
+}
+
+#[hdk_extern]
+pub fn get_happ_release_yank(happ_release_yank_hash: ActionHash) -> ExternResult<Option<Record>> {
+    get(happ_release_yank_hash, GetOptions::default())
+}
+
+#[hdk_extern]
+pub fn get_happ_release_yank_links(happ_release_hash: ActionHash) -> ExternResult<Vec<Link>> {
+    get_links(
+        GetLinksInputBuilder::try_new(happ_release_hash, LinkTypes::HappReleaseYanks)?.build(),
+    )
+}
+
+pub fn is_happ_release_yanked(happ_release_hash: ActionHash) -> ExternResult<bool> {
+    Ok(!get_happ_release_yank_links(happ_release_hash)?.is_empty())
+}
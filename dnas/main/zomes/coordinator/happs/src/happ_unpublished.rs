@@ -1,8 +1,11 @@
 use happs_integrity::*;
 use hdk::prelude::*;
 
+use crate::maintainers::{ensure_maintainer_claim, get_maintainers};
+
 #[hdk_extern]
 pub fn unpublish_happ(happ_hash: ActionHash) -> ExternResult<()> {
+    ensure_maintainer_claim(happ_hash.clone())?;
     create_link(happ_hash.clone(), happ_hash, LinkTypes::HappUnpublished, ())?;
     Ok(())
 }
@@ -16,9 +19,10 @@ pub fn get_happ_unpublished_links(happ_hash: ActionHash) -> ExternResult<Vec<Lin
 
 #[hdk_extern]
 pub fn republish_happ(happ_hash: ActionHash) -> ExternResult<()> {
+    ensure_maintainer_claim(happ_hash.clone())?;
     let links = get_happ_unpublished_links(happ_hash)?;
 
-    if links.len() == 0 {
+    if links.is_empty() {
         return Err(wasm_error!("App is not unpublished."));
     }
 
@@ -28,3 +32,55 @@ pub fn republish_happ(happ_hash: ActionHash) -> ExternResult<()> {
 
     Ok(())
 }
+
+/// Options shared by the catalog queries
+#[derive(Serialize, Deserialize, Debug, Default)]
+pub struct CatalogQueryOptions {
+    /// Unpublished hApps, and the ones hidden by moderators, are left out of the results unless this is set,
+    /// and even then the hidden ones are only kept for their maintainers
+    #[serde(default)]
+    pub include_unpublished: bool,
+}
+
+/// Removes the links to unpublished hApps, and to the ones hidden by moderators, from the given links
+///
+/// With `include_unpublished`, the unpublished hApps are kept, and the hidden ones only if we maintain them,
+/// so that the hApps that moderators hid are still kept out of the catalog for everyone else
+pub fn retain_listed_happs(links: Vec<Link>, include_unpublished: bool) -> ExternResult<Vec<Link>> {
+    let inputs = links
+        .iter()
+        .map(|link| {
+            let happ_hash = link.target.clone().into_action_hash().ok_or(wasm_error!(
+                WasmErrorInner::Guest("No action hash associated with link".to_string())
+            ))?;
+            Ok([
+                GetLinksInputBuilder::try_new(happ_hash.clone(), LinkTypes::HappUnpublished)?
+                    .build(),
+                GetLinksInputBuilder::try_new(happ_hash, LinkTypes::HiddenHapps)?.build(),
+            ])
+        })
+        .collect::<ExternResult<Vec<[GetLinksInput; 2]>>>()?
+        .into_iter()
+        .flatten()
+        .collect();
+    let unlisting_links = HDK.with(|hdk| hdk.borrow().get_links(inputs))?;
+    let my_pub_key = agent_info()?.agent_initial_pubkey;
+    let mut listed_links = vec![];
+    for (link, unlisting_links) in links.into_iter().zip(unlisting_links.chunks(2)) {
+        let is_unpublished = !unlisting_links[0].is_empty();
+        let is_hidden = !unlisting_links[1].is_empty();
+        if !include_unpublished && (is_unpublished || is_hidden) {
+            continue;
+        }
+        if is_hidden {
+            let Some(happ_hash) = link.target.clone().into_action_hash() else {
+                continue;
+            };
+            if !get_maintainers(happ_hash)?.contains(&my_pub_key) {
+                continue;
+            }
+        }
+        listed_links.push(link);
+    }
+    Ok(listed_links)
+}
@@ -1,8 +1,26 @@
 pub mod all_happs;
+pub mod domain_claim;
 pub mod happ;
+pub mod happ_categories;
+pub mod happ_overview;
 pub mod happ_release;
+pub mod happ_release_yank;
 pub mod happ_unpublished;
+pub mod maintainers;
+pub mod moderation;
+pub mod ownership;
+pub mod publisher;
+pub mod publisher_credential;
 pub mod publisher_happs;
+pub mod report;
+pub mod review;
+pub mod revision_heads;
+pub mod search;
+pub mod stored_files;
+#[cfg(debug_assertions)]
+pub mod test_utils;
+pub mod update_check;
+pub mod zome_errors;
 use happs_integrity::*;
 use hdk::prelude::*;
 
@@ -0,0 +1,156 @@
+use happs_integrity::*;
+use hdk::prelude::*;
+
+use crate::ownership::get_happ_owner;
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct MaintainerInput {
+    pub happ_hash: ActionHash,
+    pub maintainer: AgentPubKey,
+}
+
+#[hdk_extern]
+pub fn add_maintainer(input: MaintainerInput) -> ExternResult<()> {
+    let links = get_maintainer_links(input.happ_hash.clone())?;
+    if links
+        .iter()
+        .any(|link| link.target.clone().into_agent_pub_key().as_ref() == Some(&input.maintainer))
+    {
+        return Ok(());
+    }
+    create_link(
+        input.happ_hash,
+        input.maintainer,
+        LinkTypes::HappMaintainers,
+        (),
+    )?;
+    Ok(())
+}
+
+#[hdk_extern]
+pub fn remove_maintainer(input: MaintainerInput) -> ExternResult<()> {
+    let my_pub_key = agent_info()?.agent_initial_pubkey;
+    let links = get_maintainer_links(input.happ_hash)?;
+    let links: Vec<Link> = links
+        .into_iter()
+        .filter(|link| link.author.eq(&my_pub_key))
+        .filter(|link| link.target.clone().into_agent_pub_key().as_ref() == Some(&input.maintainer))
+        .collect();
+    if links.is_empty() {
+        return Err(wasm_error!(WasmErrorInner::Guest(
+            "The given agent is not a maintainer of this hApp".to_string()
+        )));
+    }
+    for link in links {
+        delete_link(link.create_link_hash)?;
+    }
+    Ok(())
+}
+
+/// Returns the owner of the hApp followed by the rest of its maintainers
+///
+/// Only the maintainers added by the current owner are returned, as the ones added by previous owners can't act anymore
+#[hdk_extern]
+pub fn get_maintainers(happ_hash: ActionHash) -> ExternResult<Vec<AgentPubKey>> {
+    let Some(owner) = get_happ_owner(happ_hash.clone())? else {
+        return Ok(vec![]);
+    };
+    let mut maintainers = vec![owner.clone()];
+    for link in get_maintainer_links(happ_hash)? {
+        if link.author.ne(&owner) {
+            continue;
+        }
+        let Some(maintainer) = link.target.into_agent_pub_key() else {
+            continue;
+        };
+        if !maintainers.contains(&maintainer) {
+            maintainers.push(maintainer);
+        }
+    }
+    Ok(maintainers)
+}
+
+fn get_maintainer_links(happ_hash: ActionHash) -> ExternResult<Vec<Link>> {
+    get_links(GetLinksInputBuilder::try_new(happ_hash, LinkTypes::HappMaintainers)?.build())
+}
+
+/// Makes our source chain reflect whether we are a maintainer of the hApp, so that the actions we commit
+/// next for it are validated accordingly: claims the HappMaintainers link pointing to us, pointing to the
+/// latest action of the chain of the owner, if our latest claim doesn't already
+///
+/// Owners don't need a claim, as they are always maintainers of their hApps
+pub fn ensure_maintainer_claim(happ_hash: ActionHash) -> ExternResult<()> {
+    let my_pub_key = agent_info()?.agent_initial_pubkey;
+    let owner = get_happ_owner(happ_hash.clone())?.ok_or(wasm_error!(WasmErrorInner::Guest(
+        "Could not find the Happ".to_string()
+    )))?;
+    if owner.eq(&my_pub_key) {
+        return Ok(());
+    }
+
+    let maintainer_link = get_maintainer_links(happ_hash.clone())?
+        .into_iter()
+        .find(|link| {
+            link.author.eq(&owner)
+                && link.target.clone().into_agent_pub_key().as_ref() == Some(&my_pub_key)
+        })
+        .ok_or(wasm_error!(WasmErrorInner::Guest(
+            "Only the maintainers of a hApp can do this".to_string()
+        )))?;
+    let owner_chain_top = get_chain_top(owner)?;
+
+    let claim = MaintainerClaim {
+        happ_hash: happ_hash.clone(),
+        maintainer_link_hash: maintainer_link.create_link_hash,
+        owner_chain_top,
+    };
+    if query_my_latest_maintainer_claim(&happ_hash)?.as_ref() != Some(&claim) {
+        create_entry(&EntryTypes::MaintainerClaim(claim))?;
+    }
+    Ok(())
+}
+
+/// Returns the latest action of the source chain of the given agent that we can see
+pub fn get_chain_top(agent: AgentPubKey) -> ExternResult<ActionHash> {
+    let activity = get_agent_activity(agent, ChainQueryFilter::new(), ActivityRequest::Status)?;
+    match activity.status {
+        ChainStatus::Valid(head) => Ok(head.hash),
+        _ => Err(wasm_error!(WasmErrorInner::Guest(
+            "Could not get the source chain of the agent".to_string()
+        ))),
+    }
+}
+
+/// Returns our latest claim for the hApp, unless we deleted it
+fn query_my_latest_maintainer_claim(
+    happ_hash: &ActionHash,
+) -> ExternResult<Option<MaintainerClaim>> {
+    let claims = query(
+        ChainQueryFilter::new()
+            .entry_type(UnitEntryTypes::MaintainerClaim.try_into()?)
+            .include_entries(true),
+    )?;
+    let deleted_hashes: Vec<ActionHash> =
+        query(ChainQueryFilter::new().action_type(ActionType::Delete))?
+            .into_iter()
+            .filter_map(|record| match record.action() {
+                Action::Delete(delete) => Some(delete.deletes_address.clone()),
+                _ => None,
+            })
+            .collect();
+
+    let mut latest_claim = None;
+    for record in claims {
+        let Some(claim) = record
+            .entry()
+            .to_app_option::<MaintainerClaim>()
+            .map_err(|e| wasm_error!(e))?
+        else {
+            continue;
+        };
+        if claim.happ_hash.eq(happ_hash) {
+            latest_claim = (!deleted_hashes.contains(record.action_address())).then_some(claim);
+        }
+    }
+    Ok(latest_claim)
+}
@@ -0,0 +1,176 @@
+use happs_integrity::*;
+use hdk::prelude::*;
+
+use crate::maintainers::get_chain_top;
+
+/// Returns the progenitor, followed by the initial moderators and the ones the progenitor appointed
+#[hdk_extern]
+pub fn get_moderators() -> ExternResult<Vec<AgentPubKey>> {
+    let properties = happs_properties()?;
+    let mut moderators: Vec<AgentPubKey> = properties.progenitor().into_iter().collect();
+    let appointed_moderators = get_moderator_links()?
+        .into_iter()
+        .filter_map(|link| link.target.into_agent_pub_key());
+    for moderator in properties
+        .initial_moderators()
+        .into_iter()
+        .chain(appointed_moderators)
+    {
+        if !moderators.contains(&moderator) {
+            moderators.push(moderator);
+        }
+    }
+    Ok(moderators)
+}
+
+fn get_moderator_links() -> ExternResult<Vec<Link>> {
+    get_links(
+        GetLinksInputBuilder::try_new(moderators_path().path_entry_hash()?, LinkTypes::Moderators)?
+            .build(),
+    )
+}
+
+fn ensure_progenitor() -> ExternResult<()> {
+    if !happs_properties()?.is_progenitor(&agent_info()?.agent_initial_pubkey) {
+        return Err(wasm_error!(WasmErrorInner::Guest(
+            "Only the progenitor can manage the moderators".to_string()
+        )));
+    }
+    Ok(())
+}
+
+#[hdk_extern]
+pub fn add_moderator(moderator: AgentPubKey) -> ExternResult<()> {
+    ensure_progenitor()?;
+    if get_moderator_links()?
+        .iter()
+        .any(|link| link.target.clone().into_agent_pub_key().as_ref() == Some(&moderator))
+    {
+        return Ok(());
+    }
+    create_link(
+        moderators_path().path_entry_hash()?,
+        moderator,
+        LinkTypes::Moderators,
+        (),
+    )?;
+    Ok(())
+}
+
+/// Initial moderators can't be removed, as they are set in the DNA properties
+#[hdk_extern]
+pub fn remove_moderator(moderator: AgentPubKey) -> ExternResult<()> {
+    ensure_progenitor()?;
+    let links: Vec<Link> = get_moderator_links()?
+        .into_iter()
+        .filter(|link| link.target.clone().into_agent_pub_key().as_ref() == Some(&moderator))
+        .collect();
+    if links.is_empty() {
+        return Err(wasm_error!(WasmErrorInner::Guest(
+            "The given agent was not appointed as a moderator".to_string()
+        )));
+    }
+    for link in links {
+        delete_link(link.create_link_hash)?;
+    }
+    Ok(())
+}
+
+/// Makes our source chain reflect whether we are a moderator, like `ensure_maintainer_claim` does for maintainers,
+/// and fails if we are not one
+pub fn ensure_moderator_claim() -> ExternResult<()> {
+    let my_pub_key = agent_info()?.agent_initial_pubkey;
+    let properties = happs_properties()?;
+    if properties.is_progenitor(&my_pub_key)
+        || properties.initial_moderators().contains(&my_pub_key)
+    {
+        return Ok(());
+    }
+
+    let not_a_moderator = || {
+        wasm_error!(WasmErrorInner::Guest(
+            "Only moderators can moderate the catalog".to_string()
+        ))
+    };
+    let progenitor = properties.progenitor().ok_or_else(not_a_moderator)?;
+    let moderator_link = get_moderator_links()?
+        .into_iter()
+        .find(|link| link.target.clone().into_agent_pub_key().as_ref() == Some(&my_pub_key))
+        .ok_or_else(not_a_moderator)?;
+
+    let claim = ModeratorClaim {
+        moderator_link_hash: moderator_link.create_link_hash,
+        progenitor_chain_top: get_chain_top(progenitor)?,
+    };
+    if query_my_latest_moderator_claim()?.as_ref() != Some(&claim) {
+        create_entry(&EntryTypes::ModeratorClaim(claim))?;
+    }
+    Ok(())
+}
+
+/// Returns our latest moderator claim, unless we deleted it
+fn query_my_latest_moderator_claim() -> ExternResult<Option<ModeratorClaim>> {
+    let claims = query(
+        ChainQueryFilter::new()
+            .entry_type(UnitEntryTypes::ModeratorClaim.try_into()?)
+            .include_entries(true),
+    )?;
+    let Some(latest_claim) = claims.last() else {
+        return Ok(None);
+    };
+    let deleted_hashes: Vec<ActionHash> =
+        query(ChainQueryFilter::new().action_type(ActionType::Delete))?
+            .into_iter()
+            .filter_map(|record| match record.action() {
+                Action::Delete(delete) => Some(delete.deletes_address.clone()),
+                _ => None,
+            })
+            .collect();
+    if deleted_hashes.contains(latest_claim.action_address()) {
+        return Ok(None);
+    }
+    latest_claim
+        .entry()
+        .to_app_option::<ModeratorClaim>()
+        .map_err(|e| wasm_error!(e))
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct HideHappInput {
+    pub happ_hash: ActionHash,
+    pub reason: String,
+}
+
+/// Hides the hApp from the catalog queries, until a moderator unhides it
+#[hdk_extern]
+pub fn hide_happ(input: HideHappInput) -> ExternResult<()> {
+    ensure_moderator_claim()?;
+    create_link(
+        input.happ_hash.clone(),
+        input.happ_hash,
+        LinkTypes::HiddenHapps,
+        LinkTag::new(input.reason),
+    )?;
+    Ok(())
+}
+
+#[hdk_extern]
+pub fn unhide_happ(happ_hash: ActionHash) -> ExternResult<()> {
+    ensure_moderator_claim()?;
+    let links = get_happ_hidden_links(happ_hash)?;
+    if links.is_empty() {
+        return Err(wasm_error!(WasmErrorInner::Guest(
+            "The hApp is not hidden".to_string()
+        )));
+    }
+    for link in links {
+        delete_link(link.create_link_hash)?;
+    }
+    Ok(())
+}
+
+/// Returns the links of the moderators that hid the hApp, whose tags hold their reasons
+#[hdk_extern]
+pub fn get_happ_hidden_links(happ_hash: ActionHash) -> ExternResult<Vec<Link>> {
+    get_links(GetLinksInputBuilder::try_new(happ_hash, LinkTypes::HiddenHapps)?.build())
+}
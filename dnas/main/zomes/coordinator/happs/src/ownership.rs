@@ -0,0 +1,240 @@
+use happs_integrity::*;
+use hdk::prelude::*;
+
+use crate::{happ::get_original_happ, maintainers::get_chain_top};
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct OfferHappOwnershipInput {
+    pub happ_hash: ActionHash,
+    pub recipient: AgentPubKey,
+}
+
+/// First step of an ownership transfer: we stop acting as the owner of the hApp until the offer is canceled
+#[hdk_extern]
+pub fn offer_happ_ownership(input: OfferHappOwnershipInput) -> ExternResult<Record> {
+    let ownership_offer = OwnershipOffer {
+        happ_hash: input.happ_hash.clone(),
+        recipient: input.recipient,
+    };
+    let ownership_offer_hash = create_entry(&EntryTypes::OwnershipOffer(ownership_offer))?;
+    create_link(
+        input.happ_hash,
+        ownership_offer_hash.clone(),
+        LinkTypes::HappOwnershipOffers,
+        (),
+    )?;
+    let record = get(ownership_offer_hash, GetOptions::default())?.ok_or(wasm_error!(
+        WasmErrorInner::Guest("Could not find the newly created OwnershipOffer".to_string())
+    ))?;
+    Ok(record)
+}
+
+/// Returns the links to the ownership offers for the hApp that are still pending
+#[hdk_extern]
+pub fn get_happ_ownership_offers(happ_hash: ActionHash) -> ExternResult<Vec<Link>> {
+    get_links(GetLinksInputBuilder::try_new(happ_hash, LinkTypes::HappOwnershipOffers)?.build())
+}
+
+#[hdk_extern]
+pub fn cancel_happ_ownership_offer(ownership_offer_hash: ActionHash) -> ExternResult<()> {
+    let (offer_record, offer) = get_ownership_offer(ownership_offer_hash.clone())?;
+    if offer_record
+        .action()
+        .author()
+        .ne(&agent_info()?.agent_initial_pubkey)
+    {
+        return Err(wasm_error!(WasmErrorInner::Guest(
+            "Only the owner that made an ownership offer can cancel it".to_string()
+        )));
+    }
+    // Canceling an accepted offer would make us the owner again, alongside the recipient
+    if get_transfer_for_offer(&offer.happ_hash, &ownership_offer_hash)?.is_some() {
+        return Err(wasm_error!(WasmErrorInner::Guest(
+            "The ownership offer was already accepted".to_string()
+        )));
+    }
+    // The recipient can accept the offer until its deadline, so only an action of theirs after it shows they didn't
+    let recipient_chain_top = get_chain_top(offer.recipient)?;
+    let recipient_chain_top_action = get(recipient_chain_top.clone(), GetOptions::default())?
+        .ok_or(wasm_error!(WasmErrorInner::Guest(
+            "Could not find the latest action of the recipient".to_string()
+        )))?;
+    if recipient_chain_top_action
+        .action()
+        .timestamp()
+        .le(&ownership_offer_deadline(offer_record.action())?)
+    {
+        return Err(wasm_error!(WasmErrorInner::Guest(
+            "The ownership offer can only be canceled once the recipient was active after its deadline"
+                .to_string()
+        )));
+    }
+    delete_ownership_offer_links(&offer.happ_hash, &ownership_offer_hash)?;
+    // Validation requires the deletion of the offer to come right after its cancellation
+    create_entry(&EntryTypes::OwnershipOfferCancellation(
+        OwnershipOfferCancellation {
+            offer_hash: ownership_offer_hash.clone(),
+            recipient_chain_top,
+        },
+    ))?;
+    delete_entry(ownership_offer_hash)?;
+    Ok(())
+}
+
+/// Second step of an ownership transfer: makes us the owner of the hApp, and moves it to our hApps
+#[hdk_extern]
+pub fn accept_happ_ownership_offer(ownership_offer_hash: ActionHash) -> ExternResult<Record> {
+    let my_pub_key = agent_info()?.agent_initial_pubkey;
+    let (offer_record, offer) = get_ownership_offer(ownership_offer_hash.clone())?;
+    if offer.recipient.ne(&my_pub_key) {
+        return Err(wasm_error!(WasmErrorInner::Guest(
+            "Only the recipient of an ownership offer can accept it".to_string()
+        )));
+    }
+    if get_transfer_for_offer(&offer.happ_hash, &ownership_offer_hash)?.is_some() {
+        return Err(wasm_error!(WasmErrorInner::Guest(
+            "The ownership offer was already accepted".to_string()
+        )));
+    }
+    if sys_time()?.gt(&ownership_offer_deadline(offer_record.action())?) {
+        return Err(wasm_error!(WasmErrorInner::Guest(
+            "The ownership offer expired".to_string()
+        )));
+    }
+
+    let ownership_transfer = OwnershipTransfer {
+        happ_hash: offer.happ_hash.clone(),
+        offer_hash: ownership_offer_hash.clone(),
+        offerer_chain_top: get_chain_top(offer_record.action().author().clone())?,
+    };
+    let ownership_transfer_hash = create_entry(&EntryTypes::OwnershipTransfer(ownership_transfer))?;
+    create_link(
+        offer.happ_hash.clone(),
+        ownership_transfer_hash.clone(),
+        LinkTypes::HappOwnershipTransfers,
+        (),
+    )?;
+    delete_ownership_offer_links(&offer.happ_hash, &ownership_offer_hash)?;
+
+    let previous_owner = offer_record.action().author().clone();
+    let previous_owner_links = get_links(
+        GetLinksInputBuilder::try_new(previous_owner, LinkTypes::PublisherHapps)?.build(),
+    )?;
+    for link in previous_owner_links {
+        if link.target.clone().into_action_hash().as_ref() == Some(&offer.happ_hash) {
+            delete_link(link.create_link_hash)?;
+        }
+    }
+    create_link(my_pub_key, offer.happ_hash, LinkTypes::PublisherHapps, ())?;
+
+    let record = get(ownership_transfer_hash, GetOptions::default())?.ok_or(wasm_error!(
+        WasmErrorInner::Guest("Could not find the newly created OwnershipTransfer".to_string())
+    ))?;
+    Ok(record)
+}
+
+/// Returns all the ownership transfers of the hApp, from oldest to newest
+#[hdk_extern]
+pub fn get_happ_ownership_transfers(happ_hash: ActionHash) -> ExternResult<Vec<Record>> {
+    let links = get_links(
+        GetLinksInputBuilder::try_new(happ_hash, LinkTypes::HappOwnershipTransfers)?.build(),
+    )?;
+    let get_input: Vec<GetInput> = links
+        .into_iter()
+        .map(|link| {
+            Ok(GetInput::new(
+                link.target
+                    .into_action_hash()
+                    .ok_or(wasm_error!(WasmErrorInner::Guest(
+                        "No action hash associated with link".to_string()
+                    )))?
+                    .into(),
+                GetOptions::default(),
+            ))
+        })
+        .collect::<ExternResult<Vec<GetInput>>>()?;
+    let records = HDK.with(|hdk| hdk.borrow().get(get_input))?;
+    let mut records: Vec<Record> = records.into_iter().flatten().collect();
+    records.sort_by_key(|record| record.action().timestamp());
+    Ok(records)
+}
+
+/// Returns the agent that accepted the latest ownership transfer of the hApp, or its creator if it was never transferred
+///
+/// Owners can't make new offers while one is pending, so each time an agent owns the hApp at most one of their offers
+/// gets accepted: the owner is found by following those from the creator, instead of relying on the timestamps
+#[hdk_extern]
+pub fn get_happ_owner(happ_hash: ActionHash) -> ExternResult<Option<AgentPubKey>> {
+    let Some(happ_record) = get_original_happ(happ_hash.clone())? else {
+        return Ok(None);
+    };
+    // (offerer, sequence of the offer in their chain, recipient, sequence of the transfer in their chain)
+    let mut transfers: Vec<(AgentPubKey, u32, AgentPubKey, u32)> = Vec::new();
+    for transfer_record in get_happ_ownership_transfers(happ_hash)? {
+        let transfer = OwnershipTransfer::try_from(transfer_record.clone())?;
+        let Some(offer_record) = get(transfer.offer_hash, GetOptions::default())? else {
+            continue;
+        };
+        transfers.push((
+            offer_record.action().author().clone(),
+            offer_record.action().action_seq(),
+            transfer_record.action().author().clone(),
+            transfer_record.action().action_seq(),
+        ));
+    }
+    let mut owner = happ_record.action().author().clone();
+    let mut owner_since = happ_record.action().action_seq();
+    while let Some(next) = transfers
+        .iter()
+        .enumerate()
+        .filter(|(_, (offerer, offer_seq, _, _))| offerer.eq(&owner) && *offer_seq > owner_since)
+        .min_by_key(|(_, (_, offer_seq, _, _))| *offer_seq)
+        .map(|(i, _)| i)
+    {
+        let (_, _, recipient, transfer_seq) = transfers.swap_remove(next);
+        owner = recipient;
+        owner_since = transfer_seq;
+    }
+    Ok(Some(owner))
+}
+
+fn get_ownership_offer(ownership_offer_hash: ActionHash) -> ExternResult<(Record, OwnershipOffer)> {
+    let Some(Details::Record(details)) = get_details(ownership_offer_hash, GetOptions::default())?
+    else {
+        return Err(wasm_error!(WasmErrorInner::Guest(
+            "Could not find the OwnershipOffer".to_string()
+        )));
+    };
+    if !details.deletes.is_empty() {
+        return Err(wasm_error!(WasmErrorInner::Guest(
+            "The ownership offer was canceled".to_string()
+        )));
+    }
+    let offer = OwnershipOffer::try_from(details.record.clone())?;
+    Ok((details.record, offer))
+}
+
+fn get_transfer_for_offer(
+    happ_hash: &ActionHash,
+    ownership_offer_hash: &ActionHash,
+) -> ExternResult<Option<Record>> {
+    for record in get_happ_ownership_transfers(happ_hash.clone())? {
+        let transfer = OwnershipTransfer::try_from(record.clone())?;
+        if transfer.offer_hash.eq(ownership_offer_hash) {
+            return Ok(Some(record));
+        }
+    }
+    Ok(None)
+}
+
+fn delete_ownership_offer_links(
+    happ_hash: &ActionHash,
+    ownership_offer_hash: &ActionHash,
+) -> ExternResult<()> {
+    for link in get_happ_ownership_offers(happ_hash.clone())? {
+        if link.target.clone().into_action_hash().as_ref() == Some(ownership_offer_hash) {
+            delete_link(link.create_link_hash)?;
+        }
+    }
+    Ok(())
+}
@@ -0,0 +1,135 @@
+use happs_integrity::*;
+use hdk::prelude::*;
+
+use crate::{
+    revision_heads::{check_is_canonical_head, resolve_revision_heads},
+    stored_files::check_icon_is_stored,
+};
+
+#[hdk_extern]
+pub fn create_publisher(publisher: Publisher) -> ExternResult<Record> {
+    let my_pub_key = agent_info()?.agent_initial_pubkey;
+    if get_publisher_link(my_pub_key.clone())?.is_some() {
+        return Err(wasm_error!(WasmErrorInner::Guest(
+            "We already have a publisher profile, update it instead".to_string()
+        )));
+    }
+    if let Some(avatar) = publisher.avatar.clone() {
+        check_icon_is_stored(avatar)?;
+    }
+    let publisher_hash = create_entry(&EntryTypes::Publisher(publisher))?;
+    create_link(
+        my_pub_key,
+        publisher_hash.clone(),
+        LinkTypes::AgentToPublisher,
+        (),
+    )?;
+    let record = get(publisher_hash, GetOptions::default())?.ok_or(wasm_error!(
+        WasmErrorInner::Guest("Could not find the newly created Publisher".to_string())
+    ))?;
+    Ok(record)
+}
+
+/// Returns the latest revision of the publisher profile of the given agent, if they created one
+#[hdk_extern]
+pub fn get_publisher_for_agent(agent: AgentPubKey) -> ExternResult<Option<Record>> {
+    let Some(link) = get_publisher_link(agent)? else {
+        return Ok(None);
+    };
+    let original_publisher_hash =
+        link.target
+            .into_action_hash()
+            .ok_or(wasm_error!(WasmErrorInner::Guest(
+                "No action hash associated with link".to_string()
+            )))?;
+    get_latest_publisher(original_publisher_hash)
+}
+
+fn get_publisher_link(agent: AgentPubKey) -> ExternResult<Option<Link>> {
+    let mut links =
+        get_links(GetLinksInputBuilder::try_new(agent, LinkTypes::AgentToPublisher)?.build())?;
+    // An agent could end up with more than one profile if they created them from different devices at the same time
+    links.sort_by_key(|link| link.timestamp);
+    Ok(links.into_iter().next())
+}
+
+#[hdk_extern]
+pub fn get_latest_publisher(original_publisher_hash: ActionHash) -> ExternResult<Option<Record>> {
+    let mut revisions = get_all_revisions_for_publisher(original_publisher_hash)?;
+    if revisions.is_empty() {
+        return Ok(None);
+    }
+    let original_record = revisions.remove(0);
+    let heads = resolve_revision_heads(original_record, revisions)?;
+    Ok(Some(heads.canonical_head))
+}
+
+#[hdk_extern]
+pub fn get_all_revisions_for_publisher(
+    original_publisher_hash: ActionHash,
+) -> ExternResult<Vec<Record>> {
+    let Some(original_record) = get(original_publisher_hash.clone(), GetOptions::default())? else {
+        return Ok(vec![]);
+    };
+    let links = get_links(
+        GetLinksInputBuilder::try_new(original_publisher_hash, LinkTypes::PublisherUpdates)?
+            .build(),
+    )?;
+    let get_input: Vec<GetInput> = links
+        .into_iter()
+        .map(|link| {
+            Ok(GetInput::new(
+                link.target
+                    .into_action_hash()
+                    .ok_or(wasm_error!(WasmErrorInner::Guest(
+                        "No action hash associated with link".to_string()
+                    )))?
+                    .into(),
+                GetOptions::default(),
+            ))
+        })
+        .collect::<ExternResult<Vec<GetInput>>>()?;
+    let records = HDK.with(|hdk| hdk.borrow().get(get_input))?;
+    let mut records: Vec<Record> = records.into_iter().flatten().collect();
+    records.insert(0, original_record);
+    Ok(records)
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct UpdatePublisherInput {
+    pub original_publisher_hash: ActionHash,
+    pub previous_publisher_hash: ActionHash,
+    pub updated_publisher: Publisher,
+}
+
+#[hdk_extern]
+pub fn update_publisher(input: UpdatePublisherInput) -> ExternResult<Record> {
+    if let Some(avatar) = input.updated_publisher.avatar.clone() {
+        check_icon_is_stored(avatar)?;
+    }
+    let mut revisions = get_all_revisions_for_publisher(input.original_publisher_hash.clone())?;
+    if revisions.is_empty() {
+        return Err(wasm_error!(WasmErrorInner::Guest(
+            "Could not find the Publisher to update".to_string()
+        )));
+    }
+    let original_record = revisions.remove(0);
+    check_is_canonical_head(
+        resolve_revision_heads(original_record, revisions)?,
+        &input.previous_publisher_hash,
+    )?;
+    let updated_publisher_hash = update_entry(
+        input.previous_publisher_hash.clone(),
+        &input.updated_publisher,
+    )?;
+    create_link(
+        input.original_publisher_hash,
+        updated_publisher_hash.clone(),
+        LinkTypes::PublisherUpdates,
+        (),
+    )?;
+    let record = get(updated_publisher_hash, GetOptions::default())?.ok_or(wasm_error!(
+        WasmErrorInner::Guest("Could not find the newly updated Publisher".to_string())
+    ))?;
+    Ok(record)
+}
@@ -0,0 +1,70 @@
+use happs_integrity::*;
+use hdk::prelude::*;
+
+/// Signs the credential that lets the given agent publish hApps, which only the progenitor can do
+///
+/// The agent can use it as their membrane proof when installing the app, or commit it with `commit_publisher_credential`
+#[hdk_extern]
+pub fn sign_publisher_credential(agent: AgentPubKey) -> ExternResult<PublisherCredential> {
+    let my_pub_key = agent_info()?.agent_initial_pubkey;
+    if !happs_properties()?.is_progenitor(&my_pub_key) {
+        return Err(wasm_error!(WasmErrorInner::Guest(
+            "Only the progenitor can issue publisher credentials".to_string()
+        )));
+    }
+    let signature = sign(
+        my_pub_key,
+        PublisherCredentialPayload {
+            agent: agent.clone(),
+        },
+    )?;
+    Ok(PublisherCredential { agent, signature })
+}
+
+/// Lets us publish hApps if we joined the network without a publisher credential
+#[hdk_extern]
+pub fn commit_publisher_credential(credential: PublisherCredential) -> ExternResult<Record> {
+    let credential_hash = create_entry(&EntryTypes::PublisherCredential(credential))?;
+    let record = get(credential_hash, GetOptions::default())?.ok_or(wasm_error!(
+        WasmErrorInner::Guest("Could not find the newly created PublisherCredential".to_string())
+    ))?;
+    Ok(record)
+}
+
+/// Returns whether we can publish hApps, mirroring the validation of the hApps and releases we create
+#[hdk_extern]
+pub fn can_publish() -> ExternResult<bool> {
+    let properties = happs_properties()?;
+    if properties.progenitor.is_none()
+        || properties.is_progenitor(&agent_info()?.agent_initial_pubkey)
+    {
+        return Ok(true);
+    }
+    let has_membrane_proof =
+        query(ChainQueryFilter::new().action_type(ActionType::AgentValidationPkg))?
+            .iter()
+            .any(|record| {
+                matches!(
+                    record.action(),
+                    Action::AgentValidationPkg(AgentValidationPkg {
+                        membrane_proof: Some(_),
+                        ..
+                    })
+                )
+            });
+    if has_membrane_proof {
+        return Ok(true);
+    }
+    let credentials =
+        query(ChainQueryFilter::new().entry_type(UnitEntryTypes::PublisherCredential.try_into()?))?;
+    Ok(!credentials.is_empty())
+}
+
+pub fn ensure_can_publish() -> ExternResult<()> {
+    if !can_publish(())? {
+        return Err(wasm_error!(WasmErrorInner::Guest(
+            "We need a publisher credential signed by the progenitor to publish hApps".to_string()
+        )));
+    }
+    Ok(())
+}
@@ -1,7 +1,20 @@
 use happs_integrity::*;
 use hdk::prelude::*;
 
+use crate::happ_unpublished::retain_listed_happs;
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct GetPublisherHappsInput {
+    pub publisher: AgentPubKey,
+    /// Publishers set this to manage all of their hApps, including the ones they unpublished
+    #[serde(default)]
+    pub include_unpublished: bool,
+}
+
 #[hdk_extern]
-pub fn get_publisher_happs(author: AgentPubKey) -> ExternResult<Vec<Link>> {
-    get_links(GetLinksInputBuilder::try_new(author, LinkTypes::PublisherHapps)?.build())
+pub fn get_publisher_happs(input: GetPublisherHappsInput) -> ExternResult<Vec<Link>> {
+    let links = get_links(
+        GetLinksInputBuilder::try_new(input.publisher, LinkTypes::PublisherHapps)?.build(),
+    )?;
+    retain_listed_happs(links, input.include_unpublished)
 }
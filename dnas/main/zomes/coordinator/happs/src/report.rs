@@ -0,0 +1,124 @@
+use happs_integrity::*;
+use hdk::prelude::*;
+
+use crate::moderation::ensure_moderator_claim;
+
+#[hdk_extern]
+pub fn create_report(report: Report) -> ExternResult<Record> {
+    let report_hash = create_entry(&EntryTypes::Report(report.clone()))?;
+    create_link(
+        report.happ_hash,
+        report_hash.clone(),
+        LinkTypes::HappReports,
+        (),
+    )?;
+    create_link(
+        open_reports_path().path_entry_hash()?,
+        report_hash.clone(),
+        LinkTypes::OpenReports,
+        (),
+    )?;
+    let record = get(report_hash, GetOptions::default())?.ok_or(wasm_error!(
+        WasmErrorInner::Guest("Could not find the newly created Report".to_string())
+    ))?;
+    Ok(record)
+}
+
+#[hdk_extern]
+pub fn get_report(report_hash: ActionHash) -> ExternResult<Option<Record>> {
+    get(report_hash, GetOptions::default())
+}
+
+/// Returns the links to all the reports of the hApp, resolved or not
+#[hdk_extern]
+pub fn get_reports_for_happ(happ_hash: ActionHash) -> ExternResult<Vec<Link>> {
+    get_links(GetLinksInputBuilder::try_new(happ_hash, LinkTypes::HappReports)?.build())
+}
+
+/// Returns the links to the reports that no moderator resolved yet, which make up the moderation queue
+#[hdk_extern]
+pub fn get_open_reports() -> ExternResult<Vec<Link>> {
+    get_links(
+        GetLinksInputBuilder::try_new(
+            open_reports_path().path_entry_hash()?,
+            LinkTypes::OpenReports,
+        )?
+        .build(),
+    )
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct ResolveReportInput {
+    pub report_hash: ActionHash,
+    pub decision: ReportDecision,
+    pub notes: String,
+}
+
+/// Records the decision of the moderator on the report, and removes it from the moderation queue
+///
+/// Fails if the report was already resolved, or is not in the moderation queue anymore
+#[hdk_extern]
+pub fn resolve_report(input: ResolveReportInput) -> ExternResult<Record> {
+    ensure_moderator_claim()?;
+    if !get_report_resolutions(input.report_hash.clone())?.is_empty() {
+        return Err(wasm_error!(WasmErrorInner::Guest(
+            "The report was already resolved".to_string()
+        )));
+    }
+    let open_report_links: Vec<Link> = get_open_reports(())?
+        .into_iter()
+        .filter(|link| link.target.clone().into_action_hash().as_ref() == Some(&input.report_hash))
+        .collect();
+    if open_report_links.is_empty() {
+        return Err(wasm_error!(WasmErrorInner::Guest(
+            "The report is not open".to_string()
+        )));
+    }
+    let report_resolution = ReportResolution {
+        report_hash: input.report_hash.clone(),
+        decision: input.decision,
+        notes: input.notes,
+    };
+    let report_resolution_hash = create_entry(&EntryTypes::ReportResolution(report_resolution))?;
+    create_link(
+        input.report_hash.clone(),
+        report_resolution_hash.clone(),
+        LinkTypes::ReportResolutions,
+        (),
+    )?;
+    for link in open_report_links {
+        delete_link(link.create_link_hash)?;
+    }
+    let record = get(report_resolution_hash, GetOptions::default())?.ok_or(wasm_error!(
+        WasmErrorInner::Guest("Could not find the newly created ReportResolution".to_string())
+    ))?;
+    Ok(record)
+}
+
+/// Returns the resolutions of the report, from oldest to newest
+///
+/// A report only has more than one resolution if moderators resolved it concurrently
+#[hdk_extern]
+pub fn get_report_resolutions(report_hash: ActionHash) -> ExternResult<Vec<Record>> {
+    let links = get_links(
+        GetLinksInputBuilder::try_new(report_hash, LinkTypes::ReportResolutions)?.build(),
+    )?;
+    let get_input: Vec<GetInput> = links
+        .into_iter()
+        .map(|link| {
+            Ok(GetInput::new(
+                link.target
+                    .into_action_hash()
+                    .ok_or(wasm_error!(WasmErrorInner::Guest(
+                        "No action hash associated with link".to_string()
+                    )))?
+                    .into(),
+                GetOptions::default(),
+            ))
+        })
+        .collect::<ExternResult<Vec<GetInput>>>()?;
+    let records = HDK.with(|hdk| hdk.borrow().get(get_input))?;
+    let mut records: Vec<Record> = records.into_iter().flatten().collect();
+    records.sort_by_key(|record| record.action().timestamp());
+    Ok(records)
+}
@@ -0,0 +1,264 @@
+use std::collections::HashSet;
+
+use happs_integrity::*;
+use hdk::prelude::*;
+
+use crate::revision_heads::{check_is_canonical_head, resolve_revision_heads};
+
+#[hdk_extern]
+pub fn create_review(review: Review) -> ExternResult<Record> {
+    let my_pub_key = agent_info()?.agent_initial_pubkey;
+    if get_happ_reviews(review.happ_hash.clone())?
+        .iter()
+        .any(|link| link.author.eq(&my_pub_key))
+    {
+        return Err(wasm_error!(WasmErrorInner::Guest(
+            "We already reviewed this hApp, update the review instead".to_string()
+        )));
+    }
+    let review_hash = create_entry(&EntryTypes::Review(review.clone()))?;
+    create_link(
+        review.happ_hash,
+        review_hash.clone(),
+        LinkTypes::HappReviews,
+        (),
+    )?;
+    let record = get(review_hash, GetOptions::default())?.ok_or(wasm_error!(
+        WasmErrorInner::Guest("Could not find the newly created Review".to_string())
+    ))?;
+    Ok(record)
+}
+
+/// Returns the links to the active reviews of the hApp, which point to their original revision
+#[hdk_extern]
+pub fn get_happ_reviews(happ_hash: ActionHash) -> ExternResult<Vec<Link>> {
+    get_links(GetLinksInputBuilder::try_new(happ_hash, LinkTypes::HappReviews)?.build())
+}
+
+#[hdk_extern]
+pub fn get_latest_review(original_review_hash: ActionHash) -> ExternResult<Option<Record>> {
+    let mut revisions = get_all_revisions_for_review(original_review_hash)?;
+    if revisions.is_empty() {
+        return Ok(None);
+    }
+    let original_record = revisions.remove(0);
+    let heads = resolve_revision_heads(original_record, revisions)?;
+    Ok(Some(heads.canonical_head))
+}
+
+#[hdk_extern]
+pub fn get_all_revisions_for_review(original_review_hash: ActionHash) -> ExternResult<Vec<Record>> {
+    let Some(original_record) = get(original_review_hash.clone(), GetOptions::default())? else {
+        return Ok(vec![]);
+    };
+    let links = get_links(
+        GetLinksInputBuilder::try_new(original_review_hash, LinkTypes::ReviewUpdates)?.build(),
+    )?;
+    let get_input: Vec<GetInput> = links
+        .into_iter()
+        .map(|link| {
+            Ok(GetInput::new(
+                link.target
+                    .into_action_hash()
+                    .ok_or(wasm_error!(WasmErrorInner::Guest(
+                        "No action hash associated with link".to_string()
+                    )))?
+                    .into(),
+                GetOptions::default(),
+            ))
+        })
+        .collect::<ExternResult<Vec<GetInput>>>()?;
+    let records = HDK.with(|hdk| hdk.borrow().get(get_input))?;
+    let mut records: Vec<Record> = records.into_iter().flatten().collect();
+    records.insert(0, original_record);
+    Ok(records)
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct UpdateReviewInput {
+    pub original_review_hash: ActionHash,
+    pub previous_review_hash: ActionHash,
+    pub updated_review: Review,
+}
+
+#[hdk_extern]
+pub fn update_review(input: UpdateReviewInput) -> ExternResult<Record> {
+    let mut revisions = get_all_revisions_for_review(input.original_review_hash.clone())?;
+    if revisions.is_empty() {
+        return Err(wasm_error!(WasmErrorInner::Guest(
+            "Could not find the Review to update".to_string()
+        )));
+    }
+    let original_record = revisions.remove(0);
+    check_is_canonical_head(
+        resolve_revision_heads(original_record, revisions)?,
+        &input.previous_review_hash,
+    )?;
+    let updated_review_hash =
+        update_entry(input.previous_review_hash.clone(), &input.updated_review)?;
+    create_link(
+        input.original_review_hash,
+        updated_review_hash.clone(),
+        LinkTypes::ReviewUpdates,
+        (),
+    )?;
+    let record = get(updated_review_hash, GetOptions::default())?.ok_or(wasm_error!(
+        WasmErrorInner::Guest("Could not find the newly updated Review".to_string())
+    ))?;
+    Ok(record)
+}
+
+/// Deleting our review of a hApp lets us review it again
+#[hdk_extern]
+pub fn delete_review(original_review_hash: ActionHash) -> ExternResult<ActionHash> {
+    let record = get(original_review_hash.clone(), GetOptions::default())?.ok_or(wasm_error!(
+        WasmErrorInner::Guest("Could not find the Review to delete".to_string())
+    ))?;
+    let review = Review::try_from(record)?;
+    for link in get_happ_reviews(review.happ_hash)? {
+        if link.target.clone().into_action_hash().as_ref() == Some(&original_review_hash) {
+            delete_link(link.create_link_hash)?;
+        }
+    }
+    delete_entry(original_review_hash)
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+pub struct RatingSummary {
+    pub reviews_count: u32,
+    /// None if the hApp has no reviews yet
+    pub average_rating: Option<f64>,
+    /// The number of reviews with each rating, from 1 star to 5 stars
+    pub histogram: [u32; MAX_REVIEW_RATING as usize],
+}
+
+/// Returns the links to the reviews that count for the ratings of the hApp: the latest one of each author that
+/// was not deleted
+///
+/// Validation only lets agents link one review at a time, but links from before that rule, or that we got before
+/// their deletion, could still count an agent more than once
+fn get_rated_review_links(happ_hash: ActionHash) -> ExternResult<Vec<Link>> {
+    let mut links = get_happ_reviews(happ_hash)?;
+    links.sort_by_key(|link| std::cmp::Reverse(link.timestamp));
+    let mut authors = HashSet::new();
+    let mut rated_links = vec![];
+    for link in links {
+        if authors.contains(&link.author) {
+            continue;
+        }
+        let Some(review_hash) = link.target.clone().into_action_hash() else {
+            continue;
+        };
+        let Some(Details::Record(details)) = get_details(review_hash, GetOptions::default())?
+        else {
+            continue;
+        };
+        if !details.deletes.is_empty() {
+            continue;
+        }
+        authors.insert(link.author.clone());
+        rated_links.push(link);
+    }
+    Ok(rated_links)
+}
+
+/// Summarizes the ratings of the latest revisions of the active reviews of the hApp, one per reviewer
+#[hdk_extern]
+pub fn get_rating_summary(happ_hash: ActionHash) -> ExternResult<RatingSummary> {
+    let mut histogram = [0; MAX_REVIEW_RATING as usize];
+    for link in get_rated_review_links(happ_hash)? {
+        let Some(original_review_hash) = link.target.into_action_hash() else {
+            continue;
+        };
+        let Some(record) = get_latest_review(original_review_hash)? else {
+            continue;
+        };
+        let review = Review::try_from(record)?;
+        if (MIN_REVIEW_RATING..=MAX_REVIEW_RATING).contains(&review.rating) {
+            histogram[(review.rating - MIN_REVIEW_RATING) as usize] += 1;
+        }
+    }
+    let reviews_count: u32 = histogram.iter().sum();
+    let ratings_sum: u32 = histogram
+        .iter()
+        .enumerate()
+        .map(|(i, count)| (i as u32 + MIN_REVIEW_RATING as u32) * count)
+        .sum();
+    let average_rating = match reviews_count {
+        0 => None,
+        _ => Some(ratings_sum as f64 / reviews_count as f64),
+    };
+    Ok(RatingSummary {
+        reviews_count,
+        average_rating,
+        histogram,
+    })
+}
+
+/// Only the owner of the hApp can reply to its reviews, once per review
+#[hdk_extern]
+pub fn create_review_reply(review_reply: ReviewReply) -> ExternResult<Record> {
+    let my_pub_key = agent_info()?.agent_initial_pubkey;
+    if get_review_reply_links(review_reply.review_hash.clone())?
+        .iter()
+        .any(|link| link.author.eq(&my_pub_key))
+    {
+        return Err(wasm_error!(WasmErrorInner::Guest(
+            "We already replied to this review".to_string()
+        )));
+    }
+    let review_reply_hash = create_entry(&EntryTypes::ReviewReply(review_reply.clone()))?;
+    create_link(
+        review_reply.review_hash,
+        review_reply_hash.clone(),
+        LinkTypes::ReviewReplies,
+        (),
+    )?;
+    let record = get(review_reply_hash, GetOptions::default())?.ok_or(wasm_error!(
+        WasmErrorInner::Guest("Could not find the newly created ReviewReply".to_string())
+    ))?;
+    Ok(record)
+}
+
+fn get_review_reply_links(review_hash: ActionHash) -> ExternResult<Vec<Link>> {
+    get_links(GetLinksInputBuilder::try_new(review_hash, LinkTypes::ReviewReplies)?.build())
+}
+
+/// Returns the replies to the review, from oldest to newest
+///
+/// A review only has more than one reply if the hApp changed owners after the first one
+#[hdk_extern]
+pub fn get_review_replies(review_hash: ActionHash) -> ExternResult<Vec<Record>> {
+    let get_input: Vec<GetInput> = get_review_reply_links(review_hash)?
+        .into_iter()
+        .map(|link| {
+            Ok(GetInput::new(
+                link.target
+                    .into_action_hash()
+                    .ok_or(wasm_error!(WasmErrorInner::Guest(
+                        "No action hash associated with link".to_string()
+                    )))?
+                    .into(),
+                GetOptions::default(),
+            ))
+        })
+        .collect::<ExternResult<Vec<GetInput>>>()?;
+    let records = HDK.with(|hdk| hdk.borrow().get(get_input))?;
+    let mut records: Vec<Record> = records.into_iter().flatten().collect();
+    records.sort_by_key(|record| record.action().timestamp());
+    Ok(records)
+}
+
+#[hdk_extern]
+pub fn delete_review_reply(review_reply_hash: ActionHash) -> ExternResult<ActionHash> {
+    let record = get(review_reply_hash.clone(), GetOptions::default())?.ok_or(wasm_error!(
+        WasmErrorInner::Guest("Could not find the ReviewReply to delete".to_string())
+    ))?;
+    let review_reply = ReviewReply::try_from(record)?;
+    for link in get_review_reply_links(review_reply.review_hash)? {
+        if link.target.clone().into_action_hash().as_ref() == Some(&review_reply_hash) {
+            delete_link(link.create_link_hash)?;
+        }
+    }
+    delete_entry(review_reply_hash)
+}
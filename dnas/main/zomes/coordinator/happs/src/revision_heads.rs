@@ -0,0 +1,108 @@
+use std::collections::{HashMap, HashSet};
+
+use hdk::prelude::*;
+
+use crate::zome_errors::serialized_guest_error;
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct RevisionHeads {
+    /// The revision that is considered the latest one
+    pub canonical_head: Record,
+    /// Other revisions that were not updated further, which happens when the entry is edited concurrently
+    pub conflicting_heads: Vec<Record>,
+}
+
+/// Finds the heads of the tree of updates made to an entry
+///
+/// Each update points to the revision it updates, so the heads are the revisions that no other revision updates
+/// The canonical head is the one at the end of the longest chain of updates, ties are broken by timestamp and then by hash,
+/// so that every agent resolves the same head
+pub fn resolve_revision_heads(
+    original_record: Record,
+    updates: Vec<Record>,
+) -> ExternResult<RevisionHeads> {
+    let parents: HashMap<ActionHash, ActionHash> = updates
+        .iter()
+        .filter_map(|record| match record.action() {
+            Action::Update(update) => Some((
+                record.action_address().clone(),
+                update.original_action_address.clone(),
+            )),
+            _ => None,
+        })
+        .collect();
+    let updated: HashSet<&ActionHash> = parents.values().collect();
+
+    let depth = |action_hash: &ActionHash| -> usize {
+        let mut depth = 0;
+        let mut current = action_hash;
+        while let Some(parent) = parents.get(current) {
+            depth += 1;
+            // Guards against malformed revisions, a valid chain is never longer than the number of updates
+            if depth > parents.len() {
+                break;
+            }
+            current = parent;
+        }
+        depth
+    };
+
+    let mut seen = HashSet::new();
+    let mut heads: Vec<(usize, Record)> = std::iter::once(original_record)
+        .chain(updates)
+        .filter(|record| seen.insert(record.action_address().clone()))
+        .filter(|record| !updated.contains(record.action_address()))
+        .map(|record| (depth(record.action_address()), record))
+        .collect();
+    heads.sort_by(|(depth_a, a), (depth_b, b)| {
+        depth_b
+            .cmp(depth_a)
+            .then_with(|| b.action().timestamp().cmp(&a.action().timestamp()))
+            .then_with(|| a.action_address().cmp(b.action_address()))
+    });
+    let mut heads = heads.into_iter().map(|(_, record)| record);
+    let canonical_head = heads.next().ok_or(wasm_error!(WasmErrorInner::Guest(
+        "Could not find the latest revision".to_string()
+    )))?;
+    Ok(RevisionHeads {
+        canonical_head,
+        conflicting_heads: heads.collect(),
+    })
+}
+
+/// Returned when updating a revision that is not the latest one anymore, which would create a conflicting head
+#[derive(Serialize, Deserialize, Debug)]
+pub struct UpdateConflictError {
+    pub current_head: Box<Record>,
+}
+
+/// Guest errors can only carry a string, so update conflicts are returned as this prefix followed by
+/// the MessagePack serialization of the `UpdateConflictError` encoded as hex, which the client decodes
+pub const UPDATE_CONFLICT_ERROR_PREFIX: &str = "UpdateConflictError:";
+
+impl From<UpdateConflictError> for WasmError {
+    fn from(err: UpdateConflictError) -> Self {
+        serialized_guest_error(
+            UPDATE_CONFLICT_ERROR_PREFIX,
+            err,
+            "The revision being updated is not the latest one".to_string(),
+        )
+    }
+}
+
+/// Checks that the given revision is the canonical head, so that updating it doesn't fork the chain of updates
+pub fn check_is_canonical_head(
+    heads: RevisionHeads,
+    previous_action_hash: &ActionHash,
+) -> Result<(), UpdateConflictError> {
+    if heads
+        .canonical_head
+        .action_address()
+        .ne(previous_action_hash)
+    {
+        return Err(UpdateConflictError {
+            current_head: Box::new(heads.canonical_head),
+        });
+    }
+    Ok(())
+}
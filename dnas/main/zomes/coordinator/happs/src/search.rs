@@ -0,0 +1,232 @@
+use std::collections::BTreeMap;
+
+use happs_integrity::*;
+use hdk::prelude::*;
+
+use crate::happ_unpublished::retain_listed_happs;
+
+/// Maximum number of tokens of a query that are looked up in the search index
+const MAX_QUERY_TOKENS: usize = 10;
+
+/// Returns the search index links to the given hApp from each of the given paths
+fn get_search_index_links(happ_hash: &ActionHash, paths: &[Path]) -> ExternResult<Vec<Link>> {
+    let links = get_search_index_paths_links(paths)?;
+    Ok(links
+        .into_iter()
+        .filter(|link| link.target.clone().into_action_hash().as_ref() == Some(happ_hash))
+        .collect())
+}
+
+fn get_search_index_paths_links(paths: &[Path]) -> ExternResult<Vec<Link>> {
+    let inputs = paths
+        .iter()
+        .map(|path| {
+            Ok(
+                GetLinksInputBuilder::try_new(path.path_entry_hash()?, LinkTypes::SearchIndex)?
+                    .build(),
+            )
+        })
+        .collect::<ExternResult<Vec<GetLinksInput>>>()?;
+    let links = HDK.with(|hdk| hdk.borrow().get_links(inputs))?;
+    Ok(links.into_iter().flatten().collect())
+}
+
+fn unique_paths<'a>(entries: impl Iterator<Item = &'a SearchIndexEntry>) -> Vec<Path> {
+    let mut paths: Vec<Path> = vec![];
+    for entry in entries {
+        let path = entry.path();
+        if !paths.contains(&path) {
+            paths.push(path);
+        }
+    }
+    paths
+}
+
+/// Makes the search index point to the hApp for the tokens of its current name and description,
+/// removing the links for the tokens that the previous revision had and the current one doesn't
+///
+/// Like the category and tags indexes, missing links are created even if the name and description
+/// didn't change, so that the hApps created before the search index existed are indexed the next
+/// time they are updated
+pub fn index_happ_search(
+    happ_hash: &ActionHash,
+    previous_happ: Option<&Happ>,
+    happ: &Happ,
+) -> ExternResult<()> {
+    let entries = happ_search_entries(happ);
+    let previous_entries = previous_happ.map(happ_search_entries).unwrap_or_default();
+    let paths = unique_paths(entries.iter().chain(previous_entries.iter()));
+    let existing_links = get_search_index_links(happ_hash, &paths)?;
+
+    // Only one link per current entry is kept, any other link to the hApp from these paths is stale
+    let mut indexed_entries: Vec<SearchIndexEntry> = vec![];
+    for link in existing_links {
+        match SearchIndexEntry::from_link_tag(&link.tag) {
+            Some(entry)
+                if entries.contains(&entry)
+                    && !indexed_entries.contains(&entry)
+                    && link.base == entry.path().path_entry_hash()?.into() =>
+            {
+                indexed_entries.push(entry);
+            }
+            _ => {
+                delete_link(link.create_link_hash)?;
+            }
+        }
+    }
+
+    for entry in entries {
+        if indexed_entries.contains(&entry) {
+            continue;
+        }
+        create_link(
+            entry.path().path_entry_hash()?,
+            happ_hash.clone(),
+            LinkTypes::SearchIndex,
+            entry.link_tag(),
+        )?;
+    }
+    Ok(())
+}
+
+/// Removes all the links from the search index to the hApp
+pub fn unindex_happ_search(happ_hash: &ActionHash, happ: &Happ) -> ExternResult<()> {
+    let entries = happ_search_entries(happ);
+    for link in get_search_index_links(happ_hash, &unique_paths(entries.iter()))? {
+        delete_link(link.create_link_hash)?;
+    }
+    Ok(())
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct SearchHappsInput {
+    pub query: String,
+    #[serde(default)]
+    pub include_unpublished: bool,
+}
+
+/// How a word of a query matched the index entries of a hApp
+#[derive(Debug, Default)]
+struct TokenMatch {
+    in_name: bool,
+    whole_token: bool,
+}
+
+/// The index entries of a hApp that matched a query
+#[derive(Debug)]
+struct HappMatch {
+    link: Link,
+    exact_name: bool,
+    /// Indexed by the position of the matched word in the query
+    tokens: BTreeMap<usize, TokenMatch>,
+}
+
+/// How well a hApp matches a query, compared so that better matches are greater
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord)]
+struct SearchScore {
+    exact_name: bool,
+    matched_tokens: usize,
+    name_matches: usize,
+    whole_token_matches: usize,
+}
+
+impl HappMatch {
+    fn score(&self) -> SearchScore {
+        SearchScore {
+            exact_name: self.exact_name,
+            matched_tokens: self.tokens.len(),
+            name_matches: self.tokens.values().filter(|m| m.in_name).count(),
+            whole_token_matches: self.tokens.values().filter(|m| m.whole_token).count(),
+        }
+    }
+}
+
+/// Returns one link per hApp that matches the query, best matches first
+///
+/// Each word of the query matches the words of the names and the keywords of the descriptions that
+/// start with it. hApps whose whole name is the query come first, followed by the ones that match
+/// more words of the query, and among those the ones that match them in their name
+///
+/// Words of the query shorter than `SEARCH_PREFIX_LENGTH` only match the words of the names, as the
+/// keywords of the descriptions are not indexed under shorter prefixes
+#[hdk_extern]
+pub fn search_happs(input: SearchHappsInput) -> ExternResult<Vec<Link>> {
+    let query_tokens = normalize_tokens(&input.query);
+    if query_tokens.is_empty() {
+        return Ok(vec![]);
+    }
+    let exact_name = truncate_search_token(&query_tokens.join(" "));
+    let mut keyword_tokens: Vec<String> = vec![];
+    for token in &query_tokens {
+        if !STOP_WORDS.contains(&token.as_str()) && !keyword_tokens.contains(token) {
+            keyword_tokens.push(token.clone());
+        }
+    }
+    keyword_tokens.truncate(MAX_QUERY_TOKENS);
+
+    let paths: Vec<Path> = query_tokens
+        .first()
+        .into_iter()
+        .chain(keyword_tokens.iter())
+        .map(|token| search_index_path(token))
+        .fold(vec![], |mut paths, path| {
+            if !paths.contains(&path) {
+                paths.push(path);
+            }
+            paths
+        });
+    let links = get_search_index_paths_links(&paths)?;
+
+    let mut matches: BTreeMap<ActionHash, HappMatch> = BTreeMap::new();
+    for link in links {
+        let Some(happ_hash) = link.target.clone().into_action_hash() else {
+            continue;
+        };
+        let Some(entry) = SearchIndexEntry::from_link_tag(&link.tag) else {
+            continue;
+        };
+        let is_exact_name = entry.field == SearchField::ExactName && entry.token.eq(&exact_name);
+        let matched_tokens: Vec<usize> = match entry.field {
+            SearchField::ExactName => vec![],
+            SearchField::NamePrefix => keyword_tokens
+                .iter()
+                .enumerate()
+                .filter(|(_, token)| entry.token.eq(*token))
+                .map(|(i, _)| i)
+                .collect(),
+            SearchField::Name | SearchField::Description => keyword_tokens
+                .iter()
+                .enumerate()
+                .filter(|(_, token)| entry.token.starts_with(token.as_str()))
+                .map(|(i, _)| i)
+                .collect(),
+        };
+        if !is_exact_name && matched_tokens.is_empty() {
+            continue;
+        }
+        let happ_match = matches.entry(happ_hash).or_insert_with(|| HappMatch {
+            link,
+            exact_name: false,
+            tokens: BTreeMap::new(),
+        });
+        happ_match.exact_name |= is_exact_name;
+        for i in matched_tokens {
+            let token_match = happ_match.tokens.entry(i).or_default();
+            token_match.in_name |=
+                matches!(entry.field, SearchField::Name | SearchField::NamePrefix);
+            token_match.whole_token |=
+                entry.field != SearchField::NamePrefix && entry.token.eq(&keyword_tokens[i]);
+        }
+    }
+
+    let mut results: Vec<(SearchScore, ActionHash, Link)> = matches
+        .into_iter()
+        .map(|(happ_hash, happ_match)| (happ_match.score(), happ_hash, happ_match.link))
+        .collect();
+    results.sort_by(|(score_a, hash_a, _), (score_b, hash_b, _)| {
+        score_b.cmp(score_a).then_with(|| hash_a.cmp(hash_b))
+    });
+    let links: Vec<Link> = results.into_iter().map(|(_, _, link)| link).collect();
+
+    retain_listed_happs(links, input.include_unpublished)
+}
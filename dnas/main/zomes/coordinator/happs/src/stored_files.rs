@@ -0,0 +1,167 @@
+use hdk::prelude::*;
+
+use crate::zome_errors::serialized_guest_error;
+
+pub const FILE_STORAGE_GATEWAY_ZOME_NAME: &str = "file_storage_gateway";
+
+pub const MAX_ICON_SIZE: usize = 1024 * 1024;
+pub const MAX_WEB_HAPP_BUNDLE_SIZE: usize = 256 * 1024 * 1024;
+
+// Browsers don't know the .webhapp extension, so they upload the bundles with a generic type
+const WEB_HAPP_BUNDLE_FILE_TYPES: [&str; 4] = [
+    "",
+    "application/octet-stream",
+    "application/gzip",
+    "application/x-gzip",
+];
+
+/// The fields of the `FileMetadata` entry from the file storage zomes that are needed to check the stored files
+#[derive(Serialize, Deserialize, Debug)]
+pub struct FileMetadata {
+    pub name: String,
+    pub size: usize,
+    pub file_type: String,
+}
+
+pub const STORED_FILE_ERROR_PREFIX: &str = "StoredFileError:";
+
+#[derive(Serialize, Deserialize, Debug)]
+#[serde(tag = "type")]
+pub enum StoredFileError {
+    NotFound {
+        file_hash: EntryHash,
+    },
+    /// The file storage couldn't be asked for the file, so whether it is stored is unknown
+    Unavailable {
+        file_hash: EntryHash,
+        reason: String,
+    },
+    UnexpectedFileType {
+        file_hash: EntryHash,
+        file_type: String,
+    },
+    TooLarge {
+        file_hash: EntryHash,
+        size: usize,
+        max_size: usize,
+    },
+}
+
+impl std::fmt::Display for StoredFileError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            StoredFileError::NotFound { file_hash } => {
+                write!(f, "File {file_hash} was not found in the file storage")
+            }
+            StoredFileError::Unavailable { file_hash, reason } => write!(
+                f,
+                "Could not get file {file_hash} from the file storage: {reason}"
+            ),
+            StoredFileError::UnexpectedFileType {
+                file_hash,
+                file_type,
+            } => write!(f, "File {file_hash} has an unexpected type: {file_type:?}"),
+            StoredFileError::TooLarge {
+                file_hash,
+                size,
+                max_size,
+            } => write!(
+                f,
+                "File {file_hash} is too large: {size} bytes, the maximum is {max_size} bytes"
+            ),
+        }
+    }
+}
+
+impl From<StoredFileError> for WasmError {
+    fn from(err: StoredFileError) -> Self {
+        let message = err.to_string();
+        serialized_guest_error(STORED_FILE_ERROR_PREFIX, err, message)
+    }
+}
+
+/// Only the gateway reporting that the file doesn't exist, by returning no metadata or failing with a not found
+/// error, is a `NotFound` error: any other failure leaves it unknown whether the file is stored
+fn fetch_file_metadata(file_hash: EntryHash) -> Result<FileMetadata, StoredFileError> {
+    let unavailable = |reason: String| StoredFileError::Unavailable {
+        file_hash: file_hash.clone(),
+        reason,
+    };
+    let response = call(
+        CallTargetCell::Local,
+        ZomeName::from(FILE_STORAGE_GATEWAY_ZOME_NAME),
+        FunctionName::from("fetch_file_metadata"),
+        None,
+        file_hash.clone(),
+    )
+    .map_err(|err| {
+        let reason = err.to_string();
+        match reason.to_lowercase().contains("not found") {
+            true => StoredFileError::NotFound {
+                file_hash: file_hash.clone(),
+            },
+            false => unavailable(reason),
+        }
+    })?;
+    let result = match response {
+        ZomeCallResponse::Ok(result) => result,
+        ZomeCallResponse::Unauthorized(..) => {
+            return Err(unavailable(
+                "Not authorized to call the file storage gateway".to_string(),
+            ))
+        }
+        ZomeCallResponse::NetworkError(err) => return Err(unavailable(err)),
+        ZomeCallResponse::CountersigningSession(err) => return Err(unavailable(err)),
+        ZomeCallResponse::AuthenticationFailed(..) => {
+            return Err(unavailable(
+                "Could not authenticate the call to the file storage gateway".to_string(),
+            ))
+        }
+    };
+    let metadata: Option<FileMetadata> = result
+        .decode()
+        .map_err(|err| unavailable(format!("Malformed file metadata: {err:?}")))?;
+    metadata.ok_or(StoredFileError::NotFound { file_hash })
+}
+
+/// Checks that the icon of a hApp, or the avatar of a publisher, is an image stored in the file storage
+pub fn check_icon_is_stored(icon: EntryHash) -> Result<(), StoredFileError> {
+    let metadata = fetch_file_metadata(icon.clone())?;
+    if !metadata.file_type.starts_with("image/") {
+        return Err(StoredFileError::UnexpectedFileType {
+            file_hash: icon,
+            file_type: metadata.file_type,
+        });
+    }
+    if metadata.size > MAX_ICON_SIZE {
+        return Err(StoredFileError::TooLarge {
+            file_hash: icon,
+            size: metadata.size,
+            max_size: MAX_ICON_SIZE,
+        });
+    }
+    Ok(())
+}
+
+/// Checks that the bundle of a hApp release is a .webhapp file stored in the file storage
+pub fn check_web_happ_bundle_is_stored(
+    web_happ_bundle_hash: EntryHash,
+) -> Result<(), StoredFileError> {
+    let metadata = fetch_file_metadata(web_happ_bundle_hash.clone())?;
+    if !metadata.name.to_lowercase().ends_with(".webhapp")
+        || !WEB_HAPP_BUNDLE_FILE_TYPES.contains(&metadata.file_type.as_str())
+    {
+        return Err(StoredFileError::UnexpectedFileType {
+            file_hash: web_happ_bundle_hash,
+            file_type: metadata.file_type,
+        });
+    }
+    if metadata.size > MAX_WEB_HAPP_BUNDLE_SIZE {
+        return Err(StoredFileError::TooLarge {
+            file_hash: web_happ_bundle_hash,
+            size: metadata.size,
+            max_size: MAX_WEB_HAPP_BUNDLE_SIZE,
+        });
+    }
+    Ok(())
+}
@@ -0,0 +1,61 @@
+//! Zome functions that create links that the other zome functions never would, so that the tests can check that
+//! validation rejects them
+//!
+//! Only compiled in debug builds, which are the ones the tests run against
+
+use happs_integrity::*;
+use hdk::prelude::*;
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct CreateSearchIndexLinkInput {
+    pub happ_hash: ActionHash,
+    pub field: SearchField,
+    pub token: String,
+}
+
+#[hdk_extern]
+pub fn create_search_index_link(input: CreateSearchIndexLinkInput) -> ExternResult<ActionHash> {
+    let entry = SearchIndexEntry {
+        field: input.field,
+        token: input.token,
+    };
+    create_link(
+        entry.path().path_entry_hash()?,
+        input.happ_hash,
+        LinkTypes::SearchIndex,
+        entry.link_tag(),
+    )
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct CreateHappToHappReleasesLinkInput {
+    pub happ_hash: ActionHash,
+    pub happ_release_hash: ActionHash,
+}
+
+#[hdk_extern]
+pub fn create_happ_to_happ_releases_link(
+    input: CreateHappToHappReleasesLinkInput,
+) -> ExternResult<ActionHash> {
+    let happ_release = HappRelease::try_from(
+        get(input.happ_release_hash.clone(), GetOptions::default())?.ok_or(wasm_error!(
+            WasmErrorInner::Guest("Could not find the HappRelease".to_string())
+        ))?,
+    )?;
+    create_link(
+        input.happ_hash,
+        input.happ_release_hash,
+        LinkTypes::HappToHappReleases,
+        LinkTag::new(happ_release.version),
+    )
+}
+
+#[hdk_extern]
+pub fn create_happ_reviews_link(review_hash: ActionHash) -> ExternResult<ActionHash> {
+    let review = Review::try_from(get(review_hash.clone(), GetOptions::default())?.ok_or(
+        wasm_error!(WasmErrorInner::Guest(
+            "Could not find the Review".to_string()
+        )),
+    )?)?;
+    create_link(review.happ_hash, review_hash, LinkTypes::HappReviews, ())
+}
@@ -0,0 +1,152 @@
+use happs_integrity::*;
+use hdk::prelude::*;
+
+use crate::{
+    happ_release::{
+        get_happ_releases_for_happ, get_happ_releases_in_channel, get_latest_happ_release,
+        link_version, HappReleaseChannelInput,
+    },
+    happ_release_yank::is_happ_release_yanked,
+};
+
+/// What is installed of a hApp, as known by the launcher
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(tag = "type", content = "value")]
+pub enum InstalledRelease {
+    Version(String),
+    WebHappBundleHash(EntryHash),
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct InstalledHapp {
+    pub happ_hash: ActionHash,
+    pub installed: InstalledRelease,
+    /// Defaults to the stable channel
+    pub channel: Option<ReleaseChannel>,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct AvailableUpdate {
+    pub happ_release_hash: ActionHash,
+    pub version: String,
+    pub changes: String,
+    pub web_happ_bundle_hash: EntryHash,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct UpdateCheck {
+    pub happ_hash: ActionHash,
+    /// The newest release that is not yanked and has a greater version than the installed one, if any
+    pub available_update: Option<AvailableUpdate>,
+    /// Why the updates of this hApp couldn't be checked, like an installed version that is not valid
+    pub error: Option<String>,
+}
+
+/// Checks whether there are newer releases for the given installed hApps
+///
+/// A hApp whose updates can't be checked gets an error in its own result, and the rest are still checked
+#[hdk_extern]
+pub fn check_for_updates(installed_happs: Vec<InstalledHapp>) -> ExternResult<Vec<UpdateCheck>> {
+    Ok(installed_happs
+        .into_iter()
+        .map(|installed_happ| {
+            let happ_hash = installed_happ.happ_hash.clone();
+            match available_update(installed_happ) {
+                Ok(available_update) => UpdateCheck {
+                    happ_hash,
+                    available_update,
+                    error: None,
+                },
+                Err(err) => UpdateCheck {
+                    happ_hash,
+                    available_update: None,
+                    error: Some(match err.error {
+                        WasmErrorInner::Guest(message) => message,
+                        error => format!("{error:?}"),
+                    }),
+                },
+            }
+        })
+        .collect())
+}
+
+fn available_update(installed_happ: InstalledHapp) -> ExternResult<Option<AvailableUpdate>> {
+    let installed_version = installed_version(&installed_happ.happ_hash, installed_happ.installed)?;
+
+    // The links are sorted by version, from newest to oldest
+    let links = get_happ_releases_in_channel(HappReleaseChannelInput {
+        happ_hash: installed_happ.happ_hash,
+        channel: installed_happ.channel.unwrap_or_default(),
+    })?;
+    for link in links {
+        let Some(version) = link_version(&link) else {
+            continue;
+        };
+        if let Some(installed_version) = &installed_version {
+            if version.cmp_precedence(installed_version).is_le() {
+                return Ok(None);
+            }
+        }
+        let happ_release_hash =
+            link.target
+                .into_action_hash()
+                .ok_or(wasm_error!(WasmErrorInner::Guest(
+                    "No action hash associated with link".to_string()
+                )))?;
+        if is_happ_release_yanked(happ_release_hash.clone())? {
+            continue;
+        }
+        let Some(record) = get_latest_happ_release(happ_release_hash.clone())? else {
+            continue;
+        };
+        let Some(happ_release) = record
+            .entry()
+            .to_app_option::<HappRelease>()
+            .map_err(|e| wasm_error!(e))?
+        else {
+            continue;
+        };
+        return Ok(Some(AvailableUpdate {
+            happ_release_hash,
+            version: happ_release.version,
+            changes: happ_release.changes,
+            web_happ_bundle_hash: happ_release.web_happ_bundle_hash,
+        }));
+    }
+    Ok(None)
+}
+
+// Returns `None` if the installed bundle is not one of the releases of the hApp, in which case any release is newer
+fn installed_version(
+    happ_hash: &ActionHash,
+    installed: InstalledRelease,
+) -> ExternResult<Option<semver::Version>> {
+    match installed {
+        InstalledRelease::Version(version) => parse_happ_release_version(&version)
+            .map(Some)
+            .map_err(|err| wasm_error!(WasmErrorInner::Guest(err))),
+        InstalledRelease::WebHappBundleHash(web_happ_bundle_hash) => {
+            for link in get_happ_releases_for_happ(happ_hash.clone())? {
+                let Some(happ_release_hash) = link.target.into_action_hash() else {
+                    continue;
+                };
+                let Some(record) = get_latest_happ_release(happ_release_hash)? else {
+                    continue;
+                };
+                let Some(happ_release) = record
+                    .entry()
+                    .to_app_option::<HappRelease>()
+                    .map_err(|e| wasm_error!(e))?
+                else {
+                    continue;
+                };
+                if happ_release.web_happ_bundle_hash.eq(&web_happ_bundle_hash) {
+                    return parse_happ_release_version(&happ_release.version)
+                        .map(Some)
+                        .map_err(|err| wasm_error!(WasmErrorInner::Guest(err)));
+                }
+            }
+            Ok(None)
+        }
+    }
+}
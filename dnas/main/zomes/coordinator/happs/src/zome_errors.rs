@@ -0,0 +1,19 @@
+use hdk::prelude::*;
+
+/// Returns a guest error that carries the MessagePack serialization of the given error, encoded as hex after
+/// the prefix, so that clients can decode it and branch on it
+///
+/// The message is appended after the serialized error, for the clients that only display it
+pub fn serialized_guest_error<E: Serialize + std::fmt::Debug>(
+    prefix: &str,
+    err: E,
+    message: String,
+) -> WasmError {
+    match ExternIO::encode(err) {
+        Ok(bytes) => {
+            let hex: String = bytes.0.iter().map(|byte| format!("{byte:02x}")).collect();
+            wasm_error!(WasmErrorInner::Guest(format!("{prefix}{hex} {message}")))
+        }
+        Err(err) => wasm_error!(err),
+    }
+}
@@ -0,0 +1,131 @@
+use std::collections::HashSet;
+
+use hdi::prelude::*;
+
+use crate::{happs_properties, UnitEntryTypes};
+
+/// An entry committed in a source chain, along with whether a later action of the same chain deleted it
+pub struct ChainEntry<T> {
+    pub action_hash: ActionHash,
+    pub action_seq: u32,
+    pub entry: T,
+    pub deleted: bool,
+}
+
+/// The source chain of an agent up to a given action, sorted from the oldest to the newest action
+///
+/// Used to validate an action against what its author had committed before it, which is deterministic
+/// because the chain up to the previous action of the validated action can't change
+pub struct ChainActivity {
+    activity: Vec<RegisterAgentActivity>,
+    deleted_actions: HashSet<ActionHash>,
+}
+
+impl ChainActivity {
+    pub fn get(agent: &AgentPubKey, chain_top: &ActionHash) -> ExternResult<Self> {
+        let mut activity =
+            must_get_agent_activity(agent.clone(), ChainFilter::new(chain_top.clone()))?;
+        activity.sort_by_key(|activity| activity.action.hashed.action_seq());
+
+        let deleted_actions = activity
+            .iter()
+            .filter_map(|activity| match &activity.action.hashed.content {
+                Action::Delete(delete) => Some(delete.deletes_address.clone()),
+                _ => None,
+            })
+            .collect();
+        Ok(ChainActivity {
+            activity,
+            deleted_actions,
+        })
+    }
+
+    pub fn actions(&self) -> impl DoubleEndedIterator<Item = &ActionHashed> {
+        self.activity.iter().map(|activity| &activity.action.hashed)
+    }
+
+    pub fn contains(&self, action_hash: &ActionHash) -> bool {
+        self.actions().any(|action| action.hash.eq(action_hash))
+    }
+
+    /// Returns whether the link created by the given action was deleted in the chain
+    pub fn is_link_deleted(&self, create_link_hash: &ActionHash) -> bool {
+        self.actions().any(|action| match &action.content {
+            Action::DeleteLink(delete_link) => delete_link.link_add_address.eq(create_link_hash),
+            _ => false,
+        })
+    }
+
+    /// Returns the entries of the given type that were created in the chain, deleted or not
+    pub fn entries<T: TryFrom<Entry, Error = WasmError>>(
+        &self,
+        unit_entry_type: UnitEntryTypes,
+    ) -> ExternResult<Vec<ChainEntry<T>>> {
+        let entry_type: EntryType = unit_entry_type.try_into()?;
+        let mut entries = vec![];
+        for action in self.actions() {
+            let Action::Create(create) = &action.content else {
+                continue;
+            };
+            if create.entry_type.ne(&entry_type) {
+                continue;
+            }
+            let entry = must_get_entry(create.entry_hash.clone())?;
+            entries.push(ChainEntry {
+                action_hash: action.hash.clone(),
+                action_seq: create.action_seq,
+                entry: T::try_from(entry.content)?,
+                deleted: self.deleted_actions.contains(&action.hash),
+            });
+        }
+        Ok(entries)
+    }
+
+    /// Returns the entries of the given type that were created in the chain and not deleted afterwards
+    pub fn active_entries<T: TryFrom<Entry, Error = WasmError>>(
+        &self,
+        unit_entry_type: UnitEntryTypes,
+    ) -> ExternResult<Vec<T>> {
+        Ok(self
+            .entries(unit_entry_type)?
+            .into_iter()
+            .filter(|chain_entry| !chain_entry.deleted)
+            .map(|chain_entry| chain_entry.entry)
+            .collect())
+    }
+}
+
+/// Returns whether the agent has an undeleted entry of the given type in their source chain, up to
+/// `chain_top`, for which `is_match` returns true
+pub fn has_active_entry<T: TryFrom<Entry, Error = WasmError>>(
+    agent: &AgentPubKey,
+    chain_top: &ActionHash,
+    unit_entry_type: UnitEntryTypes,
+    is_match: impl Fn(&T) -> bool,
+) -> ExternResult<bool> {
+    Ok(ChainActivity::get(agent, chain_top)?
+        .active_entries(unit_entry_type)?
+        .iter()
+        .any(is_match))
+}
+
+/// Returns whether a claim that points to the given action of the chain of the agent that granted it had expired
+/// at the given time
+///
+/// Granting agents can't make others commit their removal, so claims expire instead: a claim that points to an
+/// action from before the removal only grants its rights until `claim_validity_secs` after that action, and to keep
+/// them the claimant has to point to a later action of the granting agent, which validators check against the grant
+pub fn is_claim_expired(granter_chain_top: &ActionHash, at: &Timestamp) -> ExternResult<bool> {
+    let granter_action = must_get_action(granter_chain_top.clone())?;
+    let expires_at = add_secs(
+        &granter_action.action().timestamp(),
+        happs_properties()?.claim_validity_secs(),
+    );
+    Ok(at.gt(&expires_at))
+}
+
+/// Adds the given number of seconds to the timestamp, saturating instead of overflowing
+pub fn add_secs(timestamp: &Timestamp, secs: u64) -> Timestamp {
+    let micros = secs.saturating_mul(1_000_000).min(i64::MAX as u64) as i64;
+    Timestamp::from_micros(timestamp.as_micros().saturating_add(micros))
+}
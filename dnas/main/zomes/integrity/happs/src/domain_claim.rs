@@ -0,0 +1,143 @@
+use hdi::prelude::*;
+
+/// Proof that a publisher controls a domain: the signature of a `DomainClaimPayload` by the publisher,
+/// which must also be served by the domain in its `.well-known/holochain-happs-publisher.json` file
+///
+/// Only the signature can be validated here, checking the file is up to the clients that trust the claim
+#[derive(Clone, PartialEq)]
+#[hdk_entry_helper]
+pub struct DomainClaim {
+    pub domain: String,
+    pub signature: Signature,
+}
+
+/// The data that publishers sign to claim a domain
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct DomainClaimPayload {
+    pub domain: String,
+    pub agent: AgentPubKey,
+}
+
+pub const MAX_DOMAIN_LENGTH: usize = 253;
+pub const MAX_DOMAIN_LABEL_LENGTH: usize = 63;
+
+/// Domains must be lowercase fully qualified domain names, without scheme, port or path
+fn validate_domain(domain: &str) -> Option<String> {
+    let invalid = || Some(format!("\"{domain}\" is not a valid domain name."));
+    if domain.is_empty() || domain.len() > MAX_DOMAIN_LENGTH {
+        return invalid();
+    }
+    let labels: Vec<&str> = domain.split('.').collect();
+    if labels.len() < 2 {
+        return invalid();
+    }
+    for label in labels {
+        if label.is_empty()
+            || label.len() > MAX_DOMAIN_LABEL_LENGTH
+            || label.starts_with('-')
+            || label.ends_with('-')
+            || !label
+                .chars()
+                .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '-')
+        {
+            return invalid();
+        }
+    }
+    None
+}
+
+pub fn validate_create_domain_claim(
+    action: EntryCreationAction,
+    domain_claim: DomainClaim,
+) -> ExternResult<ValidateCallbackResult> {
+    if let Some(error) = validate_domain(&domain_claim.domain) {
+        return Ok(ValidateCallbackResult::Invalid(error));
+    }
+    let payload = DomainClaimPayload {
+        domain: domain_claim.domain,
+        agent: action.author().clone(),
+    };
+    if !verify_signature(action.author().clone(), domain_claim.signature, payload)? {
+        return Ok(ValidateCallbackResult::Invalid(
+            "The signature of a domain claim must be made by its author".to_string(),
+        ));
+    }
+    Ok(ValidateCallbackResult::Valid)
+}
+
+pub fn validate_update_domain_claim(
+    _action: Update,
+    _domain_claim: DomainClaim,
+    _original_action: EntryCreationAction,
+    _original_domain_claim: DomainClaim,
+) -> ExternResult<ValidateCallbackResult> {
+    Ok(ValidateCallbackResult::Invalid(
+        "Domain claims cannot be updated".to_string(),
+    ))
+}
+
+pub fn validate_delete_domain_claim(
+    action: Delete,
+    original_action: EntryCreationAction,
+    _original_domain_claim: DomainClaim,
+) -> ExternResult<ValidateCallbackResult> {
+    if action.author.ne(original_action.author()) {
+        return Ok(ValidateCallbackResult::Invalid(
+            "Only the author of a domain claim can delete it".to_string(),
+        ));
+    }
+    Ok(ValidateCallbackResult::Valid)
+}
+
+pub fn validate_create_link_publisher_domain_claims(
+    action: CreateLink,
+    base_address: AnyLinkableHash,
+    target_address: AnyLinkableHash,
+    _tag: LinkTag,
+) -> ExternResult<ValidateCallbackResult> {
+    let action_hash =
+        target_address
+            .into_action_hash()
+            .ok_or(wasm_error!(WasmErrorInner::Guest(
+                "No action hash associated with link".to_string()
+            )))?;
+    let record = must_get_valid_record(action_hash)?;
+    let _domain_claim: crate::DomainClaim = record
+        .entry()
+        .to_app_option()
+        .map_err(|e| wasm_error!(e))?
+        .ok_or(wasm_error!(WasmErrorInner::Guest(
+            "Linked action must reference an entry".to_string()
+        )))?;
+    if base_address
+        .into_agent_pub_key()
+        .as_ref()
+        .ne(&Some(&action.author))
+    {
+        return Ok(ValidateCallbackResult::Invalid(
+            "PublisherDomainClaims links must be created from the public key of their author"
+                .to_string(),
+        ));
+    }
+    if action.author.ne(record.action().author()) {
+        return Ok(ValidateCallbackResult::Invalid(
+            "Agents can only link to their own domain claims".to_string(),
+        ));
+    }
+    Ok(ValidateCallbackResult::Valid)
+}
+
+pub fn validate_delete_link_publisher_domain_claims(
+    action: DeleteLink,
+    original_action: CreateLink,
+    _base: AnyLinkableHash,
+    _target: AnyLinkableHash,
+    _tag: LinkTag,
+) -> ExternResult<ValidateCallbackResult> {
+    if action.author.ne(&original_action.author) {
+        return Ok(ValidateCallbackResult::Invalid(
+            "Only the author of a domain claim can unlink it".to_string(),
+        ));
+    }
+    Ok(ValidateCallbackResult::Valid)
+}
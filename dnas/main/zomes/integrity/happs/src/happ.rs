@@ -1,42 +1,112 @@
 use hdi::prelude::*;
 
+use crate::{
+    is_happ_maintainer, is_happ_owner, is_vetted_publisher, original_action_hash,
+    update_links::check_update_link, validate_happ_tags, HappCategory,
+};
+
 #[derive(Clone, PartialEq)]
 #[hdk_entry_helper]
 pub struct Happ {
     pub name: String,
     pub description: String,
     pub icon: EntryHash,
+    /// hApps created before categories and tags existed don't have these fields
+    #[serde(default)]
+    pub category: HappCategory,
+    #[serde(default)]
+    pub tags: Vec<String>,
 }
 
+pub const MAX_HAPP_NAME_LENGTH: usize = 64;
+pub const MAX_HAPP_DESCRIPTION_LENGTH: usize = 5000;
+
 pub fn validate_create_happ(
-    _action: EntryCreationAction,
-    _happ: Happ,
+    action: EntryCreationAction,
+    happ: Happ,
 ) -> ExternResult<ValidateCallbackResult> {
-    // TODO: add the appropriate validation rules
+    if let Some(error) = validate_happ_fields(&happ) {
+        return Ok(ValidateCallbackResult::Invalid(error));
+    }
+    // Updates are validated here as well, but maintainers don't need a credential to update a hApp
+    if let EntryCreationAction::Create(create) = &action {
+        if !is_vetted_publisher(&create.author, &create.prev_action)? {
+            return Ok(ValidateCallbackResult::Invalid(
+                "Only agents with a publisher credential can publish hApps".to_string(),
+            ));
+        }
+    }
     Ok(ValidateCallbackResult::Valid)
 }
 
+// The returned messages are shown as is to the publisher by the UI
+fn validate_happ_fields(happ: &Happ) -> Option<String> {
+    let name = happ.name.trim();
+    if name.is_empty() {
+        return Some("The name of the hApp can't be empty.".to_string());
+    }
+    if name.chars().count() > MAX_HAPP_NAME_LENGTH {
+        return Some(format!(
+            "The name of the hApp can't be longer than {MAX_HAPP_NAME_LENGTH} characters."
+        ));
+    }
+    if happ.name.chars().any(char::is_control) {
+        return Some("The name of the hApp can't contain control characters.".to_string());
+    }
+    if happ.description.chars().count() > MAX_HAPP_DESCRIPTION_LENGTH {
+        return Some(format!(
+            "The description of the hApp can't be longer than {MAX_HAPP_DESCRIPTION_LENGTH} characters."
+        ));
+    }
+    // Line breaks and tabs are allowed in the description to format it
+    if happ
+        .description
+        .chars()
+        .any(|c| c.is_control() && !matches!(c, '\n' | '\r' | '\t'))
+    {
+        return Some("The description of the hApp can't contain control characters.".to_string());
+    }
+    if let Some(error) = validate_happ_tags(&happ.tags) {
+        return Some(error);
+    }
+    None
+}
+
 pub fn validate_update_happ(
-    _action: Update,
+    action: Update,
     _happ: Happ,
     _original_action: EntryCreationAction,
     _original_happ: Happ,
 ) -> ExternResult<ValidateCallbackResult> {
-    // TODO: add the appropriate validation rules
+    let happ_hash = original_action_hash(action.original_action_address.clone())?;
+    if !is_happ_maintainer(
+        &action.author,
+        &action.prev_action,
+        &action.timestamp,
+        &happ_hash,
+    )? {
+        return Ok(ValidateCallbackResult::Invalid(
+            "Only the maintainers of a hApp can update it".to_string(),
+        ));
+    }
     Ok(ValidateCallbackResult::Valid)
 }
 
 pub fn validate_delete_happ(
-    _action: Delete,
+    action: Delete,
     _original_action: EntryCreationAction,
     _original_happ: Happ,
 ) -> ExternResult<ValidateCallbackResult> {
-    // TODO: add the appropriate validation rules
+    if !is_happ_owner(&action.author, &action.prev_action, &action.deletes_address)? {
+        return Ok(ValidateCallbackResult::Invalid(
+            "Only the owner of a hApp can delete it".to_string(),
+        ));
+    }
     Ok(ValidateCallbackResult::Valid)
 }
 
 pub fn validate_create_link_happ_updates(
-    _action: CreateLink,
+    action: CreateLink,
     base_address: AnyLinkableHash,
     target_address: AnyLinkableHash,
     _tag: LinkTag,
@@ -46,8 +116,8 @@ pub fn validate_create_link_happ_updates(
         .ok_or(wasm_error!(WasmErrorInner::Guest(
             "No action hash associated with link".to_string()
         )))?;
-    let record = must_get_valid_record(action_hash)?;
-    let _happ: crate::Happ = record
+    let original_record = must_get_valid_record(action_hash)?;
+    let _happ: crate::Happ = original_record
         .entry()
         .to_app_option()
         .map_err(|e| wasm_error!(e))?
@@ -60,15 +130,27 @@ pub fn validate_create_link_happ_updates(
             .ok_or(wasm_error!(WasmErrorInner::Guest(
                 "No action hash associated with link".to_string()
             )))?;
-    let record = must_get_valid_record(action_hash)?;
-    let _happ: crate::Happ = record
+    let update_record = must_get_valid_record(action_hash)?;
+    let _happ: crate::Happ = update_record
         .entry()
         .to_app_option()
         .map_err(|e| wasm_error!(e))?
         .ok_or(wasm_error!(WasmErrorInner::Guest(
             "Linked action must reference an entry".to_string()
         )))?;
-    // TODO: add the appropriate validation rules
+    if !is_happ_maintainer(
+        &action.author,
+        &action.prev_action,
+        &action.timestamp,
+        original_record.action_address(),
+    )? {
+        return Ok(ValidateCallbackResult::Invalid(
+            "Only the maintainers of a hApp can link updates to it".to_string(),
+        ));
+    }
+    if let Some(error) = check_update_link(&action.author, &original_record, &update_record)? {
+        return Ok(ValidateCallbackResult::Invalid(error));
+    }
     Ok(ValidateCallbackResult::Valid)
 }
 
@@ -84,9 +166,22 @@ pub fn validate_delete_link_happ_updates(
     ))
 }
 
+/// The catalog links are spread over this many anchors, so that no single set of authorities holds all of them
+pub const ALL_HAPPS_SHARDS: u8 = 16;
+
+/// Before sharding, all the catalog links were created from this anchor
+/// They are still read until their publishers migrate them to their shard
+pub const LEGACY_ALL_HAPPS_PATH: &str = "all_happs";
+
+/// Returns the anchor under which the catalog link for the given hApp is created
+pub fn all_happs_shard_path(happ_hash: &ActionHash) -> Path {
+    let shard = happ_hash.get_raw_32()[0] % ALL_HAPPS_SHARDS;
+    Path::from(format!("all_happs.{shard}"))
+}
+
 pub fn validate_create_link_all_happs(
-    _action: CreateLink,
-    _base_address: AnyLinkableHash,
+    action: CreateLink,
+    base_address: AnyLinkableHash,
     target_address: AnyLinkableHash,
     _tag: LinkTag,
 ) -> ExternResult<ValidateCallbackResult> {
@@ -96,7 +191,7 @@ pub fn validate_create_link_all_happs(
             .ok_or(wasm_error!(WasmErrorInner::Guest(
                 "No action hash associated with link".to_string()
             )))?;
-    let record = must_get_valid_record(action_hash)?;
+    let record = must_get_valid_record(action_hash.clone())?;
     let _happ: crate::Happ = record
         .entry()
         .to_app_option()
@@ -104,24 +199,51 @@ pub fn validate_create_link_all_happs(
         .ok_or(wasm_error!(WasmErrorInner::Guest(
             "Linked action must reference an entry".to_string()
         )))?;
-    // TODO: add the appropriate validation rules
+    let Action::Create(_) = record.action() else {
+        return Ok(ValidateCallbackResult::Invalid(
+            "AllHapps links must point to the original hApp".to_string(),
+        ));
+    };
+    let shard_path = all_happs_shard_path(&action_hash);
+    let legacy_path = Path::from(LEGACY_ALL_HAPPS_PATH);
+    if base_address.ne(&shard_path.path_entry_hash()?.into())
+        && base_address.ne(&legacy_path.path_entry_hash()?.into())
+    {
+        return Ok(ValidateCallbackResult::Invalid(
+            "AllHapps links must be created from the shard of the hApp".to_string(),
+        ));
+    }
+    if !is_happ_maintainer(
+        &action.author,
+        &action.prev_action,
+        &action.timestamp,
+        &action_hash,
+    )? {
+        return Ok(ValidateCallbackResult::Invalid(
+            "Only the maintainers of a hApp can list it in the catalog".to_string(),
+        ));
+    }
     Ok(ValidateCallbackResult::Valid)
 }
 
 pub fn validate_delete_link_all_happs(
-    _action: DeleteLink,
-    _original_action: CreateLink,
+    action: DeleteLink,
+    original_action: CreateLink,
     _base: AnyLinkableHash,
-    _target: AnyLinkableHash,
+    target: AnyLinkableHash,
     _tag: LinkTag,
 ) -> ExternResult<ValidateCallbackResult> {
-    // TODO: add the appropriate validation rules
+    if action.author.ne(&original_action.author) && !is_owner_of_target(&action, target)? {
+        return Ok(ValidateCallbackResult::Invalid(
+            "Only the owner of a hApp can remove it from the catalog".to_string(),
+        ));
+    }
     Ok(ValidateCallbackResult::Valid)
 }
 
 pub fn validate_create_link_publisher_happs(
-    _action: CreateLink,
-    _base_address: AnyLinkableHash,
+    action: CreateLink,
+    base_address: AnyLinkableHash,
     target_address: AnyLinkableHash,
     _tag: LinkTag,
 ) -> ExternResult<ValidateCallbackResult> {
@@ -131,7 +253,7 @@ pub fn validate_create_link_publisher_happs(
             .ok_or(wasm_error!(WasmErrorInner::Guest(
                 "No action hash associated with link".to_string()
             )))?;
-    let record = must_get_valid_record(action_hash)?;
+    let record = must_get_valid_record(action_hash.clone())?;
     let _happ: crate::Happ = record
         .entry()
         .to_app_option()
@@ -139,17 +261,44 @@ pub fn validate_create_link_publisher_happs(
         .ok_or(wasm_error!(WasmErrorInner::Guest(
             "Linked action must reference an entry".to_string()
         )))?;
-    // TODO: add the appropriate validation rules
+    if base_address
+        .into_agent_pub_key()
+        .as_ref()
+        .ne(&Some(&action.author))
+    {
+        return Ok(ValidateCallbackResult::Invalid(
+            "PublisherHapps links must be created from the public key of their author".to_string(),
+        ));
+    }
+    if !is_happ_owner(&action.author, &action.prev_action, &action_hash)? {
+        return Ok(ValidateCallbackResult::Invalid(
+            "Only the owner of a hApp can add it to their hApps".to_string(),
+        ));
+    }
     Ok(ValidateCallbackResult::Valid)
 }
 
 pub fn validate_delete_link_publisher_happs(
-    _action: DeleteLink,
-    _original_action: CreateLink,
+    action: DeleteLink,
+    original_action: CreateLink,
     _base: AnyLinkableHash,
-    _target: AnyLinkableHash,
+    target: AnyLinkableHash,
     _tag: LinkTag,
 ) -> ExternResult<ValidateCallbackResult> {
-    // TODO: add the appropriate validation rules
+    // The new owner of a transferred hApp removes it from the hApps of the previous owner
+    if action.author.ne(&original_action.author) && !is_owner_of_target(&action, target)? {
+        return Ok(ValidateCallbackResult::Invalid(
+            "Only the owner of a hApp can remove it from the hApps of its publishers".to_string(),
+        ));
+    }
     Ok(ValidateCallbackResult::Valid)
 }
+
+fn is_owner_of_target(action: &DeleteLink, target: AnyLinkableHash) -> ExternResult<bool> {
+    let happ_hash = target
+        .into_action_hash()
+        .ok_or(wasm_error!(WasmErrorInner::Guest(
+            "No action hash associated with link".to_string()
+        )))?;
+    is_happ_owner(&action.author, &action.prev_action, &happ_hash)
+}
@@ -0,0 +1,289 @@
+use hdi::prelude::*;
+
+use crate::{chain_activity::ChainActivity, is_happ_maintainer, Happ, LinkTypes};
+
+/// The fixed set of categories in which hApps are listed
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum HappCategory {
+    Productivity,
+    Social,
+    Communication,
+    Finance,
+    Games,
+    Education,
+    DeveloperTools,
+    Media,
+    /// hApps created before categories existed are in this category
+    #[default]
+    Other,
+}
+
+pub const HAPP_CATEGORIES: [HappCategory; 9] = [
+    HappCategory::Productivity,
+    HappCategory::Social,
+    HappCategory::Communication,
+    HappCategory::Finance,
+    HappCategory::Games,
+    HappCategory::Education,
+    HappCategory::DeveloperTools,
+    HappCategory::Media,
+    HappCategory::Other,
+];
+
+impl HappCategory {
+    pub fn name(&self) -> &'static str {
+        match self {
+            HappCategory::Productivity => "productivity",
+            HappCategory::Social => "social",
+            HappCategory::Communication => "communication",
+            HappCategory::Finance => "finance",
+            HappCategory::Games => "games",
+            HappCategory::Education => "education",
+            HappCategory::DeveloperTools => "developer-tools",
+            HappCategory::Media => "media",
+            HappCategory::Other => "other",
+        }
+    }
+
+    pub fn from_name(name: &str) -> Option<HappCategory> {
+        HAPP_CATEGORIES
+            .into_iter()
+            .find(|category| category.name() == name)
+    }
+}
+
+pub const MAX_HAPP_TAGS: usize = 10;
+pub const MAX_HAPP_TAG_LENGTH: usize = 32;
+
+/// The path from which all the hApps in the given category are linked
+pub fn category_path(category: &HappCategory) -> Path {
+    Path::from(format!("categories.{}", category.name()))
+}
+
+/// The path from which all the hApps with the given tag are linked
+///
+/// Tags can't contain dots, so they always map to a single path component
+pub fn tag_path(tag: &str) -> Path {
+    Path::from(format!("tags.{tag}"))
+}
+
+// The returned messages are shown as is to the publisher by the UI
+pub fn validate_happ_tags(tags: &[String]) -> Option<String> {
+    if tags.len() > MAX_HAPP_TAGS {
+        return Some(format!("A hApp can't have more than {MAX_HAPP_TAGS} tags."));
+    }
+    for (i, tag) in tags.iter().enumerate() {
+        if let Some(error) = validate_happ_tag(tag) {
+            return Some(error);
+        }
+        if tags[..i].contains(tag) {
+            return Some(format!("The tag \"{tag}\" is repeated."));
+        }
+    }
+    None
+}
+
+fn validate_happ_tag(tag: &str) -> Option<String> {
+    if tag.is_empty() || tag.chars().count() > MAX_HAPP_TAG_LENGTH {
+        return Some(format!(
+            "Tags must have between 1 and {MAX_HAPP_TAG_LENGTH} characters."
+        ));
+    }
+    if !tag
+        .chars()
+        .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '-')
+    {
+        return Some(format!(
+            "The tag \"{tag}\" can only contain lowercase letters, digits and dashes."
+        ));
+    }
+    None
+}
+
+/// Returns the latest revision of the hApp that the author of the index link committed before it
+///
+/// Maintainers index a hApp right after creating or updating it, so the indexed category and tags must be the
+/// ones of that revision, which is the original hApp or the target of the author's latest HappUpdates link
+pub(crate) fn get_indexed_happ_revision(
+    action: &CreateLink,
+    happ_hash: &ActionHash,
+) -> ExternResult<Option<Happ>> {
+    let happ_base: AnyLinkableHash = happ_hash.clone().into();
+    let activity = ChainActivity::get(&action.author, &action.prev_action)?;
+    let latest_revision_hash = activity.actions().rev().find_map(|action| {
+        if action.hash.eq(happ_hash) {
+            return Some(happ_hash.clone());
+        }
+        let Action::CreateLink(create_link) = &action.content else {
+            return None;
+        };
+        let is_happ_update = matches!(
+            LinkTypes::from_type(create_link.zome_index, create_link.link_type),
+            Ok(Some(LinkTypes::HappUpdates))
+        );
+        match is_happ_update && create_link.base_address.eq(&happ_base) {
+            true => create_link.target_address.clone().into_action_hash(),
+            false => None,
+        }
+    });
+    let Some(revision_hash) = latest_revision_hash else {
+        return Ok(None);
+    };
+    let record = must_get_valid_record(revision_hash)?;
+    let happ: Happ = record
+        .entry()
+        .to_app_option()
+        .map_err(|e| wasm_error!(e))?
+        .ok_or(wasm_error!(WasmErrorInner::Guest(
+            "Linked action must reference an entry".to_string()
+        )))?;
+    Ok(Some(happ))
+}
+
+/// Checks that the author of the index link is a maintainer of the hApp, and that `is_indexed` returns true for
+/// the revision of the hApp that they are indexing
+fn validate_create_index_link(
+    action: &CreateLink,
+    target_address: AnyLinkableHash,
+    is_indexed: impl Fn(&Happ) -> bool,
+) -> ExternResult<Option<String>> {
+    let happ_hash = target_address
+        .into_action_hash()
+        .ok_or(wasm_error!(WasmErrorInner::Guest(
+            "No action hash associated with link".to_string()
+        )))?;
+    let record = must_get_valid_record(happ_hash.clone())?;
+    let _happ: crate::Happ = record
+        .entry()
+        .to_app_option()
+        .map_err(|e| wasm_error!(e))?
+        .ok_or(wasm_error!(WasmErrorInner::Guest(
+            "Linked action must reference an entry".to_string()
+        )))?;
+    if !is_happ_maintainer(
+        &action.author,
+        &action.prev_action,
+        &action.timestamp,
+        &happ_hash,
+    )? {
+        return Ok(Some(
+            "Only the maintainers of a hApp can index it".to_string(),
+        ));
+    }
+    let Some(happ) = get_indexed_happ_revision(action, &happ_hash)? else {
+        return Ok(Some(
+            "A hApp can only be indexed after creating or updating it".to_string(),
+        ));
+    };
+    if !is_indexed(&happ) {
+        return Ok(Some(
+            "A hApp can only be indexed under the category and tags of its latest revision"
+                .to_string(),
+        ));
+    }
+    Ok(None)
+}
+
+fn validate_delete_index_link(
+    action: &DeleteLink,
+    target: AnyLinkableHash,
+) -> ExternResult<Option<String>> {
+    let happ_hash = target
+        .into_action_hash()
+        .ok_or(wasm_error!(WasmErrorInner::Guest(
+            "No action hash associated with link".to_string()
+        )))?;
+    if !is_happ_maintainer(
+        &action.author,
+        &action.prev_action,
+        &action.timestamp,
+        &happ_hash,
+    )? {
+        return Ok(Some(
+            "Only the maintainers of a hApp can remove it from an index".to_string(),
+        ));
+    }
+    Ok(None)
+}
+
+fn link_tag_string(tag: LinkTag) -> Option<String> {
+    String::from_utf8(tag.into_inner()).ok()
+}
+
+pub fn validate_create_link_category_to_happs(
+    action: CreateLink,
+    base_address: AnyLinkableHash,
+    target_address: AnyLinkableHash,
+    tag: LinkTag,
+) -> ExternResult<ValidateCallbackResult> {
+    let Some(category) = link_tag_string(tag).and_then(|name| HappCategory::from_name(&name))
+    else {
+        return Ok(ValidateCallbackResult::Invalid(
+            "The tag of a CategoryToHapps link must be the name of a category".to_string(),
+        ));
+    };
+    if base_address.ne(&category_path(&category).path_entry_hash()?.into()) {
+        return Ok(ValidateCallbackResult::Invalid(
+            "CategoryToHapps links must be created from the path of their category".to_string(),
+        ));
+    }
+    if let Some(error) =
+        validate_create_index_link(&action, target_address, |happ| happ.category == category)?
+    {
+        return Ok(ValidateCallbackResult::Invalid(error));
+    }
+    Ok(ValidateCallbackResult::Valid)
+}
+
+pub fn validate_delete_link_category_to_happs(
+    action: DeleteLink,
+    _original_action: CreateLink,
+    _base: AnyLinkableHash,
+    target: AnyLinkableHash,
+    _tag: LinkTag,
+) -> ExternResult<ValidateCallbackResult> {
+    if let Some(error) = validate_delete_index_link(&action, target)? {
+        return Ok(ValidateCallbackResult::Invalid(error));
+    }
+    Ok(ValidateCallbackResult::Valid)
+}
+
+pub fn validate_create_link_tag_to_happs(
+    action: CreateLink,
+    base_address: AnyLinkableHash,
+    target_address: AnyLinkableHash,
+    tag: LinkTag,
+) -> ExternResult<ValidateCallbackResult> {
+    let Some(happ_tag) = link_tag_string(tag) else {
+        return Ok(ValidateCallbackResult::Invalid(
+            "The tag of a TagToHapps link must be the hApp tag".to_string(),
+        ));
+    };
+    if let Some(error) = validate_happ_tag(&happ_tag) {
+        return Ok(ValidateCallbackResult::Invalid(error));
+    }
+    if base_address.ne(&tag_path(&happ_tag).path_entry_hash()?.into()) {
+        return Ok(ValidateCallbackResult::Invalid(
+            "TagToHapps links must be created from the path of their tag".to_string(),
+        ));
+    }
+    if let Some(error) = validate_create_index_link(&action, target_address, |happ| {
+        happ.tags.contains(&happ_tag)
+    })? {
+        return Ok(ValidateCallbackResult::Invalid(error));
+    }
+    Ok(ValidateCallbackResult::Valid)
+}
+
+pub fn validate_delete_link_tag_to_happs(
+    action: DeleteLink,
+    _original_action: CreateLink,
+    _base: AnyLinkableHash,
+    target: AnyLinkableHash,
+    _tag: LinkTag,
+) -> ExternResult<ValidateCallbackResult> {
+    if let Some(error) = validate_delete_index_link(&action, target)? {
+        return Ok(ValidateCallbackResult::Invalid(error));
+    }
+    Ok(ValidateCallbackResult::Valid)
+}
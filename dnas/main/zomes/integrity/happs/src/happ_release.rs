@@ -1,4 +1,10 @@
 use hdi::prelude::*;
+use semver::Version;
+
+use crate::{
+    chain_activity::ChainActivity, is_happ_maintainer, is_vetted_publisher,
+    update_links::check_update_link, UnitEntryTypes,
+};
 
 #[derive(Clone, PartialEq)]
 #[hdk_entry_helper]
@@ -7,10 +13,67 @@ pub struct HappRelease {
     pub version: String,
     pub changes: String,
     pub web_happ_bundle_hash: EntryHash,
+    /// Releases created before channels existed don't have this field, and are in the stable channel
+    #[serde(default)]
+    pub channel: ReleaseChannel,
+}
+
+pub const MAX_RELEASE_CHANNEL_NAME_LENGTH: usize = 32;
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, Hash, Default)]
+#[serde(tag = "type", content = "value")]
+pub enum ReleaseChannel {
+    #[default]
+    Stable,
+    Beta,
+    Nightly,
+    Custom(String),
+}
+
+impl ReleaseChannel {
+    pub fn name(&self) -> &str {
+        match self {
+            ReleaseChannel::Stable => "stable",
+            ReleaseChannel::Beta => "beta",
+            ReleaseChannel::Nightly => "nightly",
+            ReleaseChannel::Custom(name) => name.as_str(),
+        }
+    }
+}
+
+/// The tag of the HappReleasesByChannel links, which starts with the channel name so that releases can be queried by channel
+pub fn release_channel_link_tag(channel: &ReleaseChannel, version: &str) -> LinkTag {
+    LinkTag::new(format!("{}:{version}", channel.name()))
+}
+
+fn validate_release_channel(channel: &ReleaseChannel) -> Option<String> {
+    let ReleaseChannel::Custom(name) = channel else {
+        return None;
+    };
+    if name.is_empty() || name.chars().count() > MAX_RELEASE_CHANNEL_NAME_LENGTH {
+        return Some(format!(
+            "The name of a release channel must have between 1 and {MAX_RELEASE_CHANNEL_NAME_LENGTH} characters."
+        ));
+    }
+    if !name
+        .chars()
+        .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '-')
+    {
+        return Some(
+            "The name of a release channel can only contain lowercase letters, digits and dashes."
+                .to_string(),
+        );
+    }
+    if ["stable", "beta", "nightly"].contains(&name.as_str()) {
+        return Some(format!(
+            "\"{name}\" is a predefined release channel, it can't be used as a custom one."
+        ));
+    }
+    None
 }
 
 pub fn validate_create_happ_release(
-    _action: EntryCreationAction,
+    action: EntryCreationAction,
     happ_release: HappRelease,
 ) -> ExternResult<ValidateCallbackResult> {
     let record = must_get_valid_record(happ_release.happ_hash.clone())?;
@@ -21,17 +84,110 @@ pub fn validate_create_happ_release(
         .ok_or(wasm_error!(WasmErrorInner::Guest(String::from(
             "Dependant action must be accompanied by an entry"
         ))))?;
-    // TODO: add the appropriate validation rules
+    if !is_happ_maintainer(
+        action.author(),
+        action.prev_action(),
+        action.timestamp(),
+        &happ_release.happ_hash,
+    )? {
+        return Ok(ValidateCallbackResult::Invalid(
+            "Only the maintainers of a hApp can create releases for it".to_string(),
+        ));
+    }
+    if let EntryCreationAction::Create(create) = &action {
+        if !is_vetted_publisher(&create.author, &create.prev_action)? {
+            return Ok(ValidateCallbackResult::Invalid(
+                "Only agents with a publisher credential can release hApps".to_string(),
+            ));
+        }
+    }
+    let version = match parse_happ_release_version(&happ_release.version) {
+        Ok(version) => version,
+        Err(err) => return Ok(ValidateCallbackResult::Invalid(err)),
+    };
+    if let Some(error) = validate_release_channel(&happ_release.channel) {
+        return Ok(ValidateCallbackResult::Invalid(error));
+    }
+    if let EntryCreationAction::Create(create) = action {
+        // Versions only need to increase within each channel, so that a stable patch can be released after a beta,
+        // and only among the releases of the author, see `releases_published_before`
+        let previous_releases =
+            releases_published_before(create.author, create.prev_action, &happ_release.happ_hash)?
+                .into_iter()
+                .filter(|previous_release| previous_release.channel.eq(&happ_release.channel));
+        for previous_release in previous_releases {
+            let Ok(previous_version) = parse_happ_release_version(&previous_release.version) else {
+                continue;
+            };
+            if version.cmp_precedence(&previous_version).is_le() {
+                return Ok(ValidateCallbackResult::Invalid(format!(
+                    "The version of a new hApp release must be greater than the version of all the previous releases of its author in its channel, but {} was already released in the {} channel",
+                    previous_release.version,
+                    previous_release.channel.name()
+                )));
+            }
+        }
+    }
     Ok(ValidateCallbackResult::Valid)
 }
 
+/// Parses the version of a hApp release, which must follow the semantic versioning specification (https://semver.org),
+/// including optional pre-release and build metadata (e.g. "1.2.0-beta.1+20240101")
+pub fn parse_happ_release_version(version: &str) -> Result<Version, String> {
+    Version::parse(version)
+        .map_err(|err| format!("Invalid hApp release version \"{version}\": {err}"))
+}
+
+/// Returns the releases of the hApp that the author committed before `chain_top`
+///
+/// Validation can only see the source chain of the author, so the invariant it enforces is per-author:
+/// the versions of a channel strictly increase among the releases of each maintainer, but two maintainers
+/// can publish the same or a lower version than each other. The coordinator zome also checks the new
+/// version against the releases of all maintainers that it can get before creating a release
+fn releases_published_before(
+    author: AgentPubKey,
+    chain_top: ActionHash,
+    happ_hash: &ActionHash,
+) -> ExternResult<Vec<HappRelease>> {
+    Ok(ChainActivity::get(&author, &chain_top)?
+        .entries::<HappRelease>(UnitEntryTypes::HappRelease)?
+        .into_iter()
+        .map(|chain_entry| chain_entry.entry)
+        .filter(|happ_release| happ_release.happ_hash.eq(happ_hash))
+        .collect())
+}
+
 pub fn validate_update_happ_release(
-    _action: Update,
-    _happ_release: HappRelease,
+    action: Update,
+    happ_release: HappRelease,
     _original_action: EntryCreationAction,
-    _original_happ_release: HappRelease,
+    original_happ_release: HappRelease,
 ) -> ExternResult<ValidateCallbackResult> {
-    // TODO: add the appropriate validation rules
+    if !is_happ_maintainer(
+        &action.author,
+        &action.prev_action,
+        &action.timestamp,
+        &original_happ_release.happ_hash,
+    )? {
+        return Ok(ValidateCallbackResult::Invalid(
+            "Only the maintainers of a hApp can update its releases".to_string(),
+        ));
+    }
+    if happ_release.happ_hash.ne(&original_happ_release.happ_hash) {
+        return Ok(ValidateCallbackResult::Invalid(
+            "A hApp release cannot be moved to another hApp".to_string(),
+        ));
+    }
+    if happ_release.version.ne(&original_happ_release.version) {
+        return Ok(ValidateCallbackResult::Invalid(
+            "The version of a hApp release cannot be changed".to_string(),
+        ));
+    }
+    if happ_release.channel.ne(&original_happ_release.channel) {
+        return Ok(ValidateCallbackResult::Invalid(
+            "The channel of a hApp release cannot be changed".to_string(),
+        ));
+    }
     Ok(ValidateCallbackResult::Valid)
 }
 
@@ -46,18 +202,18 @@ pub fn validate_delete_happ_release(
 }
 
 pub fn validate_create_link_happ_to_happ_releases(
-    _action: CreateLink,
+    action: CreateLink,
     base_address: AnyLinkableHash,
     target_address: AnyLinkableHash,
-    _tag: LinkTag,
+    tag: LinkTag,
 ) -> ExternResult<ValidateCallbackResult> {
     let action_hash = base_address
         .into_action_hash()
         .ok_or(wasm_error!(WasmErrorInner::Guest(
             "No action hash associated with link".to_string()
         )))?;
-    let record = must_get_valid_record(action_hash)?;
-    let _happ: crate::Happ = record
+    let happ_record = must_get_valid_record(action_hash)?;
+    let _happ: crate::Happ = happ_record
         .entry()
         .to_app_option()
         .map_err(|e| wasm_error!(e))?
@@ -71,14 +227,33 @@ pub fn validate_create_link_happ_to_happ_releases(
                 "No action hash associated with link".to_string()
             )))?;
     let record = must_get_valid_record(action_hash)?;
-    let _happ_release: crate::HappRelease = record
+    let happ_release: crate::HappRelease = record
         .entry()
         .to_app_option()
         .map_err(|e| wasm_error!(e))?
         .ok_or(wasm_error!(WasmErrorInner::Guest(
             "Linked action must reference an entry".to_string()
         )))?;
-    // TODO: add the appropriate validation rules
+    if happ_release.happ_hash.ne(happ_record.action_address()) {
+        return Ok(ValidateCallbackResult::Invalid(
+            "HappToHappReleases links must be created from the hApp of the release".to_string(),
+        ));
+    }
+    if !is_happ_maintainer(
+        &action.author,
+        &action.prev_action,
+        &action.timestamp,
+        happ_record.action_address(),
+    )? {
+        return Ok(ValidateCallbackResult::Invalid(
+            "Only the maintainers of a hApp can link releases to it".to_string(),
+        ));
+    }
+    if tag.into_inner().ne(happ_release.version.as_bytes()) {
+        return Ok(ValidateCallbackResult::Invalid(
+            "The tag of a HappToHappReleases link must be the version of the release".to_string(),
+        ));
+    }
     Ok(ValidateCallbackResult::Valid)
 }
 
@@ -95,7 +270,7 @@ pub fn validate_delete_link_happ_to_happ_releases(
 }
 
 pub fn validate_create_link_happ_release_updates(
-    _action: CreateLink,
+    action: CreateLink,
     base_address: AnyLinkableHash,
     target_address: AnyLinkableHash,
     _tag: LinkTag,
@@ -105,8 +280,8 @@ pub fn validate_create_link_happ_release_updates(
         .ok_or(wasm_error!(WasmErrorInner::Guest(
             "No action hash associated with link".to_string()
         )))?;
-    let record = must_get_valid_record(action_hash)?;
-    let _happ_release: crate::HappRelease = record
+    let original_record = must_get_valid_record(action_hash)?;
+    let happ_release: crate::HappRelease = original_record
         .entry()
         .to_app_option()
         .map_err(|e| wasm_error!(e))?
@@ -119,15 +294,27 @@ pub fn validate_create_link_happ_release_updates(
             .ok_or(wasm_error!(WasmErrorInner::Guest(
                 "No action hash associated with link".to_string()
             )))?;
-    let record = must_get_valid_record(action_hash)?;
-    let _happ_release: crate::HappRelease = record
+    let update_record = must_get_valid_record(action_hash)?;
+    let _happ_release: crate::HappRelease = update_record
         .entry()
         .to_app_option()
         .map_err(|e| wasm_error!(e))?
         .ok_or(wasm_error!(WasmErrorInner::Guest(
             "Linked action must reference an entry".to_string()
         )))?;
-    // TODO: add the appropriate validation rules
+    if !is_happ_maintainer(
+        &action.author,
+        &action.prev_action,
+        &action.timestamp,
+        &happ_release.happ_hash,
+    )? {
+        return Ok(ValidateCallbackResult::Invalid(
+            "Only the maintainers of a hApp can link updates to its releases".to_string(),
+        ));
+    }
+    if let Some(error) = check_update_link(&action.author, &original_record, &update_record)? {
+        return Ok(ValidateCallbackResult::Invalid(error));
+    }
     Ok(ValidateCallbackResult::Valid)
 }
 
@@ -142,3 +329,62 @@ pub fn validate_delete_link_happ_release_updates(
         "HappReleaseUpdates links cannot be deleted".to_string(),
     ))
 }
+
+pub fn validate_create_link_happ_releases_by_channel(
+    action: CreateLink,
+    base_address: AnyLinkableHash,
+    target_address: AnyLinkableHash,
+    tag: LinkTag,
+) -> ExternResult<ValidateCallbackResult> {
+    let happ_hash = base_address
+        .into_action_hash()
+        .ok_or(wasm_error!(WasmErrorInner::Guest(
+            "No action hash associated with link".to_string()
+        )))?;
+    let action_hash =
+        target_address
+            .into_action_hash()
+            .ok_or(wasm_error!(WasmErrorInner::Guest(
+                "No action hash associated with link".to_string()
+            )))?;
+    let record = must_get_valid_record(action_hash)?;
+    let happ_release: crate::HappRelease = record
+        .entry()
+        .to_app_option()
+        .map_err(|e| wasm_error!(e))?
+        .ok_or(wasm_error!(WasmErrorInner::Guest(
+            "Linked action must reference an entry".to_string()
+        )))?;
+    if happ_release.happ_hash.ne(&happ_hash) {
+        return Ok(ValidateCallbackResult::Invalid(
+            "HappReleasesByChannel links must be created from the hApp of the release".to_string(),
+        ));
+    }
+    if action.author.ne(record.action().author()) {
+        return Ok(ValidateCallbackResult::Invalid(
+            "Only the publisher of a hApp release can add it to its channel".to_string(),
+        ));
+    }
+    if tag.ne(&release_channel_link_tag(
+        &happ_release.channel,
+        &happ_release.version,
+    )) {
+        return Ok(ValidateCallbackResult::Invalid(
+            "The tag of a HappReleasesByChannel link must be the channel and the version of the release"
+                .to_string(),
+        ));
+    }
+    Ok(ValidateCallbackResult::Valid)
+}
+
+pub fn validate_delete_link_happ_releases_by_channel(
+    _action: DeleteLink,
+    _original_action: CreateLink,
+    _base: AnyLinkableHash,
+    _target: AnyLinkableHash,
+    _tag: LinkTag,
+) -> ExternResult<ValidateCallbackResult> {
+    Ok(ValidateCallbackResult::Invalid(
+        "HappReleasesByChannel links cannot be deleted".to_string(),
+    ))
+}
@@ -0,0 +1,129 @@
+use hdi::prelude::*;
+
+use crate::is_happ_maintainer;
+
+pub const MAX_YANK_REASON_LENGTH: usize = 1000;
+
+/// Flags a release as broken or insecure, without deleting it
+#[derive(Clone, PartialEq)]
+#[hdk_entry_helper]
+pub struct HappReleaseYank {
+    pub happ_release_hash: ActionHash,
+    pub reason: String,
+    pub severity: Option<YankSeverity>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum YankSeverity {
+    Low,
+    Medium,
+    High,
+    Critical,
+}
+
+pub fn validate_create_happ_release_yank(
+    action: EntryCreationAction,
+    happ_release_yank: HappReleaseYank,
+) -> ExternResult<ValidateCallbackResult> {
+    let record = must_get_valid_record(happ_release_yank.happ_release_hash.clone())?;
+    let happ_release: crate::HappRelease = record
+        .entry()
+        .to_app_option()
+        .map_err(|e| wasm_error!(e))?
+        .ok_or(wasm_error!(WasmErrorInner::Guest(String::from(
+            "Dependant action must be accompanied by an entry"
+        ))))?;
+    if !is_happ_maintainer(
+        action.author(),
+        action.prev_action(),
+        action.timestamp(),
+        &happ_release.happ_hash,
+    )? {
+        return Ok(ValidateCallbackResult::Invalid(
+            "Only the maintainers of a hApp can yank its releases".to_string(),
+        ));
+    }
+    if happ_release_yank.reason.trim().is_empty() {
+        return Ok(ValidateCallbackResult::Invalid(
+            "The reason for yanking a hApp release can't be empty.".to_string(),
+        ));
+    }
+    if happ_release_yank.reason.chars().count() > MAX_YANK_REASON_LENGTH {
+        return Ok(ValidateCallbackResult::Invalid(format!(
+            "The reason for yanking a hApp release can't be longer than {MAX_YANK_REASON_LENGTH} characters."
+        )));
+    }
+    Ok(ValidateCallbackResult::Valid)
+}
+
+pub fn validate_update_happ_release_yank(
+    _action: Update,
+    _happ_release_yank: HappReleaseYank,
+    _original_action: EntryCreationAction,
+    _original_happ_release_yank: HappReleaseYank,
+) -> ExternResult<ValidateCallbackResult> {
+    Ok(ValidateCallbackResult::Invalid(
+        "hApp release yanks cannot be updated".to_string(),
+    ))
+}
+
+pub fn validate_delete_happ_release_yank(
+    _action: Delete,
+    _original_action: EntryCreationAction,
+    _original_happ_release_yank: HappReleaseYank,
+) -> ExternResult<ValidateCallbackResult> {
+    Ok(ValidateCallbackResult::Invalid(
+        "hApp release yanks cannot be deleted".to_string(),
+    ))
+}
+
+pub fn validate_create_link_happ_release_yanks(
+    action: CreateLink,
+    base_address: AnyLinkableHash,
+    target_address: AnyLinkableHash,
+    _tag: LinkTag,
+) -> ExternResult<ValidateCallbackResult> {
+    let happ_release_hash =
+        base_address
+            .into_action_hash()
+            .ok_or(wasm_error!(WasmErrorInner::Guest(
+                "No action hash associated with link".to_string()
+            )))?;
+    let action_hash =
+        target_address
+            .into_action_hash()
+            .ok_or(wasm_error!(WasmErrorInner::Guest(
+                "No action hash associated with link".to_string()
+            )))?;
+    let record = must_get_valid_record(action_hash)?;
+    let happ_release_yank: crate::HappReleaseYank = record
+        .entry()
+        .to_app_option()
+        .map_err(|e| wasm_error!(e))?
+        .ok_or(wasm_error!(WasmErrorInner::Guest(
+            "Linked action must reference an entry".to_string()
+        )))?;
+    if happ_release_yank.happ_release_hash.ne(&happ_release_hash) {
+        return Ok(ValidateCallbackResult::Invalid(
+            "HappReleaseYanks links must be created from the yanked hApp release".to_string(),
+        ));
+    }
+    if action.author.ne(record.action().author()) {
+        return Ok(ValidateCallbackResult::Invalid(
+            "Only the author of a hApp release yank can link it".to_string(),
+        ));
+    }
+    Ok(ValidateCallbackResult::Valid)
+}
+
+pub fn validate_delete_link_happ_release_yanks(
+    _action: DeleteLink,
+    _original_action: CreateLink,
+    _base: AnyLinkableHash,
+    _target: AnyLinkableHash,
+    _tag: LinkTag,
+) -> ExternResult<ValidateCallbackResult> {
+    Ok(ValidateCallbackResult::Invalid(
+        "HappReleaseYanks links cannot be deleted".to_string(),
+    ))
+}
@@ -0,0 +1,303 @@
+use hdi::prelude::*;
+
+use crate::{
+    chain_activity::ChainActivity, happ_categories::get_indexed_happ_revision, is_happ_maintainer,
+    LinkTypes,
+};
+
+/// Width of the time buckets of the sort indexes by timestamp: about a month
+pub const SORT_INDEX_BUCKET_MICROS: i64 = 30 * 24 * 60 * 60 * 1_000_000;
+/// The name bucket for the names that don't start with an ASCII letter or digit, which are sorted last
+pub const OTHER_NAMES_BUCKET: &str = "_";
+
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum HappsSortKey {
+    CreatedTime,
+    LastUpdated,
+    LatestReleaseTime,
+    Name,
+}
+
+impl HappsSortKey {
+    fn path_name(&self) -> &'static str {
+        match self {
+            HappsSortKey::CreatedTime => "happs_by_created_time",
+            HappsSortKey::LastUpdated => "happs_by_last_updated",
+            HappsSortKey::LatestReleaseTime => "happs_by_latest_release_time",
+            HappsSortKey::Name => "happs_by_name",
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+#[serde(tag = "type", content = "value")]
+pub enum HappSortValue {
+    Timestamp(Timestamp),
+    Name(String),
+}
+
+impl HappSortValue {
+    /// Names are sorted case-insensitively, ignoring surrounding whitespace
+    pub fn name(name: &str) -> HappSortValue {
+        HappSortValue::Name(name.trim().to_lowercase())
+    }
+}
+
+/// The tag of the HappsSortIndex links, which holds the value that the hApp is sorted by
+///
+/// Pages of the catalog are read from the tags, without fetching the hApps they link to
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+pub struct HappSortIndexTag {
+    pub sort_key: HappsSortKey,
+    pub sort_value: HappSortValue,
+}
+
+impl HappSortIndexTag {
+    pub fn link_tag(&self) -> ExternResult<LinkTag> {
+        let bytes = ExternIO::encode(self).map_err(|e| wasm_error!(e))?;
+        Ok(LinkTag::new(bytes.0))
+    }
+
+    pub fn from_link_tag(tag: &LinkTag) -> Option<HappSortIndexTag> {
+        ExternIO(tag.clone().into_inner()).decode().ok()
+    }
+
+    pub fn path(&self) -> Path {
+        happ_sort_index_path(self.sort_key, &sort_index_bucket(&self.sort_value))
+    }
+}
+
+/// The bucket of the sort index in which the given value is linked
+///
+/// Timestamps are bucketed by time, so that the newest hApps are read from the latest buckets, and names by
+/// their first character
+pub fn sort_index_bucket(sort_value: &HappSortValue) -> String {
+    match sort_value {
+        HappSortValue::Timestamp(timestamp) => timestamp_bucket(timestamp).to_string(),
+        HappSortValue::Name(name) => match name.chars().next() {
+            Some(c) if c.is_ascii_alphanumeric() => c.to_string(),
+            _ => OTHER_NAMES_BUCKET.to_string(),
+        },
+    }
+}
+
+pub fn timestamp_bucket(timestamp: &Timestamp) -> i64 {
+    timestamp.as_micros().div_euclid(SORT_INDEX_BUCKET_MICROS)
+}
+
+/// The name buckets in the order in which they are sorted
+pub fn name_sort_index_buckets() -> Vec<String> {
+    ('0'..='9')
+        .chain('a'..='z')
+        .map(|c| c.to_string())
+        .chain(std::iter::once(OTHER_NAMES_BUCKET.to_string()))
+        .collect()
+}
+
+pub fn happ_sort_index_path(sort_key: HappsSortKey, bucket: &str) -> Path {
+    Path::from(format!("{}.{bucket}", sort_key.path_name()))
+}
+
+/// The path that links to the time buckets of a sort index by timestamp that have hApps in them
+///
+/// Names are bucketed by a fixed set of characters, so the buckets of the sort index by name are not linked
+pub fn happ_sort_index_buckets_path(sort_key: HappsSortKey) -> Path {
+    Path::from(sort_key.path_name())
+}
+
+/// Returns the value that the author of the sort index link can index the hApp with for the given key
+///
+/// Maintainers index a hApp right after creating, updating or releasing it, so the values are derived from the
+/// author's source chain: the last update and latest release times are the times of their latest HappUpdates and
+/// HappToHappReleases links, or the creation time if they have none, and the name is the one of the revision
+/// returned by `get_indexed_happ_revision`
+fn indexed_happ_sort_value(
+    action: &CreateLink,
+    happ_record: &Record,
+    sort_key: HappsSortKey,
+) -> ExternResult<Option<HappSortValue>> {
+    let happ_hash = happ_record.action_address();
+    let created_at = happ_record.action().timestamp();
+    let latest_link_timestamp = |link_type: LinkTypes| -> ExternResult<Timestamp> {
+        let happ_base: AnyLinkableHash = happ_hash.clone().into();
+        let activity = ChainActivity::get(&action.author, &action.prev_action)?;
+        let timestamp = activity.actions().rev().find_map(|action| {
+            let Action::CreateLink(create_link) = &action.content else {
+                return None;
+            };
+            let is_link_type = matches!(
+                LinkTypes::from_type(create_link.zome_index, create_link.link_type),
+                Ok(Some(t)) if t == link_type
+            );
+            match is_link_type && create_link.base_address.eq(&happ_base) {
+                true => Some(create_link.timestamp),
+                false => None,
+            }
+        });
+        Ok(timestamp.unwrap_or(created_at))
+    };
+    let sort_value = match sort_key {
+        HappsSortKey::CreatedTime => Some(HappSortValue::Timestamp(created_at)),
+        HappsSortKey::LastUpdated => Some(HappSortValue::Timestamp(latest_link_timestamp(
+            LinkTypes::HappUpdates,
+        )?)),
+        HappsSortKey::LatestReleaseTime => Some(HappSortValue::Timestamp(latest_link_timestamp(
+            LinkTypes::HappToHappReleases,
+        )?)),
+        HappsSortKey::Name => get_indexed_happ_revision(action, happ_hash)?
+            .map(|happ| HappSortValue::name(&happ.name)),
+    };
+    Ok(sort_value)
+}
+
+pub fn validate_create_link_happs_sort_index(
+    action: CreateLink,
+    base_address: AnyLinkableHash,
+    target_address: AnyLinkableHash,
+    tag: LinkTag,
+) -> ExternResult<ValidateCallbackResult> {
+    let Some(tag) = HappSortIndexTag::from_link_tag(&tag) else {
+        return Ok(ValidateCallbackResult::Invalid(
+            "The tag of a HappsSortIndex link must be the value the hApp is sorted by".to_string(),
+        ));
+    };
+    let matches_key = matches!(
+        (&tag.sort_key, &tag.sort_value),
+        (HappsSortKey::Name, HappSortValue::Name(_))
+            | (
+                HappsSortKey::CreatedTime
+                    | HappsSortKey::LastUpdated
+                    | HappsSortKey::LatestReleaseTime,
+                HappSortValue::Timestamp(_)
+            )
+    );
+    if !matches_key {
+        return Ok(ValidateCallbackResult::Invalid(
+            "The sort value of a HappsSortIndex link doesn't match its sort key".to_string(),
+        ));
+    }
+    if base_address.ne(&tag.path().path_entry_hash()?.into()) {
+        return Ok(ValidateCallbackResult::Invalid(
+            "HappsSortIndex links must be created from the bucket of their sort value".to_string(),
+        ));
+    }
+    let happ_hash = target_address
+        .into_action_hash()
+        .ok_or(wasm_error!(WasmErrorInner::Guest(
+            "No action hash associated with link".to_string()
+        )))?;
+    let record = must_get_valid_record(happ_hash.clone())?;
+    let _happ: crate::Happ = record
+        .entry()
+        .to_app_option()
+        .map_err(|e| wasm_error!(e))?
+        .ok_or(wasm_error!(WasmErrorInner::Guest(
+            "Linked action must reference an entry".to_string()
+        )))?;
+    let Action::Create(_) = record.action() else {
+        return Ok(ValidateCallbackResult::Invalid(
+            "HappsSortIndex links must point to the original hApp".to_string(),
+        ));
+    };
+    if indexed_happ_sort_value(&action, &record, tag.sort_key)?.as_ref() != Some(&tag.sort_value) {
+        return Ok(ValidateCallbackResult::Invalid(
+            "The sort value of a HappsSortIndex link must be the one of the hApp as seen by its author"
+                .to_string(),
+        ));
+    }
+    if !is_happ_maintainer(
+        &action.author,
+        &action.prev_action,
+        &action.timestamp,
+        &happ_hash,
+    )? {
+        return Ok(ValidateCallbackResult::Invalid(
+            "Only the maintainers of a hApp can index it".to_string(),
+        ));
+    }
+    Ok(ValidateCallbackResult::Valid)
+}
+
+pub fn validate_delete_link_happs_sort_index(
+    action: DeleteLink,
+    _original_action: CreateLink,
+    _base: AnyLinkableHash,
+    target: AnyLinkableHash,
+    _tag: LinkTag,
+) -> ExternResult<ValidateCallbackResult> {
+    let happ_hash = target
+        .into_action_hash()
+        .ok_or(wasm_error!(WasmErrorInner::Guest(
+            "No action hash associated with link".to_string()
+        )))?;
+    if !is_happ_maintainer(
+        &action.author,
+        &action.prev_action,
+        &action.timestamp,
+        &happ_hash,
+    )? {
+        return Ok(ValidateCallbackResult::Invalid(
+            "Only the maintainers of a hApp can remove it from the sort index".to_string(),
+        ));
+    }
+    Ok(ValidateCallbackResult::Valid)
+}
+
+pub fn validate_create_link_happs_sort_index_buckets(
+    action: CreateLink,
+    base_address: AnyLinkableHash,
+    target_address: AnyLinkableHash,
+    tag: LinkTag,
+) -> ExternResult<ValidateCallbackResult> {
+    // The tag holds the time bucket that the link points to
+    let bucket = String::from_utf8(tag.into_inner())
+        .ok()
+        .and_then(|bucket| bucket.parse::<i64>().ok());
+    let Some(bucket) = bucket else {
+        return Ok(ValidateCallbackResult::Invalid(
+            "The tag of a HappsSortIndexBuckets link must be a time bucket".to_string(),
+        ));
+    };
+    if bucket > timestamp_bucket(&action.timestamp) {
+        return Ok(ValidateCallbackResult::Invalid(
+            "HappsSortIndexBuckets links can't point to future time buckets".to_string(),
+        ));
+    }
+    let sort_key = [
+        HappsSortKey::CreatedTime,
+        HappsSortKey::LastUpdated,
+        HappsSortKey::LatestReleaseTime,
+    ]
+    .into_iter()
+    .find(|sort_key| {
+        happ_sort_index_buckets_path(*sort_key)
+            .path_entry_hash()
+            .is_ok_and(|hash| base_address.eq(&hash.into()))
+    });
+    let Some(sort_key) = sort_key else {
+        return Ok(ValidateCallbackResult::Invalid(
+            "HappsSortIndexBuckets links must be created from the path of a sort index by timestamp"
+                .to_string(),
+        ));
+    };
+    let bucket_path = happ_sort_index_path(sort_key, &bucket.to_string());
+    if target_address.ne(&bucket_path.path_entry_hash()?.into()) {
+        return Ok(ValidateCallbackResult::Invalid(
+            "HappsSortIndexBuckets links must point to the path of the bucket in their tag"
+                .to_string(),
+        ));
+    }
+    Ok(ValidateCallbackResult::Valid)
+}
+
+pub fn validate_delete_link_happs_sort_index_buckets(
+    _action: DeleteLink,
+    _original_action: CreateLink,
+    _base: AnyLinkableHash,
+    _target: AnyLinkableHash,
+    _tag: LinkTag,
+) -> ExternResult<ValidateCallbackResult> {
+    Ok(ValidateCallbackResult::Invalid(
+        "HappsSortIndexBuckets links cannot be deleted".to_string(),
+    ))
+}
@@ -1,11 +1,18 @@
 use hdi::prelude::*;
 
+use crate::is_happ_maintainer;
+
 pub fn validate_create_link_happ_unpublished(
-    _action: CreateLink,
+    action: CreateLink,
     base_address: AnyLinkableHash,
-    _target_address: AnyLinkableHash,
+    target_address: AnyLinkableHash,
     _tag: LinkTag,
 ) -> ExternResult<ValidateCallbackResult> {
+    if base_address.ne(&target_address) {
+        return Ok(ValidateCallbackResult::Invalid(
+            "HappUnpublished links must point to the hApp they are created from".to_string(),
+        ));
+    }
     let action_hash = base_address
         .into_action_hash()
         .ok_or(wasm_error!(WasmErrorInner::Guest(
@@ -19,17 +26,40 @@ pub fn validate_create_link_happ_unpublished(
         .ok_or(wasm_error!(WasmErrorInner::Guest(
             "Linked action must reference an entry".to_string()
         )))?;
-    // TODO: add the appropriate validation rules
+    if !is_happ_maintainer(
+        &action.author,
+        &action.prev_action,
+        &action.timestamp,
+        record.action_address(),
+    )? {
+        return Ok(ValidateCallbackResult::Invalid(
+            "Only the maintainers of a hApp can unpublish it".to_string(),
+        ));
+    }
     Ok(ValidateCallbackResult::Valid)
 }
 
 pub fn validate_delete_link_happ_unpublished(
-    _action: DeleteLink,
+    action: DeleteLink,
     _original_action: CreateLink,
-    _base: AnyLinkableHash,
+    base: AnyLinkableHash,
     _target: AnyLinkableHash,
     _tag: LinkTag,
 ) -> ExternResult<ValidateCallbackResult> {
-    // TODO: add the appropriate validation rules
+    let action_hash = base
+        .into_action_hash()
+        .ok_or(wasm_error!(WasmErrorInner::Guest(
+            "No action hash associated with link".to_string()
+        )))?;
+    if !is_happ_maintainer(
+        &action.author,
+        &action.prev_action,
+        &action.timestamp,
+        &action_hash,
+    )? {
+        return Ok(ValidateCallbackResult::Invalid(
+            "Only the maintainers of a hApp can republish it".to_string(),
+        ));
+    }
     Ok(ValidateCallbackResult::Valid)
 }
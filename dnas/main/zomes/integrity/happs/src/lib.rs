@@ -2,8 +2,36 @@ pub mod happ_unpublished;
 pub use happ_unpublished::*;
 pub mod happ_release;
 pub use happ_release::*;
+pub mod happ_release_yank;
+pub use happ_release_yank::*;
 pub mod happ;
 pub use happ::*;
+pub mod happ_categories;
+pub use happ_categories::*;
+pub mod happ_sort_index;
+pub use happ_sort_index::*;
+pub mod search_index;
+pub use search_index::*;
+pub mod review;
+pub use review::*;
+pub mod properties;
+pub use properties::*;
+pub mod report;
+pub use report::*;
+pub mod moderation;
+pub use moderation::*;
+pub mod publisher_credential;
+pub use publisher_credential::*;
+pub mod maintainers;
+pub use maintainers::*;
+pub mod ownership;
+pub use ownership::*;
+pub mod publisher;
+pub use publisher::*;
+pub mod domain_claim;
+pub use domain_claim::*;
+pub mod chain_activity;
+pub mod update_links;
 use hdi::prelude::*;
 
 #[derive(Serialize, Deserialize)]
@@ -13,6 +41,19 @@ use hdi::prelude::*;
 pub enum EntryTypes {
     Happ(Happ),
     HappRelease(HappRelease),
+    HappReleaseYank(HappReleaseYank),
+    MaintainerClaim(MaintainerClaim),
+    OwnershipOffer(OwnershipOffer),
+    OwnershipTransfer(OwnershipTransfer),
+    Publisher(Publisher),
+    DomainClaim(DomainClaim),
+    Review(Review),
+    ReviewReply(ReviewReply),
+    Report(Report),
+    ReportResolution(ReportResolution),
+    ModeratorClaim(ModeratorClaim),
+    PublisherCredential(PublisherCredential),
+    OwnershipOfferCancellation(OwnershipOfferCancellation),
 }
 
 #[derive(Serialize, Deserialize)]
@@ -24,22 +65,44 @@ pub enum LinkTypes {
     HappReleaseUpdates,
     AllHapps,
     PublisherHapps,
+    HappReleasesByChannel,
+    HappReleaseYanks,
+    HappMaintainers,
+    HappOwnershipOffers,
+    HappOwnershipTransfers,
+    AgentToPublisher,
+    PublisherUpdates,
+    PublisherDomainClaims,
+    CategoryToHapps,
+    TagToHapps,
+    SearchIndex,
+    HappReviews,
+    ReviewUpdates,
+    ReviewReplies,
+    HappReports,
+    OpenReports,
+    ReportResolutions,
+    Moderators,
+    HiddenHapps,
+    HappsSortIndex,
+    HappsSortIndexBuckets,
 }
 
 // Validation you perform during the genesis process. Nobody else on the network performs it, only you.
 // There *is no* access to network calls in this callback
 #[hdk_extern]
-pub fn genesis_self_check(_data: GenesisSelfCheckData) -> ExternResult<ValidateCallbackResult> {
-    Ok(ValidateCallbackResult::Valid)
+pub fn genesis_self_check(data: GenesisSelfCheckData) -> ExternResult<ValidateCallbackResult> {
+    // Fails early for malformed publisher credentials, instead of being rejected by the network
+    validate_membrane_proof(&data.agent_key, &data.membrane_proof)
 }
 
 // Validation the network performs when you try to join, you can't perform this validation yourself as you are not a member yet.
 // There *is* access to network calls in this function
 pub fn validate_agent_joining(
-    _agent_pub_key: AgentPubKey,
-    _membrane_proof: &Option<MembraneProof>,
+    agent_pub_key: AgentPubKey,
+    membrane_proof: &Option<MembraneProof>,
 ) -> ExternResult<ValidateCallbackResult> {
-    Ok(ValidateCallbackResult::Valid)
+    validate_membrane_proof(&agent_pub_key, membrane_proof)
 }
 
 // This is the unified validation callback for all entries and link types in this integrity zome
@@ -71,6 +134,63 @@ pub fn validate(op: Op) -> ExternResult<ValidateCallbackResult> {
                 EntryTypes::HappRelease(happ_release) => {
                     validate_create_happ_release(EntryCreationAction::Create(action), happ_release)
                 }
+                EntryTypes::HappReleaseYank(happ_release_yank) => {
+                    validate_create_happ_release_yank(
+                        EntryCreationAction::Create(action),
+                        happ_release_yank,
+                    )
+                }
+                EntryTypes::MaintainerClaim(maintainer_claim) => validate_create_maintainer_claim(
+                    EntryCreationAction::Create(action),
+                    maintainer_claim,
+                ),
+                EntryTypes::OwnershipOffer(ownership_offer) => validate_create_ownership_offer(
+                    EntryCreationAction::Create(action),
+                    ownership_offer,
+                ),
+                EntryTypes::OwnershipTransfer(ownership_transfer) => {
+                    validate_create_ownership_transfer(
+                        EntryCreationAction::Create(action),
+                        ownership_transfer,
+                    )
+                }
+                EntryTypes::Publisher(publisher) => {
+                    validate_create_publisher(EntryCreationAction::Create(action), publisher)
+                }
+                EntryTypes::DomainClaim(domain_claim) => {
+                    validate_create_domain_claim(EntryCreationAction::Create(action), domain_claim)
+                }
+                EntryTypes::Review(review) => {
+                    validate_create_review(EntryCreationAction::Create(action), review)
+                }
+                EntryTypes::ReviewReply(review_reply) => {
+                    validate_create_review_reply(EntryCreationAction::Create(action), review_reply)
+                }
+                EntryTypes::Report(report) => {
+                    validate_create_report(EntryCreationAction::Create(action), report)
+                }
+                EntryTypes::ReportResolution(report_resolution) => {
+                    validate_create_report_resolution(
+                        EntryCreationAction::Create(action),
+                        report_resolution,
+                    )
+                }
+                EntryTypes::ModeratorClaim(moderator_claim) => validate_create_moderator_claim(
+                    EntryCreationAction::Create(action),
+                    moderator_claim,
+                ),
+                EntryTypes::PublisherCredential(publisher_credential) => {
+                    validate_create_publisher_credential(
+                        EntryCreationAction::Create(action),
+                        publisher_credential,
+                    )
+                }
+                EntryTypes::OwnershipOfferCancellation(ownership_offer_cancellation) => {
+                    validate_create_ownership_offer_cancellation(
+                        EntryCreationAction::Create(action),
+                        ownership_offer_cancellation,
+                    )
+                }
             },
             OpEntry::UpdateEntry {
                 app_entry, action, ..
@@ -81,6 +201,63 @@ pub fn validate(op: Op) -> ExternResult<ValidateCallbackResult> {
                 EntryTypes::HappRelease(happ_release) => {
                     validate_create_happ_release(EntryCreationAction::Update(action), happ_release)
                 }
+                EntryTypes::HappReleaseYank(happ_release_yank) => {
+                    validate_create_happ_release_yank(
+                        EntryCreationAction::Update(action),
+                        happ_release_yank,
+                    )
+                }
+                EntryTypes::MaintainerClaim(maintainer_claim) => validate_create_maintainer_claim(
+                    EntryCreationAction::Update(action),
+                    maintainer_claim,
+                ),
+                EntryTypes::OwnershipOffer(ownership_offer) => validate_create_ownership_offer(
+                    EntryCreationAction::Update(action),
+                    ownership_offer,
+                ),
+                EntryTypes::OwnershipTransfer(ownership_transfer) => {
+                    validate_create_ownership_transfer(
+                        EntryCreationAction::Update(action),
+                        ownership_transfer,
+                    )
+                }
+                EntryTypes::Publisher(publisher) => {
+                    validate_create_publisher(EntryCreationAction::Update(action), publisher)
+                }
+                EntryTypes::DomainClaim(domain_claim) => {
+                    validate_create_domain_claim(EntryCreationAction::Update(action), domain_claim)
+                }
+                EntryTypes::Review(review) => {
+                    validate_create_review(EntryCreationAction::Update(action), review)
+                }
+                EntryTypes::ReviewReply(review_reply) => {
+                    validate_create_review_reply(EntryCreationAction::Update(action), review_reply)
+                }
+                EntryTypes::Report(report) => {
+                    validate_create_report(EntryCreationAction::Update(action), report)
+                }
+                EntryTypes::ReportResolution(report_resolution) => {
+                    validate_create_report_resolution(
+                        EntryCreationAction::Update(action),
+                        report_resolution,
+                    )
+                }
+                EntryTypes::ModeratorClaim(moderator_claim) => validate_create_moderator_claim(
+                    EntryCreationAction::Update(action),
+                    moderator_claim,
+                ),
+                EntryTypes::PublisherCredential(publisher_credential) => {
+                    validate_create_publisher_credential(
+                        EntryCreationAction::Update(action),
+                        publisher_credential,
+                    )
+                }
+                EntryTypes::OwnershipOfferCancellation(ownership_offer_cancellation) => {
+                    validate_create_ownership_offer_cancellation(
+                        EntryCreationAction::Update(action),
+                        ownership_offer_cancellation,
+                    )
+                }
             },
             _ => Ok(ValidateCallbackResult::Valid),
         },
@@ -130,6 +307,250 @@ pub fn validate(op: Op) -> ExternResult<ValidateCallbackResult> {
                         };
                         validate_update_happ(action, happ, original_create_action, original_happ)
                     }
+                    EntryTypes::HappReleaseYank(happ_release_yank) => {
+                        let original_app_entry =
+                            must_get_valid_record(action.clone().original_action_address)?;
+                        let original_happ_release_yank =
+                            match HappReleaseYank::try_from(original_app_entry) {
+                                Ok(entry) => entry,
+                                Err(e) => {
+                                    return Ok(ValidateCallbackResult::Invalid(format!(
+                                        "Expected to get HappReleaseYank from Record: {e:?}"
+                                    )));
+                                }
+                            };
+                        validate_update_happ_release_yank(
+                            action,
+                            happ_release_yank,
+                            original_create_action,
+                            original_happ_release_yank,
+                        )
+                    }
+                    EntryTypes::MaintainerClaim(maintainer_claim) => {
+                        let original_app_entry =
+                            must_get_valid_record(action.clone().original_action_address)?;
+                        let original_maintainer_claim =
+                            match MaintainerClaim::try_from(original_app_entry) {
+                                Ok(entry) => entry,
+                                Err(e) => {
+                                    return Ok(ValidateCallbackResult::Invalid(format!(
+                                        "Expected to get MaintainerClaim from Record: {e:?}"
+                                    )));
+                                }
+                            };
+                        validate_update_maintainer_claim(
+                            action,
+                            maintainer_claim,
+                            original_create_action,
+                            original_maintainer_claim,
+                        )
+                    }
+                    EntryTypes::OwnershipOffer(ownership_offer) => {
+                        let original_app_entry =
+                            must_get_valid_record(action.clone().original_action_address)?;
+                        let original_ownership_offer =
+                            match OwnershipOffer::try_from(original_app_entry) {
+                                Ok(entry) => entry,
+                                Err(e) => {
+                                    return Ok(ValidateCallbackResult::Invalid(format!(
+                                        "Expected to get OwnershipOffer from Record: {e:?}"
+                                    )));
+                                }
+                            };
+                        validate_update_ownership_offer(
+                            action,
+                            ownership_offer,
+                            original_create_action,
+                            original_ownership_offer,
+                        )
+                    }
+                    EntryTypes::OwnershipTransfer(ownership_transfer) => {
+                        let original_app_entry =
+                            must_get_valid_record(action.clone().original_action_address)?;
+                        let original_ownership_transfer =
+                            match OwnershipTransfer::try_from(original_app_entry) {
+                                Ok(entry) => entry,
+                                Err(e) => {
+                                    return Ok(ValidateCallbackResult::Invalid(format!(
+                                        "Expected to get OwnershipTransfer from Record: {e:?}"
+                                    )));
+                                }
+                            };
+                        validate_update_ownership_transfer(
+                            action,
+                            ownership_transfer,
+                            original_create_action,
+                            original_ownership_transfer,
+                        )
+                    }
+                    EntryTypes::Publisher(publisher) => {
+                        let original_app_entry =
+                            must_get_valid_record(action.clone().original_action_address)?;
+                        let original_publisher = match Publisher::try_from(original_app_entry) {
+                            Ok(entry) => entry,
+                            Err(e) => {
+                                return Ok(ValidateCallbackResult::Invalid(format!(
+                                    "Expected to get Publisher from Record: {e:?}"
+                                )));
+                            }
+                        };
+                        validate_update_publisher(
+                            action,
+                            publisher,
+                            original_create_action,
+                            original_publisher,
+                        )
+                    }
+                    EntryTypes::DomainClaim(domain_claim) => {
+                        let original_app_entry =
+                            must_get_valid_record(action.clone().original_action_address)?;
+                        let original_domain_claim = match DomainClaim::try_from(original_app_entry)
+                        {
+                            Ok(entry) => entry,
+                            Err(e) => {
+                                return Ok(ValidateCallbackResult::Invalid(format!(
+                                    "Expected to get DomainClaim from Record: {e:?}"
+                                )));
+                            }
+                        };
+                        validate_update_domain_claim(
+                            action,
+                            domain_claim,
+                            original_create_action,
+                            original_domain_claim,
+                        )
+                    }
+                    EntryTypes::Review(review) => {
+                        let original_app_entry =
+                            must_get_valid_record(action.clone().original_action_address)?;
+                        let original_review = match Review::try_from(original_app_entry) {
+                            Ok(entry) => entry,
+                            Err(e) => {
+                                return Ok(ValidateCallbackResult::Invalid(format!(
+                                    "Expected to get Review from Record: {e:?}"
+                                )));
+                            }
+                        };
+                        validate_update_review(
+                            action,
+                            review,
+                            original_create_action,
+                            original_review,
+                        )
+                    }
+                    EntryTypes::ReviewReply(review_reply) => {
+                        let original_app_entry =
+                            must_get_valid_record(action.clone().original_action_address)?;
+                        let original_review_reply = match ReviewReply::try_from(original_app_entry)
+                        {
+                            Ok(entry) => entry,
+                            Err(e) => {
+                                return Ok(ValidateCallbackResult::Invalid(format!(
+                                    "Expected to get ReviewReply from Record: {e:?}"
+                                )));
+                            }
+                        };
+                        validate_update_review_reply(
+                            action,
+                            review_reply,
+                            original_create_action,
+                            original_review_reply,
+                        )
+                    }
+                    EntryTypes::Report(report) => {
+                        let original_app_entry =
+                            must_get_valid_record(action.clone().original_action_address)?;
+                        let original_report = match Report::try_from(original_app_entry) {
+                            Ok(entry) => entry,
+                            Err(e) => {
+                                return Ok(ValidateCallbackResult::Invalid(format!(
+                                    "Expected to get Report from Record: {e:?}"
+                                )));
+                            }
+                        };
+                        validate_update_report(
+                            action,
+                            report,
+                            original_create_action,
+                            original_report,
+                        )
+                    }
+                    EntryTypes::ReportResolution(report_resolution) => {
+                        let original_app_entry =
+                            must_get_valid_record(action.clone().original_action_address)?;
+                        let original_report_resolution =
+                            match ReportResolution::try_from(original_app_entry) {
+                                Ok(entry) => entry,
+                                Err(e) => {
+                                    return Ok(ValidateCallbackResult::Invalid(format!(
+                                        "Expected to get ReportResolution from Record: {e:?}"
+                                    )));
+                                }
+                            };
+                        validate_update_report_resolution(
+                            action,
+                            report_resolution,
+                            original_create_action,
+                            original_report_resolution,
+                        )
+                    }
+                    EntryTypes::ModeratorClaim(moderator_claim) => {
+                        let original_app_entry =
+                            must_get_valid_record(action.clone().original_action_address)?;
+                        let original_moderator_claim =
+                            match ModeratorClaim::try_from(original_app_entry) {
+                                Ok(entry) => entry,
+                                Err(e) => {
+                                    return Ok(ValidateCallbackResult::Invalid(format!(
+                                        "Expected to get ModeratorClaim from Record: {e:?}"
+                                    )));
+                                }
+                            };
+                        validate_update_moderator_claim(
+                            action,
+                            moderator_claim,
+                            original_create_action,
+                            original_moderator_claim,
+                        )
+                    }
+                    EntryTypes::PublisherCredential(publisher_credential) => {
+                        let original_app_entry =
+                            must_get_valid_record(action.clone().original_action_address)?;
+                        let original_publisher_credential =
+                            match PublisherCredential::try_from(original_app_entry) {
+                                Ok(entry) => entry,
+                                Err(e) => {
+                                    return Ok(ValidateCallbackResult::Invalid(format!(
+                                        "Expected to get PublisherCredential from Record: {e:?}"
+                                    )));
+                                }
+                            };
+                        validate_update_publisher_credential(
+                            action,
+                            publisher_credential,
+                            original_create_action,
+                            original_publisher_credential,
+                        )
+                    }
+                    EntryTypes::OwnershipOfferCancellation(ownership_offer_cancellation) => {
+                        let original_app_entry =
+                            must_get_valid_record(action.clone().original_action_address)?;
+                        let original_ownership_offer_cancellation =
+                            match OwnershipOfferCancellation::try_from(original_app_entry) {
+                                Ok(entry) => entry,
+                                Err(e) => {
+                                    return Ok(ValidateCallbackResult::Invalid(format!(
+                                        "Expected to get OwnershipOfferCancellation from Record: {e:?}"
+                                    )));
+                                }
+                            };
+                        validate_update_ownership_offer_cancellation(
+                            action,
+                            ownership_offer_cancellation,
+                            original_create_action,
+                            original_ownership_offer_cancellation,
+                        )
+                    }
                 }
             }
             _ => Ok(ValidateCallbackResult::Valid),
@@ -184,6 +605,87 @@ pub fn validate(op: Op) -> ExternResult<ValidateCallbackResult> {
                     original_action,
                     original_happ,
                 ),
+                EntryTypes::HappReleaseYank(original_happ_release_yank) => {
+                    validate_delete_happ_release_yank(
+                        delete_entry.clone().action,
+                        original_action,
+                        original_happ_release_yank,
+                    )
+                }
+                EntryTypes::MaintainerClaim(original_maintainer_claim) => {
+                    validate_delete_maintainer_claim(
+                        delete_entry.clone().action,
+                        original_action,
+                        original_maintainer_claim,
+                    )
+                }
+                EntryTypes::OwnershipOffer(original_ownership_offer) => {
+                    validate_delete_ownership_offer(
+                        delete_entry.clone().action,
+                        original_action,
+                        original_ownership_offer,
+                    )
+                }
+                EntryTypes::OwnershipTransfer(original_ownership_transfer) => {
+                    validate_delete_ownership_transfer(
+                        delete_entry.clone().action,
+                        original_action,
+                        original_ownership_transfer,
+                    )
+                }
+                EntryTypes::Publisher(original_publisher) => validate_delete_publisher(
+                    delete_entry.clone().action,
+                    original_action,
+                    original_publisher,
+                ),
+                EntryTypes::DomainClaim(original_domain_claim) => validate_delete_domain_claim(
+                    delete_entry.clone().action,
+                    original_action,
+                    original_domain_claim,
+                ),
+                EntryTypes::Review(original_review) => validate_delete_review(
+                    delete_entry.clone().action,
+                    original_action,
+                    original_review,
+                ),
+                EntryTypes::ReviewReply(original_review_reply) => validate_delete_review_reply(
+                    delete_entry.clone().action,
+                    original_action,
+                    original_review_reply,
+                ),
+                EntryTypes::Report(original_report) => validate_delete_report(
+                    delete_entry.clone().action,
+                    original_action,
+                    original_report,
+                ),
+                EntryTypes::ReportResolution(original_report_resolution) => {
+                    validate_delete_report_resolution(
+                        delete_entry.clone().action,
+                        original_action,
+                        original_report_resolution,
+                    )
+                }
+                EntryTypes::ModeratorClaim(original_moderator_claim) => {
+                    validate_delete_moderator_claim(
+                        delete_entry.clone().action,
+                        original_action,
+                        original_moderator_claim,
+                    )
+                }
+                EntryTypes::PublisherCredential(original_publisher_credential) => {
+                    validate_delete_publisher_credential(
+                        delete_entry.clone().action,
+                        original_action,
+                        original_publisher_credential,
+                    )
+                }
+                EntryTypes::OwnershipOfferCancellation(original_ownership_offer_cancellation) => {
+                    validate_delete_ownership_offer_cancellation(
+                        delete_entry.clone().action,
+                        original_action,
+                        original_ownership_offer_cancellation,
+                    )
+                }
             }
         }
         FlatOp::RegisterCreateLink {
@@ -214,6 +716,84 @@ pub fn validate(op: Op) -> ExternResult<ValidateCallbackResult> {
             LinkTypes::HappUnpublished => {
                 validate_create_link_happ_unpublished(action, base_address, target_address, tag)
             }
+            LinkTypes::HappReleasesByChannel => validate_create_link_happ_releases_by_channel(
+                action,
+                base_address,
+                target_address,
+                tag,
+            ),
+            LinkTypes::HappReleaseYanks => {
+                validate_create_link_happ_release_yanks(action, base_address, target_address, tag)
+            }
+            LinkTypes::HappMaintainers => {
+                validate_create_link_happ_maintainers(action, base_address, target_address, tag)
+            }
+            LinkTypes::HappOwnershipOffers => validate_create_link_happ_ownership_offers(
+                action,
+                base_address,
+                target_address,
+                tag,
+            ),
+            LinkTypes::HappOwnershipTransfers => validate_create_link_happ_ownership_transfers(
+                action,
+                base_address,
+                target_address,
+                tag,
+            ),
+            LinkTypes::AgentToPublisher => {
+                validate_create_link_agent_to_publisher(action, base_address, target_address, tag)
+            }
+            LinkTypes::PublisherUpdates => {
+                validate_create_link_publisher_updates(action, base_address, target_address, tag)
+            }
+            LinkTypes::PublisherDomainClaims => validate_create_link_publisher_domain_claims(
+                action,
+                base_address,
+                target_address,
+                tag,
+            ),
+            LinkTypes::CategoryToHapps => {
+                validate_create_link_category_to_happs(action, base_address, target_address, tag)
+            }
+            LinkTypes::TagToHapps => {
+                validate_create_link_tag_to_happs(action, base_address, target_address, tag)
+            }
+            LinkTypes::SearchIndex => {
+                validate_create_link_search_index(action, base_address, target_address, tag)
+            }
+            LinkTypes::ReviewReplies => {
+                validate_create_link_review_replies(action, base_address, target_address, tag)
+            }
+            LinkTypes::ReportResolutions => {
+                validate_create_link_report_resolutions(action, base_address, target_address, tag)
+            }
+            LinkTypes::HiddenHapps => {
+                validate_create_link_hidden_happs(action, base_address, target_address, tag)
+            }
+            LinkTypes::HappsSortIndex => {
+                validate_create_link_happs_sort_index(action, base_address, target_address, tag)
+            }
+            LinkTypes::HappsSortIndexBuckets => validate_create_link_happs_sort_index_buckets(
+                action,
+                base_address,
+                target_address,
+                tag,
+            ),
+            LinkTypes::Moderators => {
+                validate_create_link_moderators(action, base_address, target_address, tag)
+            }
+            LinkTypes::OpenReports => {
+                validate_create_link_open_reports(action, base_address, target_address, tag)
+            }
+            LinkTypes::HappReports => {
+                validate_create_link_happ_reports(action, base_address, target_address, tag)
+            }
+            LinkTypes::ReviewUpdates => {
+                validate_create_link_review_updates(action, base_address, target_address, tag)
+            }
+            LinkTypes::HappReviews => {
+                validate_create_link_happ_reviews(action, base_address, target_address, tag)
+            }
         },
         FlatOp::RegisterDeleteLink {
             link_type,
@@ -265,57 +845,265 @@ pub fn validate(op: Op) -> ExternResult<ValidateCallbackResult> {
                 target_address,
                 tag,
             ),
-        },
-        FlatOp::StoreRecord(store_record) => {
-            match store_record {
-                // Complementary validation to the `StoreEntry` Op, in which the record itself is validated
-                // If you want to optimize performance, you can remove the validation for an entry type here and keep it in `StoreEntry`
-                // Notice that doing so will cause `must_get_valid_record` for this record to return a valid record even if the `StoreEntry` validation failed
-                OpRecord::CreateEntry { app_entry, action } => match app_entry {
-                    EntryTypes::Happ(happ) => {
-                        validate_create_happ(EntryCreationAction::Create(action), happ)
-                    }
-                    EntryTypes::HappRelease(happ_release) => validate_create_happ_release(
-                        EntryCreationAction::Create(action),
-                        happ_release,
-                    ),
-                },
-                // Complementary validation to the `RegisterUpdate` Op, in which the record itself is validated
-                // If you want to optimize performance, you can remove the validation for an entry type here and keep it in `StoreEntry` and in `RegisterUpdate`
-                // Notice that doing so will cause `must_get_valid_record` for this record to return a valid record even if the other validations failed
-                OpRecord::UpdateEntry {
-                    original_action_hash,
-                    app_entry,
-                    action,
-                    ..
-                } => {
-                    let original_record = must_get_valid_record(original_action_hash)?;
-                    let original_action = original_record.action().clone();
-                    let original_action = match original_action {
-                        Action::Create(create) => EntryCreationAction::Create(create),
-                        Action::Update(update) => EntryCreationAction::Update(update),
-                        _ => {
-                            return Ok(ValidateCallbackResult::Invalid(
-                                "Original action for an update must be a Create or Update action"
-                                    .to_string(),
-                            ));
-                        }
-                    };
-                    match app_entry {
-                        EntryTypes::Happ(happ) => {
-                            let result = validate_create_happ(
-                                EntryCreationAction::Update(action.clone()),
-                                happ.clone(),
-                            )?;
-                            if let ValidateCallbackResult::Valid = result {
-                                let original_happ: Option<Happ> = original_record
-                                    .entry()
-                                    .to_app_option()
-                                    .map_err(|e| wasm_error!(e))?;
-                                let original_happ = match original_happ {
-                                    Some(happ) => happ,
-                                    None => {
-                                        return Ok(
+            LinkTypes::HappReleasesByChannel => validate_delete_link_happ_releases_by_channel(
+                action,
+                original_action,
+                base_address,
+                target_address,
+                tag,
+            ),
+            LinkTypes::HappReleaseYanks => validate_delete_link_happ_release_yanks(
+                action,
+                original_action,
+                base_address,
+                target_address,
+                tag,
+            ),
+            LinkTypes::HappMaintainers => validate_delete_link_happ_maintainers(
+                action,
+                original_action,
+                base_address,
+                target_address,
+                tag,
+            ),
+            LinkTypes::HappOwnershipOffers => validate_delete_link_happ_ownership_offers(
+                action,
+                original_action,
+                base_address,
+                target_address,
+                tag,
+            ),
+            LinkTypes::HappOwnershipTransfers => validate_delete_link_happ_ownership_transfers(
+                action,
+                original_action,
+                base_address,
+                target_address,
+                tag,
+            ),
+            LinkTypes::AgentToPublisher => validate_delete_link_agent_to_publisher(
+                action,
+                original_action,
+                base_address,
+                target_address,
+                tag,
+            ),
+            LinkTypes::PublisherUpdates => validate_delete_link_publisher_updates(
+                action,
+                original_action,
+                base_address,
+                target_address,
+                tag,
+            ),
+            LinkTypes::PublisherDomainClaims => validate_delete_link_publisher_domain_claims(
+                action,
+                original_action,
+                base_address,
+                target_address,
+                tag,
+            ),
+            LinkTypes::CategoryToHapps => validate_delete_link_category_to_happs(
+                action,
+                original_action,
+                base_address,
+                target_address,
+                tag,
+            ),
+            LinkTypes::TagToHapps => validate_delete_link_tag_to_happs(
+                action,
+                original_action,
+                base_address,
+                target_address,
+                tag,
+            ),
+            LinkTypes::SearchIndex => validate_delete_link_search_index(
+                action,
+                original_action,
+                base_address,
+                target_address,
+                tag,
+            ),
+            LinkTypes::ReviewReplies => validate_delete_link_review_replies(
+                action,
+                original_action,
+                base_address,
+                target_address,
+                tag,
+            ),
+            LinkTypes::ReportResolutions => validate_delete_link_report_resolutions(
+                action,
+                original_action,
+                base_address,
+                target_address,
+                tag,
+            ),
+            LinkTypes::HiddenHapps => validate_delete_link_hidden_happs(
+                action,
+                original_action,
+                base_address,
+                target_address,
+                tag,
+            ),
+            LinkTypes::HappsSortIndex => validate_delete_link_happs_sort_index(
+                action,
+                original_action,
+                base_address,
+                target_address,
+                tag,
+            ),
+            LinkTypes::HappsSortIndexBuckets => validate_delete_link_happs_sort_index_buckets(
+                action,
+                original_action,
+                base_address,
+                target_address,
+                tag,
+            ),
+            LinkTypes::Moderators => validate_delete_link_moderators(
+                action,
+                original_action,
+                base_address,
+                target_address,
+                tag,
+            ),
+            LinkTypes::OpenReports => validate_delete_link_open_reports(
+                action,
+                original_action,
+                base_address,
+                target_address,
+                tag,
+            ),
+            LinkTypes::HappReports => validate_delete_link_happ_reports(
+                action,
+                original_action,
+                base_address,
+                target_address,
+                tag,
+            ),
+            LinkTypes::ReviewUpdates => validate_delete_link_review_updates(
+                action,
+                original_action,
+                base_address,
+                target_address,
+                tag,
+            ),
+            LinkTypes::HappReviews => validate_delete_link_happ_reviews(
+                action,
+                original_action,
+                base_address,
+                target_address,
+                tag,
+            ),
+        },
+        FlatOp::StoreRecord(store_record) => {
+            match store_record {
+                // Complementary validation to the `StoreEntry` Op, in which the record itself is validated
+                // If you want to optimize performance, you can remove the validation for an entry type here and keep it in `StoreEntry`
+                // Notice that doing so will cause `must_get_valid_record` for this record to return a valid record even if the `StoreEntry` validation failed
+                OpRecord::CreateEntry { app_entry, action } => match app_entry {
+                    EntryTypes::Happ(happ) => {
+                        validate_create_happ(EntryCreationAction::Create(action), happ)
+                    }
+                    EntryTypes::HappRelease(happ_release) => validate_create_happ_release(
+                        EntryCreationAction::Create(action),
+                        happ_release,
+                    ),
+                    EntryTypes::HappReleaseYank(happ_release_yank) => {
+                        validate_create_happ_release_yank(
+                            EntryCreationAction::Create(action),
+                            happ_release_yank,
+                        )
+                    }
+                    EntryTypes::MaintainerClaim(maintainer_claim) => {
+                        validate_create_maintainer_claim(
+                            EntryCreationAction::Create(action),
+                            maintainer_claim,
+                        )
+                    }
+                    EntryTypes::OwnershipOffer(ownership_offer) => validate_create_ownership_offer(
+                        EntryCreationAction::Create(action),
+                        ownership_offer,
+                    ),
+                    EntryTypes::OwnershipTransfer(ownership_transfer) => {
+                        validate_create_ownership_transfer(
+                            EntryCreationAction::Create(action),
+                            ownership_transfer,
+                        )
+                    }
+                    EntryTypes::Publisher(publisher) => {
+                        validate_create_publisher(EntryCreationAction::Create(action), publisher)
+                    }
+                    EntryTypes::DomainClaim(domain_claim) => validate_create_domain_claim(
+                        EntryCreationAction::Create(action),
+                        domain_claim,
+                    ),
+                    EntryTypes::Review(review) => {
+                        validate_create_review(EntryCreationAction::Create(action), review)
+                    }
+                    EntryTypes::ReviewReply(review_reply) => validate_create_review_reply(
+                        EntryCreationAction::Create(action),
+                        review_reply,
+                    ),
+                    EntryTypes::Report(report) => {
+                        validate_create_report(EntryCreationAction::Create(action), report)
+                    }
+                    EntryTypes::ReportResolution(report_resolution) => {
+                        validate_create_report_resolution(
+                            EntryCreationAction::Create(action),
+                            report_resolution,
+                        )
+                    }
+                    EntryTypes::ModeratorClaim(moderator_claim) => validate_create_moderator_claim(
+                        EntryCreationAction::Create(action),
+                        moderator_claim,
+                    ),
+                    EntryTypes::PublisherCredential(publisher_credential) => {
+                        validate_create_publisher_credential(
+                            EntryCreationAction::Create(action),
+                            publisher_credential,
+                        )
+                    }
+                    EntryTypes::OwnershipOfferCancellation(ownership_offer_cancellation) => {
+                        validate_create_ownership_offer_cancellation(
+                            EntryCreationAction::Create(action),
+                            ownership_offer_cancellation,
+                        )
+                    }
+                },
+                // Complementary validation to the `RegisterUpdate` Op, in which the record itself is validated
+                // If you want to optimize performance, you can remove the validation for an entry type here and keep it in `StoreEntry` and in `RegisterUpdate`
+                // Notice that doing so will cause `must_get_valid_record` for this record to return a valid record even if the other validations failed
+                OpRecord::UpdateEntry {
+                    original_action_hash,
+                    app_entry,
+                    action,
+                    ..
+                } => {
+                    let original_record = must_get_valid_record(original_action_hash)?;
+                    let original_action = original_record.action().clone();
+                    let original_action = match original_action {
+                        Action::Create(create) => EntryCreationAction::Create(create),
+                        Action::Update(update) => EntryCreationAction::Update(update),
+                        _ => {
+                            return Ok(ValidateCallbackResult::Invalid(
+                                "Original action for an update must be a Create or Update action"
+                                    .to_string(),
+                            ));
+                        }
+                    };
+                    match app_entry {
+                        EntryTypes::Happ(happ) => {
+                            let result = validate_create_happ(
+                                EntryCreationAction::Update(action.clone()),
+                                happ.clone(),
+                            )?;
+                            if let ValidateCallbackResult::Valid = result {
+                                let original_happ: Option<Happ> = original_record
+                                    .entry()
+                                    .to_app_option()
+                                    .map_err(|e| wasm_error!(e))?;
+                                let original_happ = match original_happ {
+                                    Some(happ) => happ,
+                                    None => {
+                                        return Ok(
                                             ValidateCallbackResult::Invalid(
                                                 "The updated entry type must be the same as the original entry type"
                                                     .to_string(),
@@ -331,29 +1119,446 @@ pub fn validate(op: Op) -> ExternResult<ValidateCallbackResult> {
                         EntryTypes::HappRelease(happ_release) => {
                             let result = validate_create_happ_release(
                                 EntryCreationAction::Update(action.clone()),
-                                happ_release.clone(),
+                                happ_release.clone(),
+                            )?;
+                            if let ValidateCallbackResult::Valid = result {
+                                let original_happ_release: Option<HappRelease> = original_record
+                                    .entry()
+                                    .to_app_option()
+                                    .map_err(|e| wasm_error!(e))?;
+                                let original_happ_release = match original_happ_release {
+                                    Some(happ_release) => happ_release,
+                                    None => {
+                                        return Ok(
+                                            ValidateCallbackResult::Invalid(
+                                                "The updated entry type must be the same as the original entry type"
+                                                    .to_string(),
+                                            ),
+                                        );
+                                    }
+                                };
+                                validate_update_happ_release(
+                                    action,
+                                    happ_release,
+                                    original_action,
+                                    original_happ_release,
+                                )
+                            } else {
+                                Ok(result)
+                            }
+                        }
+                        EntryTypes::HappReleaseYank(happ_release_yank) => {
+                            let result = validate_create_happ_release_yank(
+                                EntryCreationAction::Update(action.clone()),
+                                happ_release_yank.clone(),
+                            )?;
+                            if let ValidateCallbackResult::Valid = result {
+                                let original_happ_release_yank: Option<HappReleaseYank> =
+                                    original_record
+                                        .entry()
+                                        .to_app_option()
+                                        .map_err(|e| wasm_error!(e))?;
+                                let original_happ_release_yank = match original_happ_release_yank {
+                                    Some(happ_release_yank) => happ_release_yank,
+                                    None => {
+                                        return Ok(
+                                            ValidateCallbackResult::Invalid(
+                                                "The updated entry type must be the same as the original entry type"
+                                                    .to_string(),
+                                            ),
+                                        );
+                                    }
+                                };
+                                validate_update_happ_release_yank(
+                                    action,
+                                    happ_release_yank,
+                                    original_action,
+                                    original_happ_release_yank,
+                                )
+                            } else {
+                                Ok(result)
+                            }
+                        }
+                        EntryTypes::MaintainerClaim(maintainer_claim) => {
+                            let result = validate_create_maintainer_claim(
+                                EntryCreationAction::Update(action.clone()),
+                                maintainer_claim.clone(),
+                            )?;
+                            if let ValidateCallbackResult::Valid = result {
+                                let original_maintainer_claim: Option<MaintainerClaim> =
+                                    original_record
+                                        .entry()
+                                        .to_app_option()
+                                        .map_err(|e| wasm_error!(e))?;
+                                let original_maintainer_claim = match original_maintainer_claim {
+                                    Some(maintainer_claim) => maintainer_claim,
+                                    None => {
+                                        return Ok(
+                                            ValidateCallbackResult::Invalid(
+                                                "The updated entry type must be the same as the original entry type"
+                                                    .to_string(),
+                                            ),
+                                        );
+                                    }
+                                };
+                                validate_update_maintainer_claim(
+                                    action,
+                                    maintainer_claim,
+                                    original_action,
+                                    original_maintainer_claim,
+                                )
+                            } else {
+                                Ok(result)
+                            }
+                        }
+                        EntryTypes::OwnershipOffer(ownership_offer) => {
+                            let result = validate_create_ownership_offer(
+                                EntryCreationAction::Update(action.clone()),
+                                ownership_offer.clone(),
+                            )?;
+                            if let ValidateCallbackResult::Valid = result {
+                                let original_ownership_offer: Option<OwnershipOffer> =
+                                    original_record
+                                        .entry()
+                                        .to_app_option()
+                                        .map_err(|e| wasm_error!(e))?;
+                                let original_ownership_offer = match original_ownership_offer {
+                                    Some(ownership_offer) => ownership_offer,
+                                    None => {
+                                        return Ok(
+                                            ValidateCallbackResult::Invalid(
+                                                "The updated entry type must be the same as the original entry type"
+                                                    .to_string(),
+                                            ),
+                                        );
+                                    }
+                                };
+                                validate_update_ownership_offer(
+                                    action,
+                                    ownership_offer,
+                                    original_action,
+                                    original_ownership_offer,
+                                )
+                            } else {
+                                Ok(result)
+                            }
+                        }
+                        EntryTypes::OwnershipTransfer(ownership_transfer) => {
+                            let result = validate_create_ownership_transfer(
+                                EntryCreationAction::Update(action.clone()),
+                                ownership_transfer.clone(),
+                            )?;
+                            if let ValidateCallbackResult::Valid = result {
+                                let original_ownership_transfer: Option<OwnershipTransfer> =
+                                    original_record
+                                        .entry()
+                                        .to_app_option()
+                                        .map_err(|e| wasm_error!(e))?;
+                                let original_ownership_transfer = match original_ownership_transfer
+                                {
+                                    Some(ownership_transfer) => ownership_transfer,
+                                    None => {
+                                        return Ok(
+                                            ValidateCallbackResult::Invalid(
+                                                "The updated entry type must be the same as the original entry type"
+                                                    .to_string(),
+                                            ),
+                                        );
+                                    }
+                                };
+                                validate_update_ownership_transfer(
+                                    action,
+                                    ownership_transfer,
+                                    original_action,
+                                    original_ownership_transfer,
+                                )
+                            } else {
+                                Ok(result)
+                            }
+                        }
+                        EntryTypes::Publisher(publisher) => {
+                            let result = validate_create_publisher(
+                                EntryCreationAction::Update(action.clone()),
+                                publisher.clone(),
+                            )?;
+                            if let ValidateCallbackResult::Valid = result {
+                                let original_publisher: Option<Publisher> = original_record
+                                    .entry()
+                                    .to_app_option()
+                                    .map_err(|e| wasm_error!(e))?;
+                                let original_publisher = match original_publisher {
+                                    Some(publisher) => publisher,
+                                    None => {
+                                        return Ok(
+                                            ValidateCallbackResult::Invalid(
+                                                "The updated entry type must be the same as the original entry type"
+                                                    .to_string(),
+                                            ),
+                                        );
+                                    }
+                                };
+                                validate_update_publisher(
+                                    action,
+                                    publisher,
+                                    original_action,
+                                    original_publisher,
+                                )
+                            } else {
+                                Ok(result)
+                            }
+                        }
+                        EntryTypes::DomainClaim(domain_claim) => {
+                            let result = validate_create_domain_claim(
+                                EntryCreationAction::Update(action.clone()),
+                                domain_claim.clone(),
+                            )?;
+                            if let ValidateCallbackResult::Valid = result {
+                                let original_domain_claim: Option<DomainClaim> = original_record
+                                    .entry()
+                                    .to_app_option()
+                                    .map_err(|e| wasm_error!(e))?;
+                                let original_domain_claim = match original_domain_claim {
+                                    Some(domain_claim) => domain_claim,
+                                    None => {
+                                        return Ok(
+                                            ValidateCallbackResult::Invalid(
+                                                "The updated entry type must be the same as the original entry type"
+                                                    .to_string(),
+                                            ),
+                                        );
+                                    }
+                                };
+                                validate_update_domain_claim(
+                                    action,
+                                    domain_claim,
+                                    original_action,
+                                    original_domain_claim,
+                                )
+                            } else {
+                                Ok(result)
+                            }
+                        }
+                        EntryTypes::Review(review) => {
+                            let result = validate_create_review(
+                                EntryCreationAction::Update(action.clone()),
+                                review.clone(),
+                            )?;
+                            if let ValidateCallbackResult::Valid = result {
+                                let original_review: Option<Review> = original_record
+                                    .entry()
+                                    .to_app_option()
+                                    .map_err(|e| wasm_error!(e))?;
+                                let original_review = match original_review {
+                                    Some(review) => review,
+                                    None => {
+                                        return Ok(
+                                            ValidateCallbackResult::Invalid(
+                                                "The updated entry type must be the same as the original entry type"
+                                                    .to_string(),
+                                            ),
+                                        );
+                                    }
+                                };
+                                validate_update_review(
+                                    action,
+                                    review,
+                                    original_action,
+                                    original_review,
+                                )
+                            } else {
+                                Ok(result)
+                            }
+                        }
+                        EntryTypes::ReviewReply(review_reply) => {
+                            let result = validate_create_review_reply(
+                                EntryCreationAction::Update(action.clone()),
+                                review_reply.clone(),
+                            )?;
+                            if let ValidateCallbackResult::Valid = result {
+                                let original_review_reply: Option<ReviewReply> = original_record
+                                    .entry()
+                                    .to_app_option()
+                                    .map_err(|e| wasm_error!(e))?;
+                                let original_review_reply = match original_review_reply {
+                                    Some(review_reply) => review_reply,
+                                    None => {
+                                        return Ok(
+                                            ValidateCallbackResult::Invalid(
+                                                "The updated entry type must be the same as the original entry type"
+                                                    .to_string(),
+                                            ),
+                                        );
+                                    }
+                                };
+                                validate_update_review_reply(
+                                    action,
+                                    review_reply,
+                                    original_action,
+                                    original_review_reply,
+                                )
+                            } else {
+                                Ok(result)
+                            }
+                        }
+                        EntryTypes::Report(report) => {
+                            let result = validate_create_report(
+                                EntryCreationAction::Update(action.clone()),
+                                report.clone(),
+                            )?;
+                            if let ValidateCallbackResult::Valid = result {
+                                let original_report: Option<Report> = original_record
+                                    .entry()
+                                    .to_app_option()
+                                    .map_err(|e| wasm_error!(e))?;
+                                let original_report = match original_report {
+                                    Some(report) => report,
+                                    None => {
+                                        return Ok(
+                                            ValidateCallbackResult::Invalid(
+                                                "The updated entry type must be the same as the original entry type"
+                                                    .to_string(),
+                                            ),
+                                        );
+                                    }
+                                };
+                                validate_update_report(
+                                    action,
+                                    report,
+                                    original_action,
+                                    original_report,
+                                )
+                            } else {
+                                Ok(result)
+                            }
+                        }
+                        EntryTypes::ReportResolution(report_resolution) => {
+                            let result = validate_create_report_resolution(
+                                EntryCreationAction::Update(action.clone()),
+                                report_resolution.clone(),
+                            )?;
+                            if let ValidateCallbackResult::Valid = result {
+                                let original_report_resolution: Option<ReportResolution> =
+                                    original_record
+                                        .entry()
+                                        .to_app_option()
+                                        .map_err(|e| wasm_error!(e))?;
+                                let original_report_resolution = match original_report_resolution {
+                                    Some(report_resolution) => report_resolution,
+                                    None => {
+                                        return Ok(
+                                            ValidateCallbackResult::Invalid(
+                                                "The updated entry type must be the same as the original entry type"
+                                                    .to_string(),
+                                            ),
+                                        );
+                                    }
+                                };
+                                validate_update_report_resolution(
+                                    action,
+                                    report_resolution,
+                                    original_action,
+                                    original_report_resolution,
+                                )
+                            } else {
+                                Ok(result)
+                            }
+                        }
+                        EntryTypes::ModeratorClaim(moderator_claim) => {
+                            let result = validate_create_moderator_claim(
+                                EntryCreationAction::Update(action.clone()),
+                                moderator_claim.clone(),
+                            )?;
+                            if let ValidateCallbackResult::Valid = result {
+                                let original_moderator_claim: Option<ModeratorClaim> =
+                                    original_record
+                                        .entry()
+                                        .to_app_option()
+                                        .map_err(|e| wasm_error!(e))?;
+                                let original_moderator_claim = match original_moderator_claim {
+                                    Some(moderator_claim) => moderator_claim,
+                                    None => {
+                                        return Ok(
+                                            ValidateCallbackResult::Invalid(
+                                                "The updated entry type must be the same as the original entry type"
+                                                    .to_string(),
+                                            ),
+                                        );
+                                    }
+                                };
+                                validate_update_moderator_claim(
+                                    action,
+                                    moderator_claim,
+                                    original_action,
+                                    original_moderator_claim,
+                                )
+                            } else {
+                                Ok(result)
+                            }
+                        }
+                        EntryTypes::PublisherCredential(publisher_credential) => {
+                            let result = validate_create_publisher_credential(
+                                EntryCreationAction::Update(action.clone()),
+                                publisher_credential.clone(),
+                            )?;
+                            if let ValidateCallbackResult::Valid = result {
+                                let original_publisher_credential: Option<PublisherCredential> =
+                                    original_record
+                                        .entry()
+                                        .to_app_option()
+                                        .map_err(|e| wasm_error!(e))?;
+                                let original_publisher_credential =
+                                    match original_publisher_credential {
+                                        Some(publisher_credential) => publisher_credential,
+                                        None => {
+                                            return Ok(
+                                            ValidateCallbackResult::Invalid(
+                                                "The updated entry type must be the same as the original entry type"
+                                                    .to_string(),
+                                            ),
+                                        );
+                                        }
+                                    };
+                                validate_update_publisher_credential(
+                                    action,
+                                    publisher_credential,
+                                    original_action,
+                                    original_publisher_credential,
+                                )
+                            } else {
+                                Ok(result)
+                            }
+                        }
+                        EntryTypes::OwnershipOfferCancellation(ownership_offer_cancellation) => {
+                            let result = validate_create_ownership_offer_cancellation(
+                                EntryCreationAction::Update(action.clone()),
+                                ownership_offer_cancellation.clone(),
                             )?;
                             if let ValidateCallbackResult::Valid = result {
-                                let original_happ_release: Option<HappRelease> = original_record
+                                let original_ownership_offer_cancellation: Option<
+                                    OwnershipOfferCancellation,
+                                > = original_record
                                     .entry()
                                     .to_app_option()
                                     .map_err(|e| wasm_error!(e))?;
-                                let original_happ_release = match original_happ_release {
-                                    Some(happ_release) => happ_release,
-                                    None => {
-                                        return Ok(
+                                let original_ownership_offer_cancellation =
+                                    match original_ownership_offer_cancellation {
+                                        Some(ownership_offer_cancellation) => {
+                                            ownership_offer_cancellation
+                                        }
+                                        None => {
+                                            return Ok(
                                             ValidateCallbackResult::Invalid(
                                                 "The updated entry type must be the same as the original entry type"
                                                     .to_string(),
                                             ),
                                         );
-                                    }
-                                };
-                                validate_update_happ_release(
+                                        }
+                                    };
+                                validate_update_ownership_offer_cancellation(
                                     action,
-                                    happ_release,
+                                    ownership_offer_cancellation,
                                     original_action,
-                                    original_happ_release,
+                                    original_ownership_offer_cancellation,
                                 )
                             } else {
                                 Ok(result)
@@ -421,6 +1626,85 @@ pub fn validate(op: Op) -> ExternResult<ValidateCallbackResult> {
                                 original_happ_release,
                             )
                         }
+                        EntryTypes::HappReleaseYank(original_happ_release_yank) => {
+                            validate_delete_happ_release_yank(
+                                action,
+                                original_action,
+                                original_happ_release_yank,
+                            )
+                        }
+                        EntryTypes::MaintainerClaim(original_maintainer_claim) => {
+                            validate_delete_maintainer_claim(
+                                action,
+                                original_action,
+                                original_maintainer_claim,
+                            )
+                        }
+                        EntryTypes::OwnershipOffer(original_ownership_offer) => {
+                            validate_delete_ownership_offer(
+                                action,
+                                original_action,
+                                original_ownership_offer,
+                            )
+                        }
+                        EntryTypes::OwnershipTransfer(original_ownership_transfer) => {
+                            validate_delete_ownership_transfer(
+                                action,
+                                original_action,
+                                original_ownership_transfer,
+                            )
+                        }
+                        EntryTypes::Publisher(original_publisher) => {
+                            validate_delete_publisher(action, original_action, original_publisher)
+                        }
+                        EntryTypes::DomainClaim(original_domain_claim) => {
+                            validate_delete_domain_claim(
+                                action,
+                                original_action,
+                                original_domain_claim,
+                            )
+                        }
+                        EntryTypes::Review(original_review) => {
+                            validate_delete_review(action, original_action, original_review)
+                        }
+                        EntryTypes::ReviewReply(original_review_reply) => {
+                            validate_delete_review_reply(
+                                action,
+                                original_action,
+                                original_review_reply,
+                            )
+                        }
+                        EntryTypes::Report(original_report) => {
+                            validate_delete_report(action, original_action, original_report)
+                        }
+                        EntryTypes::ReportResolution(original_report_resolution) => {
+                            validate_delete_report_resolution(
+                                action,
+                                original_action,
+                                original_report_resolution,
+                            )
+                        }
+                        EntryTypes::ModeratorClaim(original_moderator_claim) => {
+                            validate_delete_moderator_claim(
+                                action,
+                                original_action,
+                                original_moderator_claim,
+                            )
+                        }
+                        EntryTypes::PublisherCredential(original_publisher_credential) => {
+                            validate_delete_publisher_credential(
+                                action,
+                                original_action,
+                                original_publisher_credential,
+                            )
+                        }
+                        EntryTypes::OwnershipOfferCancellation(
+                            original_ownership_offer_cancellation,
+                        ) => validate_delete_ownership_offer_cancellation(
+                            action,
+                            original_action,
+                            original_ownership_offer_cancellation,
+                        ),
                     }
                 }
                 // Complementary validation to the `RegisterCreateLink` Op, in which the record itself is validated
@@ -463,6 +1747,119 @@ pub fn validate(op: Op) -> ExternResult<ValidateCallbackResult> {
                         target_address,
                         tag,
                     ),
+                    LinkTypes::HappReleasesByChannel => {
+                        validate_create_link_happ_releases_by_channel(
+                            action,
+                            base_address,
+                            target_address,
+                            tag,
+                        )
+                    }
+                    LinkTypes::HappReleaseYanks => validate_create_link_happ_release_yanks(
+                        action,
+                        base_address,
+                        target_address,
+                        tag,
+                    ),
+                    LinkTypes::HappMaintainers => validate_create_link_happ_maintainers(
+                        action,
+                        base_address,
+                        target_address,
+                        tag,
+                    ),
+                    LinkTypes::HappOwnershipOffers => validate_create_link_happ_ownership_offers(
+                        action,
+                        base_address,
+                        target_address,
+                        tag,
+                    ),
+                    LinkTypes::HappOwnershipTransfers => {
+                        validate_create_link_happ_ownership_transfers(
+                            action,
+                            base_address,
+                            target_address,
+                            tag,
+                        )
+                    }
+                    LinkTypes::AgentToPublisher => validate_create_link_agent_to_publisher(
+                        action,
+                        base_address,
+                        target_address,
+                        tag,
+                    ),
+                    LinkTypes::PublisherUpdates => validate_create_link_publisher_updates(
+                        action,
+                        base_address,
+                        target_address,
+                        tag,
+                    ),
+                    LinkTypes::PublisherDomainClaims => {
+                        validate_create_link_publisher_domain_claims(
+                            action,
+                            base_address,
+                            target_address,
+                            tag,
+                        )
+                    }
+                    LinkTypes::CategoryToHapps => validate_create_link_category_to_happs(
+                        action,
+                        base_address,
+                        target_address,
+                        tag,
+                    ),
+                    LinkTypes::TagToHapps => {
+                        validate_create_link_tag_to_happs(action, base_address, target_address, tag)
+                    }
+                    LinkTypes::SearchIndex => {
+                        validate_create_link_search_index(action, base_address, target_address, tag)
+                    }
+                    LinkTypes::ReviewReplies => validate_create_link_review_replies(
+                        action,
+                        base_address,
+                        target_address,
+                        tag,
+                    ),
+                    LinkTypes::ReportResolutions => validate_create_link_report_resolutions(
+                        action,
+                        base_address,
+                        target_address,
+                        tag,
+                    ),
+                    LinkTypes::HiddenHapps => {
+                        validate_create_link_hidden_happs(action, base_address, target_address, tag)
+                    }
+                    LinkTypes::HappsSortIndex => validate_create_link_happs_sort_index(
+                        action,
+                        base_address,
+                        target_address,
+                        tag,
+                    ),
+                    LinkTypes::HappsSortIndexBuckets => {
+                        validate_create_link_happs_sort_index_buckets(
+                            action,
+                            base_address,
+                            target_address,
+                            tag,
+                        )
+                    }
+                    LinkTypes::Moderators => {
+                        validate_create_link_moderators(action, base_address, target_address, tag)
+                    }
+                    LinkTypes::OpenReports => {
+                        validate_create_link_open_reports(action, base_address, target_address, tag)
+                    }
+                    LinkTypes::HappReports => {
+                        validate_create_link_happ_reports(action, base_address, target_address, tag)
+                    }
+                    LinkTypes::ReviewUpdates => validate_create_link_review_updates(
+                        action,
+                        base_address,
+                        target_address,
+                        tag,
+                    ),
+                    LinkTypes::HappReviews => {
+                        validate_create_link_happ_reviews(action, base_address, target_address, tag)
+                    }
                 },
                 // Complementary validation to the `RegisterDeleteLink` Op, in which the record itself is validated
                 // If you want to optimize performance, you can remove the validation for an entry type here and keep it in `RegisterDeleteLink`
@@ -536,6 +1933,163 @@ pub fn validate(op: Op) -> ExternResult<ValidateCallbackResult> {
                             create_link.target_address,
                             create_link.tag,
                         ),
+                        LinkTypes::HappReleasesByChannel => {
+                            validate_delete_link_happ_releases_by_channel(
+                                action,
+                                create_link.clone(),
+                                base_address,
+                                create_link.target_address,
+                                create_link.tag,
+                            )
+                        }
+                        LinkTypes::HappReleaseYanks => validate_delete_link_happ_release_yanks(
+                            action,
+                            create_link.clone(),
+                            base_address,
+                            create_link.target_address,
+                            create_link.tag,
+                        ),
+                        LinkTypes::HappMaintainers => validate_delete_link_happ_maintainers(
+                            action,
+                            create_link.clone(),
+                            base_address,
+                            create_link.target_address,
+                            create_link.tag,
+                        ),
+                        LinkTypes::HappOwnershipOffers => {
+                            validate_delete_link_happ_ownership_offers(
+                                action,
+                                create_link.clone(),
+                                base_address,
+                                create_link.target_address,
+                                create_link.tag,
+                            )
+                        }
+                        LinkTypes::HappOwnershipTransfers => {
+                            validate_delete_link_happ_ownership_transfers(
+                                action,
+                                create_link.clone(),
+                                base_address,
+                                create_link.target_address,
+                                create_link.tag,
+                            )
+                        }
+                        LinkTypes::AgentToPublisher => validate_delete_link_agent_to_publisher(
+                            action,
+                            create_link.clone(),
+                            base_address,
+                            create_link.target_address,
+                            create_link.tag,
+                        ),
+                        LinkTypes::PublisherUpdates => validate_delete_link_publisher_updates(
+                            action,
+                            create_link.clone(),
+                            base_address,
+                            create_link.target_address,
+                            create_link.tag,
+                        ),
+                        LinkTypes::PublisherDomainClaims => {
+                            validate_delete_link_publisher_domain_claims(
+                                action,
+                                create_link.clone(),
+                                base_address,
+                                create_link.target_address,
+                                create_link.tag,
+                            )
+                        }
+                        LinkTypes::CategoryToHapps => validate_delete_link_category_to_happs(
+                            action,
+                            create_link.clone(),
+                            base_address,
+                            create_link.target_address,
+                            create_link.tag,
+                        ),
+                        LinkTypes::TagToHapps => validate_delete_link_tag_to_happs(
+                            action,
+                            create_link.clone(),
+                            base_address,
+                            create_link.target_address,
+                            create_link.tag,
+                        ),
+                        LinkTypes::SearchIndex => validate_delete_link_search_index(
+                            action,
+                            create_link.clone(),
+                            base_address,
+                            create_link.target_address,
+                            create_link.tag,
+                        ),
+                        LinkTypes::ReviewReplies => validate_delete_link_review_replies(
+                            action,
+                            create_link.clone(),
+                            base_address,
+                            create_link.target_address,
+                            create_link.tag,
+                        ),
+                        LinkTypes::ReportResolutions => validate_delete_link_report_resolutions(
+                            action,
+                            create_link.clone(),
+                            base_address,
+                            create_link.target_address,
+                            create_link.tag,
+                        ),
+                        LinkTypes::HiddenHapps => validate_delete_link_hidden_happs(
+                            action,
+                            create_link.clone(),
+                            base_address,
+                            create_link.target_address,
+                            create_link.tag,
+                        ),
+                        LinkTypes::HappsSortIndex => validate_delete_link_happs_sort_index(
+                            action,
+                            create_link.clone(),
+                            base_address,
+                            create_link.target_address,
+                            create_link.tag,
+                        ),
+                        LinkTypes::HappsSortIndexBuckets => {
+                            validate_delete_link_happs_sort_index_buckets(
+                                action,
+                                create_link.clone(),
+                                base_address,
+                                create_link.target_address,
+                                create_link.tag,
+                            )
+                        }
+                        LinkTypes::Moderators => validate_delete_link_moderators(
+                            action,
+                            create_link.clone(),
+                            base_address,
+                            create_link.target_address,
+                            create_link.tag,
+                        ),
+                        LinkTypes::OpenReports => validate_delete_link_open_reports(
+                            action,
+                            create_link.clone(),
+                            base_address,
+                            create_link.target_address,
+                            create_link.tag,
+                        ),
+                        LinkTypes::HappReports => validate_delete_link_happ_reports(
+                            action,
+                            create_link.clone(),
+                            base_address,
+                            create_link.target_address,
+                            create_link.tag,
+                        ),
+                        LinkTypes::ReviewUpdates => validate_delete_link_review_updates(
+                            action,
+                            create_link.clone(),
+                            base_address,
+                            create_link.target_address,
+                            create_link.tag,
+                        ),
+                        LinkTypes::HappReviews => validate_delete_link_happ_reviews(
+                            action,
+                            create_link.clone(),
+                            base_address,
+                            create_link.target_address,
+                            create_link.tag,
+                        ),
                     }
                 }
                 OpRecord::CreatePrivateEntry { .. } => Ok(ValidateCallbackResult::Valid),
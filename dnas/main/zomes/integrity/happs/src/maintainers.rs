@@ -0,0 +1,245 @@
+use hdi::prelude::*;
+
+use crate::{
+    chain_activity::{is_claim_expired, ChainActivity},
+    is_happ_owner, LinkTypes, UnitEntryTypes,
+};
+
+/// Committed by a maintainer in their own source chain to accept the HappMaintainers link that the owner created for them
+///
+/// The claim points to an action in the source chain of the owner that added the maintainer, so that validators can
+/// check deterministically that the owner hadn't removed the maintainer by then. A claim only grants the rights of a
+/// maintainer until `claim_validity_secs` after that action, see `is_claim_expired`, so the coordinator zome of the
+/// maintainer commits a new claim whenever the chain of the owner has moved. Every claim must point to a later action
+/// of that chain than the previous one, so a removed maintainer loses their rights at most `claim_validity_secs` after
+/// their removal, even if they never commit a new claim
+#[derive(Clone, PartialEq)]
+#[hdk_entry_helper]
+pub struct MaintainerClaim {
+    pub happ_hash: ActionHash,
+    pub maintainer_link_hash: ActionHash,
+    pub owner_chain_top: ActionHash,
+}
+
+/// Returns whether the given agent was a maintainer of the hApp when they committed the action after `chain_top`,
+/// at the time `at` of that action
+///
+/// The owner of a hApp is always one of its maintainers. Other agents are maintainers if their latest claim for
+/// the hApp is not deleted nor expired, and its HappMaintainers link was not deleted up to the `owner_chain_top`
+/// of the claim
+pub fn is_happ_maintainer(
+    agent: &AgentPubKey,
+    chain_top: &ActionHash,
+    at: &Timestamp,
+    happ_hash: &ActionHash,
+) -> ExternResult<bool> {
+    let happ_hash = original_action_hash(happ_hash.clone())?;
+    if is_happ_owner(agent, chain_top, &happ_hash)? {
+        return Ok(true);
+    }
+
+    let latest_claim = ChainActivity::get(agent, chain_top)?
+        .entries::<MaintainerClaim>(UnitEntryTypes::MaintainerClaim)?
+        .into_iter()
+        .rfind(|claim| claim.entry.happ_hash.eq(&happ_hash));
+    let Some(latest_claim) = latest_claim else {
+        return Ok(false);
+    };
+    if latest_claim.deleted || is_claim_expired(&latest_claim.entry.owner_chain_top, at)? {
+        return Ok(false);
+    }
+    Ok(check_maintainer_grant(&latest_claim.entry)?.is_none())
+}
+
+/// Checks that the HappMaintainers link of the claim is still in place at the `owner_chain_top` of the claim,
+/// and that the agent that created it still owned the hApp by then
+///
+/// Returns the reason why the claim is not valid anymore, if it isn't
+fn check_maintainer_grant(claim: &MaintainerClaim) -> ExternResult<Option<String>> {
+    let grant_action = must_get_action(claim.maintainer_link_hash.clone())?;
+    let owner = grant_action.action().author();
+    let owner_activity = ChainActivity::get(owner, &claim.owner_chain_top)?;
+    if !owner_activity.contains(&claim.maintainer_link_hash) {
+        return Ok(Some(
+            "The owner chain top of a maintainer claim must come after its HappMaintainers link"
+                .to_string(),
+        ));
+    }
+    if owner_activity.is_link_deleted(&claim.maintainer_link_hash) {
+        return Ok(Some(
+            "The owner removed the maintainer before the owner chain top of the claim".to_string(),
+        ));
+    }
+    if !is_happ_owner(owner, &claim.owner_chain_top, &claim.happ_hash)? {
+        return Ok(Some(
+            "The agent that added the maintainer didn't own the hApp anymore at the owner chain top of the claim"
+                .to_string(),
+        ));
+    }
+    Ok(None)
+}
+
+/// Walks back the updates of a record until its original action
+pub fn original_action_hash(action_hash: ActionHash) -> ExternResult<ActionHash> {
+    let mut action_hash = action_hash;
+    loop {
+        let action = must_get_action(action_hash.clone())?;
+        match action.action() {
+            Action::Update(update) => action_hash = update.original_action_address.clone(),
+            _ => return Ok(action_hash),
+        }
+    }
+}
+
+pub fn validate_create_maintainer_claim(
+    action: EntryCreationAction,
+    maintainer_claim: MaintainerClaim,
+) -> ExternResult<ValidateCallbackResult> {
+    let record = must_get_valid_record(maintainer_claim.maintainer_link_hash.clone())?;
+    let Action::CreateLink(create_link) = record.action() else {
+        return Ok(ValidateCallbackResult::Invalid(
+            "A maintainer claim must reference a HappMaintainers link".to_string(),
+        ));
+    };
+    let link_type = LinkTypes::from_type(create_link.zome_index, create_link.link_type)?;
+    if !matches!(link_type, Some(LinkTypes::HappMaintainers)) {
+        return Ok(ValidateCallbackResult::Invalid(
+            "A maintainer claim must reference a HappMaintainers link".to_string(),
+        ));
+    }
+    if create_link
+        .base_address
+        .clone()
+        .into_action_hash()
+        .as_ref()
+        .ne(&Some(&maintainer_claim.happ_hash))
+    {
+        return Ok(ValidateCallbackResult::Invalid(
+            "The HappMaintainers link of a maintainer claim must be for the claimed hApp"
+                .to_string(),
+        ));
+    }
+    if create_link
+        .target_address
+        .clone()
+        .into_agent_pub_key()
+        .as_ref()
+        .ne(&Some(action.author()))
+    {
+        return Ok(ValidateCallbackResult::Invalid(
+            "Only the maintainer that the HappMaintainers link points to can claim it".to_string(),
+        ));
+    }
+    if let Some(error) = check_maintainer_grant(&maintainer_claim)? {
+        return Ok(ValidateCallbackResult::Invalid(error));
+    }
+    if is_claim_expired(&maintainer_claim.owner_chain_top, action.timestamp())? {
+        return Ok(ValidateCallbackResult::Invalid(
+            "The owner chain top of a maintainer claim is too old to grant the rights of a maintainer"
+                .to_string(),
+        ));
+    }
+
+    // A new claim can't go back to an earlier point of the chain of the owner than the previous one, even if it
+    // points to another HappMaintainers link of the same owner
+    let previous_claim = ChainActivity::get(action.author(), action.prev_action())?
+        .entries::<MaintainerClaim>(UnitEntryTypes::MaintainerClaim)?
+        .into_iter()
+        .rfind(|claim| claim.entry.happ_hash.eq(&maintainer_claim.happ_hash));
+    if let Some(previous_claim) = previous_claim {
+        let previous_grant = must_get_action(previous_claim.entry.maintainer_link_hash.clone())?;
+        let owner_activity =
+            ChainActivity::get(&create_link.author, &maintainer_claim.owner_chain_top)?;
+        if previous_grant.action().author().eq(&create_link.author)
+            && !owner_activity.contains(&previous_claim.entry.owner_chain_top)
+        {
+            return Ok(ValidateCallbackResult::Invalid(
+                "The owner chain top of a maintainer claim must come after the one of the previous claim"
+                    .to_string(),
+            ));
+        }
+    }
+    Ok(ValidateCallbackResult::Valid)
+}
+
+pub fn validate_update_maintainer_claim(
+    _action: Update,
+    _maintainer_claim: MaintainerClaim,
+    _original_action: EntryCreationAction,
+    _original_maintainer_claim: MaintainerClaim,
+) -> ExternResult<ValidateCallbackResult> {
+    Ok(ValidateCallbackResult::Invalid(
+        "Maintainer claims cannot be updated".to_string(),
+    ))
+}
+
+pub fn validate_delete_maintainer_claim(
+    action: Delete,
+    original_action: EntryCreationAction,
+    _original_maintainer_claim: MaintainerClaim,
+) -> ExternResult<ValidateCallbackResult> {
+    if action.author.ne(original_action.author()) {
+        return Ok(ValidateCallbackResult::Invalid(
+            "Only the maintainer can delete their own claim".to_string(),
+        ));
+    }
+    Ok(ValidateCallbackResult::Valid)
+}
+
+pub fn validate_create_link_happ_maintainers(
+    action: CreateLink,
+    base_address: AnyLinkableHash,
+    target_address: AnyLinkableHash,
+    _tag: LinkTag,
+) -> ExternResult<ValidateCallbackResult> {
+    let action_hash = base_address
+        .into_action_hash()
+        .ok_or(wasm_error!(WasmErrorInner::Guest(
+            "No action hash associated with link".to_string()
+        )))?;
+    let happ_record = must_get_valid_record(action_hash.clone())?;
+    let _happ: crate::Happ = happ_record
+        .entry()
+        .to_app_option()
+        .map_err(|e| wasm_error!(e))?
+        .ok_or(wasm_error!(WasmErrorInner::Guest(
+            "Linked action must reference an entry".to_string()
+        )))?;
+    if target_address.into_agent_pub_key().is_none() {
+        return Ok(ValidateCallbackResult::Invalid(
+            "HappMaintainers links must point to the public key of the maintainer".to_string(),
+        ));
+    }
+    if !is_happ_owner(&action.author, &action.prev_action, &action_hash)? {
+        return Ok(ValidateCallbackResult::Invalid(
+            "Only the owner of a hApp can add maintainers to it".to_string(),
+        ));
+    }
+    Ok(ValidateCallbackResult::Valid)
+}
+
+pub fn validate_delete_link_happ_maintainers(
+    action: DeleteLink,
+    original_action: CreateLink,
+    base: AnyLinkableHash,
+    _target: AnyLinkableHash,
+    _tag: LinkTag,
+) -> ExternResult<ValidateCallbackResult> {
+    let action_hash = base
+        .into_action_hash()
+        .ok_or(wasm_error!(WasmErrorInner::Guest(
+            "No action hash associated with link".to_string()
+        )))?;
+    // Only the chain of the owner that added the maintainer is checked when validating their claims
+    if action.author.ne(&original_action.author) {
+        return Ok(ValidateCallbackResult::Invalid(
+            "Only the owner that added a maintainer can remove them".to_string(),
+        ));
+    }
+    if !is_happ_owner(&action.author, &action.prev_action, &action_hash)? {
+        return Ok(ValidateCallbackResult::Invalid(
+            "Only the owner of a hApp can remove maintainers from it".to_string(),
+        ));
+    }
+    Ok(ValidateCallbackResult::Valid)
+}
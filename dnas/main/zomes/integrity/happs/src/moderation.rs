@@ -0,0 +1,253 @@
+use hdi::prelude::*;
+
+use crate::{
+    chain_activity::{is_claim_expired, ChainActivity},
+    happs_properties, LinkTypes, UnitEntryTypes,
+};
+
+/// Committed by a moderator in their own source chain to accept the Moderators link that the progenitor created for them
+///
+/// Like maintainer claims, moderator claims point to an action in the source chain of the progenitor, up to which
+/// the progenitor hadn't removed the moderator, and expire `claim_validity_secs` after that action. Every claim must
+/// point to a later action than the previous one
+#[derive(Clone, PartialEq)]
+#[hdk_entry_helper]
+pub struct ModeratorClaim {
+    pub moderator_link_hash: ActionHash,
+    pub progenitor_chain_top: ActionHash,
+}
+
+/// In bytes, as the reason is stored in the tag of the HiddenHapps link, and link tags can't exceed 1KB
+pub const MAX_MODERATION_REASON_LENGTH: usize = 500;
+
+/// The path that links to the moderators appointed by the progenitor
+pub fn moderators_path() -> Path {
+    Path::from("moderators")
+}
+
+/// Returns whether the given agent was a moderator when they committed the action after `chain_top`, at the time
+/// `at` of that action
+///
+/// The progenitor and the initial moderators set in the DNA properties are always moderators
+pub fn is_moderator(
+    agent: &AgentPubKey,
+    chain_top: &ActionHash,
+    at: &Timestamp,
+) -> ExternResult<bool> {
+    let properties = happs_properties()?;
+    if properties.is_progenitor(agent) || properties.initial_moderators().contains(agent) {
+        return Ok(true);
+    }
+    let latest_claim = ChainActivity::get(agent, chain_top)?
+        .entries::<ModeratorClaim>(UnitEntryTypes::ModeratorClaim)?
+        .pop();
+    let Some(latest_claim) = latest_claim else {
+        return Ok(false);
+    };
+    if latest_claim.deleted || is_claim_expired(&latest_claim.entry.progenitor_chain_top, at)? {
+        return Ok(false);
+    }
+    Ok(check_moderator_appointment(&latest_claim.entry)?.is_none())
+}
+
+/// Checks that the Moderators link of the claim is still in place at the `progenitor_chain_top` of the claim
+///
+/// Returns the reason why the claim is not valid anymore, if it isn't
+fn check_moderator_appointment(claim: &ModeratorClaim) -> ExternResult<Option<String>> {
+    let Some(progenitor) = happs_properties()?.progenitor() else {
+        return Ok(Some(
+            "Only networks with a progenitor can have appointed moderators".to_string(),
+        ));
+    };
+    let progenitor_activity = ChainActivity::get(&progenitor, &claim.progenitor_chain_top)?;
+    if !progenitor_activity.contains(&claim.moderator_link_hash) {
+        return Ok(Some(
+            "The progenitor chain top of a moderator claim must come after its Moderators link"
+                .to_string(),
+        ));
+    }
+    if progenitor_activity.is_link_deleted(&claim.moderator_link_hash) {
+        return Ok(Some(
+            "The progenitor removed the moderator before the progenitor chain top of the claim"
+                .to_string(),
+        ));
+    }
+    Ok(None)
+}
+
+pub fn validate_create_moderator_claim(
+    action: EntryCreationAction,
+    moderator_claim: ModeratorClaim,
+) -> ExternResult<ValidateCallbackResult> {
+    let record = must_get_valid_record(moderator_claim.moderator_link_hash.clone())?;
+    let Action::CreateLink(create_link) = record.action() else {
+        return Ok(ValidateCallbackResult::Invalid(
+            "A moderator claim must reference a Moderators link".to_string(),
+        ));
+    };
+    let link_type = LinkTypes::from_type(create_link.zome_index, create_link.link_type)?;
+    if !matches!(link_type, Some(LinkTypes::Moderators)) {
+        return Ok(ValidateCallbackResult::Invalid(
+            "A moderator claim must reference a Moderators link".to_string(),
+        ));
+    }
+    if create_link
+        .target_address
+        .clone()
+        .into_agent_pub_key()
+        .as_ref()
+        .ne(&Some(action.author()))
+    {
+        return Ok(ValidateCallbackResult::Invalid(
+            "Only the moderator that the Moderators link points to can claim it".to_string(),
+        ));
+    }
+    if let Some(error) = check_moderator_appointment(&moderator_claim)? {
+        return Ok(ValidateCallbackResult::Invalid(error));
+    }
+    if is_claim_expired(&moderator_claim.progenitor_chain_top, action.timestamp())? {
+        return Ok(ValidateCallbackResult::Invalid(
+            "The progenitor chain top of a moderator claim is too old to grant the rights of a moderator"
+                .to_string(),
+        ));
+    }
+
+    // A new claim can't go back to an earlier point of the chain of the progenitor than the previous one
+    let previous_claim = ChainActivity::get(action.author(), action.prev_action())?
+        .entries::<ModeratorClaim>(UnitEntryTypes::ModeratorClaim)?
+        .pop();
+    if let Some(previous_claim) = previous_claim {
+        let progenitor_activity =
+            ChainActivity::get(&create_link.author, &moderator_claim.progenitor_chain_top)?;
+        if !progenitor_activity.contains(&previous_claim.entry.progenitor_chain_top) {
+            return Ok(ValidateCallbackResult::Invalid(
+                "The progenitor chain top of a moderator claim must come after the one of the previous claim"
+                    .to_string(),
+            ));
+        }
+    }
+    Ok(ValidateCallbackResult::Valid)
+}
+
+pub fn validate_update_moderator_claim(
+    _action: Update,
+    _moderator_claim: ModeratorClaim,
+    _original_action: EntryCreationAction,
+    _original_moderator_claim: ModeratorClaim,
+) -> ExternResult<ValidateCallbackResult> {
+    Ok(ValidateCallbackResult::Invalid(
+        "Moderator claims cannot be updated".to_string(),
+    ))
+}
+
+pub fn validate_delete_moderator_claim(
+    action: Delete,
+    original_action: EntryCreationAction,
+    _original_moderator_claim: ModeratorClaim,
+) -> ExternResult<ValidateCallbackResult> {
+    if action.author.ne(original_action.author()) {
+        return Ok(ValidateCallbackResult::Invalid(
+            "Only the moderator can delete their own claim".to_string(),
+        ));
+    }
+    Ok(ValidateCallbackResult::Valid)
+}
+
+pub fn validate_create_link_moderators(
+    action: CreateLink,
+    base_address: AnyLinkableHash,
+    target_address: AnyLinkableHash,
+    _tag: LinkTag,
+) -> ExternResult<ValidateCallbackResult> {
+    if base_address.ne(&moderators_path().path_entry_hash()?.into()) {
+        return Ok(ValidateCallbackResult::Invalid(
+            "Moderators links must be created from the moderators path".to_string(),
+        ));
+    }
+    if target_address.into_agent_pub_key().is_none() {
+        return Ok(ValidateCallbackResult::Invalid(
+            "Moderators links must point to the public key of the moderator".to_string(),
+        ));
+    }
+    if !happs_properties()?.is_progenitor(&action.author) {
+        return Ok(ValidateCallbackResult::Invalid(
+            "Only the progenitor can add moderators".to_string(),
+        ));
+    }
+    Ok(ValidateCallbackResult::Valid)
+}
+
+pub fn validate_delete_link_moderators(
+    action: DeleteLink,
+    _original_action: CreateLink,
+    _base: AnyLinkableHash,
+    _target: AnyLinkableHash,
+    _tag: LinkTag,
+) -> ExternResult<ValidateCallbackResult> {
+    if !happs_properties()?.is_progenitor(&action.author) {
+        return Ok(ValidateCallbackResult::Invalid(
+            "Only the progenitor can remove moderators".to_string(),
+        ));
+    }
+    Ok(ValidateCallbackResult::Valid)
+}
+
+pub fn validate_create_link_hidden_happs(
+    action: CreateLink,
+    base_address: AnyLinkableHash,
+    target_address: AnyLinkableHash,
+    tag: LinkTag,
+) -> ExternResult<ValidateCallbackResult> {
+    let action_hash =
+        base_address
+            .clone()
+            .into_action_hash()
+            .ok_or(wasm_error!(WasmErrorInner::Guest(
+                "No action hash associated with link".to_string()
+            )))?;
+    let happ_record = must_get_valid_record(action_hash)?;
+    let _happ: crate::Happ = happ_record
+        .entry()
+        .to_app_option()
+        .map_err(|e| wasm_error!(e))?
+        .ok_or(wasm_error!(WasmErrorInner::Guest(
+            "Linked action must reference an entry".to_string()
+        )))?;
+    if base_address.ne(&target_address) {
+        return Ok(ValidateCallbackResult::Invalid(
+            "HiddenHapps links must point from the hidden hApp to itself".to_string(),
+        ));
+    }
+    // The tag holds the reason the moderator gave for hiding the hApp
+    let Ok(reason) = String::from_utf8(tag.into_inner()) else {
+        return Ok(ValidateCallbackResult::Invalid(
+            "The tag of a HiddenHapps link must be the reason for hiding the hApp".to_string(),
+        ));
+    };
+    if reason.len() > MAX_MODERATION_REASON_LENGTH {
+        return Ok(ValidateCallbackResult::Invalid(format!(
+            "The reason for hiding a hApp can't be longer than {MAX_MODERATION_REASON_LENGTH} bytes."
+        )));
+    }
+    if !is_moderator(&action.author, &action.prev_action, &action.timestamp)? {
+        return Ok(ValidateCallbackResult::Invalid(
+            "Only moderators can hide hApps".to_string(),
+        ));
+    }
+    Ok(ValidateCallbackResult::Valid)
+}
+
+pub fn validate_delete_link_hidden_happs(
+    action: DeleteLink,
+    _original_action: CreateLink,
+    _base: AnyLinkableHash,
+    _target: AnyLinkableHash,
+    _tag: LinkTag,
+) -> ExternResult<ValidateCallbackResult> {
+    if !is_moderator(&action.author, &action.prev_action, &action.timestamp)? {
+        return Ok(ValidateCallbackResult::Invalid(
+            "Only moderators can unhide hApps".to_string(),
+        ));
+    }
+    Ok(ValidateCallbackResult::Valid)
+}
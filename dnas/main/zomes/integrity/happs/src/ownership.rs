@@ -0,0 +1,437 @@
+use hdi::prelude::*;
+
+use crate::{
+    chain_activity::{add_secs, ChainActivity},
+    happs_properties, original_action_hash, UnitEntryTypes,
+};
+
+/// Committed by the owner of a hApp to offer its ownership to another agent
+///
+/// The owner stops being able to act as such while the offer is pending. The recipient can only accept it until
+/// `ownership_offer_validity_secs` after it was made, see `ownership_offer_deadline`, and the owner can only cancel it
+/// after that, so that an accepted offer can never be canceled
+#[derive(Clone, PartialEq)]
+#[hdk_entry_helper]
+pub struct OwnershipOffer {
+    pub happ_hash: ActionHash,
+    pub recipient: AgentPubKey,
+}
+
+/// Committed by the recipient of an ownership offer to accept it, which makes them the owner of the hApp
+///
+/// Points to an action in the source chain of the owner that made the offer, up to which the offer was not canceled
+#[derive(Clone, PartialEq)]
+#[hdk_entry_helper]
+pub struct OwnershipTransfer {
+    pub happ_hash: ActionHash,
+    pub offer_hash: ActionHash,
+    pub offerer_chain_top: ActionHash,
+}
+
+/// Committed by the owner right before deleting an ownership offer to cancel it
+///
+/// Points to an action in the source chain of the recipient from after the deadline of the offer, up to which they had
+/// not accepted it. The timestamps of a source chain only go forward, so a valid acceptance can't come after that action
+#[derive(Clone, PartialEq)]
+#[hdk_entry_helper]
+pub struct OwnershipOfferCancellation {
+    pub offer_hash: ActionHash,
+    pub recipient_chain_top: ActionHash,
+}
+
+/// Returns the time until which the recipient of the offer can accept it, and after which the owner can cancel it
+pub fn ownership_offer_deadline(offer_action: &Action) -> ExternResult<Timestamp> {
+    Ok(add_secs(
+        &offer_action.timestamp(),
+        happs_properties()?.ownership_offer_validity_secs(),
+    ))
+}
+
+/// Returns whether the given agent owned the hApp when they committed the action after `chain_top`
+///
+/// Agents become owners by creating the hApp or by accepting an ownership offer, and stop being owners
+/// when they offer it to someone else. Offers can only be accepted before their deadline, and only canceled after
+/// it if the recipient had not accepted them, so they can't be both accepted and canceled
+pub fn is_happ_owner(
+    agent: &AgentPubKey,
+    chain_top: &ActionHash,
+    happ_hash: &ActionHash,
+) -> ExternResult<bool> {
+    let happ_hash = original_action_hash(happ_hash.clone())?;
+    let happ_action = must_get_action(happ_hash.clone())?;
+
+    let activity = ChainActivity::get(agent, chain_top)?;
+    let transfers = activity
+        .entries::<OwnershipTransfer>(UnitEntryTypes::OwnershipTransfer)?
+        .into_iter()
+        .filter(|transfer| transfer.entry.happ_hash.eq(&happ_hash))
+        .map(|transfer| (transfer.action_seq, true));
+    let pending_offers = activity
+        .entries::<OwnershipOffer>(UnitEntryTypes::OwnershipOffer)?
+        .into_iter()
+        .filter(|offer| !offer.deleted && offer.entry.happ_hash.eq(&happ_hash))
+        .map(|offer| (offer.action_seq, false));
+
+    // The latest transfer or pending offer decides whether the agent is the owner
+    let latest_change = transfers
+        .chain(pending_offers)
+        .max_by_key(|(action_seq, _)| *action_seq);
+    Ok(match latest_change {
+        Some((_, is_owner)) => is_owner,
+        None => agent.eq(happ_action.action().author()),
+    })
+}
+
+pub fn validate_create_ownership_offer(
+    action: EntryCreationAction,
+    ownership_offer: OwnershipOffer,
+) -> ExternResult<ValidateCallbackResult> {
+    let happ_record = must_get_valid_record(ownership_offer.happ_hash.clone())?;
+    let _happ: crate::Happ = happ_record
+        .entry()
+        .to_app_option()
+        .map_err(|e| wasm_error!(e))?
+        .ok_or(wasm_error!(WasmErrorInner::Guest(String::from(
+            "Dependant action must be accompanied by an entry"
+        ))))?;
+    if ownership_offer.recipient.eq(action.author()) {
+        return Ok(ValidateCallbackResult::Invalid(
+            "The ownership of a hApp can't be offered to its current owner".to_string(),
+        ));
+    }
+    if !is_happ_owner(
+        action.author(),
+        action.prev_action(),
+        &ownership_offer.happ_hash,
+    )? {
+        return Ok(ValidateCallbackResult::Invalid(
+            "Only the owner of a hApp can offer its ownership".to_string(),
+        ));
+    }
+    Ok(ValidateCallbackResult::Valid)
+}
+
+pub fn validate_update_ownership_offer(
+    _action: Update,
+    _ownership_offer: OwnershipOffer,
+    _original_action: EntryCreationAction,
+    _original_ownership_offer: OwnershipOffer,
+) -> ExternResult<ValidateCallbackResult> {
+    Ok(ValidateCallbackResult::Invalid(
+        "Ownership offers cannot be updated".to_string(),
+    ))
+}
+
+pub fn validate_delete_ownership_offer(
+    action: Delete,
+    original_action: EntryCreationAction,
+    _original_ownership_offer: OwnershipOffer,
+) -> ExternResult<ValidateCallbackResult> {
+    if action.author.ne(original_action.author()) {
+        return Ok(ValidateCallbackResult::Invalid(
+            "Only the owner that made an ownership offer can cancel it".to_string(),
+        ));
+    }
+    let previous_action = must_get_action(action.prev_action.clone())?;
+    let cancellation_entry_type: EntryType =
+        UnitEntryTypes::OwnershipOfferCancellation.try_into()?;
+    let Action::Create(create) = previous_action.action() else {
+        return Ok(ValidateCallbackResult::Invalid(
+            "Deleting an ownership offer must come right after its cancellation".to_string(),
+        ));
+    };
+    if create.entry_type.ne(&cancellation_entry_type) {
+        return Ok(ValidateCallbackResult::Invalid(
+            "Deleting an ownership offer must come right after its cancellation".to_string(),
+        ));
+    }
+    let entry = must_get_entry(create.entry_hash.clone())?;
+    let cancellation = OwnershipOfferCancellation::try_from(entry.content)?;
+    if cancellation.offer_hash.ne(&action.deletes_address) {
+        return Ok(ValidateCallbackResult::Invalid(
+            "Deleting an ownership offer must come right after its cancellation".to_string(),
+        ));
+    }
+    Ok(ValidateCallbackResult::Valid)
+}
+
+pub fn validate_create_ownership_offer_cancellation(
+    action: EntryCreationAction,
+    ownership_offer_cancellation: OwnershipOfferCancellation,
+) -> ExternResult<ValidateCallbackResult> {
+    let offer_record = must_get_valid_record(ownership_offer_cancellation.offer_hash.clone())?;
+    let Some(offer) = offer_record
+        .entry()
+        .to_app_option::<OwnershipOffer>()
+        .map_err(|e| wasm_error!(e))?
+    else {
+        return Ok(ValidateCallbackResult::Invalid(
+            "An ownership offer cancellation must reference an ownership offer".to_string(),
+        ));
+    };
+    if offer_record.action().author().ne(action.author()) {
+        return Ok(ValidateCallbackResult::Invalid(
+            "Only the owner that made an ownership offer can cancel it".to_string(),
+        ));
+    }
+    // Acceptances must come before the deadline, so the chain of the recipient up to an action after the deadline
+    // has all of them, and canceling an accepted offer would make the owner own the hApp again alongside the recipient
+    let recipient_chain_top =
+        must_get_action(ownership_offer_cancellation.recipient_chain_top.clone())?;
+    if recipient_chain_top.action().author().ne(&offer.recipient) {
+        return Ok(ValidateCallbackResult::Invalid(
+            "The recipient chain top of an ownership offer cancellation must be an action of the recipient"
+                .to_string(),
+        ));
+    }
+    if recipient_chain_top
+        .action()
+        .timestamp()
+        .le(&ownership_offer_deadline(offer_record.action())?)
+    {
+        return Ok(ValidateCallbackResult::Invalid(
+            "An ownership offer can only be canceled with an action of the recipient from after its deadline"
+                .to_string(),
+        ));
+    }
+    let was_accepted = ChainActivity::get(
+        &offer.recipient,
+        &ownership_offer_cancellation.recipient_chain_top,
+    )?
+    .entries::<OwnershipTransfer>(UnitEntryTypes::OwnershipTransfer)?
+    .iter()
+    .any(|transfer| {
+        transfer
+            .entry
+            .offer_hash
+            .eq(&ownership_offer_cancellation.offer_hash)
+    });
+    if was_accepted {
+        return Ok(ValidateCallbackResult::Invalid(
+            "An ownership offer can't be canceled after the recipient accepted it".to_string(),
+        ));
+    }
+    Ok(ValidateCallbackResult::Valid)
+}
+
+pub fn validate_update_ownership_offer_cancellation(
+    _action: Update,
+    _ownership_offer_cancellation: OwnershipOfferCancellation,
+    _original_action: EntryCreationAction,
+    _original_ownership_offer_cancellation: OwnershipOfferCancellation,
+) -> ExternResult<ValidateCallbackResult> {
+    Ok(ValidateCallbackResult::Invalid(
+        "Ownership offer cancellations cannot be updated".to_string(),
+    ))
+}
+
+pub fn validate_delete_ownership_offer_cancellation(
+    _action: Delete,
+    _original_action: EntryCreationAction,
+    _original_ownership_offer_cancellation: OwnershipOfferCancellation,
+) -> ExternResult<ValidateCallbackResult> {
+    Ok(ValidateCallbackResult::Invalid(
+        "Ownership offer cancellations cannot be deleted".to_string(),
+    ))
+}
+
+pub fn validate_create_ownership_transfer(
+    action: EntryCreationAction,
+    ownership_transfer: OwnershipTransfer,
+) -> ExternResult<ValidateCallbackResult> {
+    let offer_record = must_get_valid_record(ownership_transfer.offer_hash.clone())?;
+    let Some(offer) = offer_record
+        .entry()
+        .to_app_option::<OwnershipOffer>()
+        .map_err(|e| wasm_error!(e))?
+    else {
+        return Ok(ValidateCallbackResult::Invalid(
+            "An ownership transfer must reference an ownership offer".to_string(),
+        ));
+    };
+    if offer.happ_hash.ne(&ownership_transfer.happ_hash) {
+        return Ok(ValidateCallbackResult::Invalid(
+            "An ownership transfer must be for the hApp of its offer".to_string(),
+        ));
+    }
+    if offer.recipient.ne(action.author()) {
+        return Ok(ValidateCallbackResult::Invalid(
+            "Only the recipient of an ownership offer can accept it".to_string(),
+        ));
+    }
+    if action
+        .timestamp()
+        .gt(&ownership_offer_deadline(offer_record.action())?)
+    {
+        return Ok(ValidateCallbackResult::Invalid(
+            "An ownership offer can't be accepted after its deadline".to_string(),
+        ));
+    }
+
+    let offerer_activity = ChainActivity::get(
+        offer_record.action().author(),
+        &ownership_transfer.offerer_chain_top,
+    )?;
+    let is_pending = offerer_activity
+        .entries::<OwnershipOffer>(UnitEntryTypes::OwnershipOffer)?
+        .iter()
+        .any(|chain_offer| {
+            chain_offer.action_hash.eq(&ownership_transfer.offer_hash) && !chain_offer.deleted
+        });
+    let was_canceled = offerer_activity
+        .entries::<OwnershipOfferCancellation>(UnitEntryTypes::OwnershipOfferCancellation)?
+        .iter()
+        .any(|cancellation| {
+            cancellation
+                .entry
+                .offer_hash
+                .eq(&ownership_transfer.offer_hash)
+        });
+    if !is_pending || was_canceled {
+        return Ok(ValidateCallbackResult::Invalid(
+            "An ownership offer can only be accepted if the owner had not canceled it by the offerer chain top of the transfer"
+                .to_string(),
+        ));
+    }
+    Ok(ValidateCallbackResult::Valid)
+}
+
+pub fn validate_update_ownership_transfer(
+    _action: Update,
+    _ownership_transfer: OwnershipTransfer,
+    _original_action: EntryCreationAction,
+    _original_ownership_transfer: OwnershipTransfer,
+) -> ExternResult<ValidateCallbackResult> {
+    Ok(ValidateCallbackResult::Invalid(
+        "Ownership transfers cannot be updated".to_string(),
+    ))
+}
+
+pub fn validate_delete_ownership_transfer(
+    _action: Delete,
+    _original_action: EntryCreationAction,
+    _original_ownership_transfer: OwnershipTransfer,
+) -> ExternResult<ValidateCallbackResult> {
+    Ok(ValidateCallbackResult::Invalid(
+        "Ownership transfers cannot be deleted".to_string(),
+    ))
+}
+
+pub fn validate_create_link_happ_ownership_offers(
+    action: CreateLink,
+    base_address: AnyLinkableHash,
+    target_address: AnyLinkableHash,
+    _tag: LinkTag,
+) -> ExternResult<ValidateCallbackResult> {
+    let happ_hash = base_address
+        .into_action_hash()
+        .ok_or(wasm_error!(WasmErrorInner::Guest(
+            "No action hash associated with link".to_string()
+        )))?;
+    let action_hash =
+        target_address
+            .into_action_hash()
+            .ok_or(wasm_error!(WasmErrorInner::Guest(
+                "No action hash associated with link".to_string()
+            )))?;
+    let record = must_get_valid_record(action_hash)?;
+    let offer: crate::OwnershipOffer = record
+        .entry()
+        .to_app_option()
+        .map_err(|e| wasm_error!(e))?
+        .ok_or(wasm_error!(WasmErrorInner::Guest(
+            "Linked action must reference an entry".to_string()
+        )))?;
+    if offer.happ_hash.ne(&happ_hash) {
+        return Ok(ValidateCallbackResult::Invalid(
+            "HappOwnershipOffers links must be created from the hApp of the offer".to_string(),
+        ));
+    }
+    if action.author.ne(record.action().author()) {
+        return Ok(ValidateCallbackResult::Invalid(
+            "Only the author of an ownership offer can link it".to_string(),
+        ));
+    }
+    Ok(ValidateCallbackResult::Valid)
+}
+
+pub fn validate_delete_link_happ_ownership_offers(
+    action: DeleteLink,
+    original_action: CreateLink,
+    _base: AnyLinkableHash,
+    target: AnyLinkableHash,
+    _tag: LinkTag,
+) -> ExternResult<ValidateCallbackResult> {
+    let action_hash = target
+        .into_action_hash()
+        .ok_or(wasm_error!(WasmErrorInner::Guest(
+            "No action hash associated with link".to_string()
+        )))?;
+    let record = must_get_valid_record(action_hash)?;
+    let offer: crate::OwnershipOffer = record
+        .entry()
+        .to_app_option()
+        .map_err(|e| wasm_error!(e))?
+        .ok_or(wasm_error!(WasmErrorInner::Guest(
+            "Linked action must reference an entry".to_string()
+        )))?;
+    // The recipient removes the offer from the pending ones when they accept it
+    if action.author.ne(&original_action.author) && action.author.ne(&offer.recipient) {
+        return Ok(ValidateCallbackResult::Invalid(
+            "Only the owner that made an ownership offer or its recipient can remove it"
+                .to_string(),
+        ));
+    }
+    Ok(ValidateCallbackResult::Valid)
+}
+
+pub fn validate_create_link_happ_ownership_transfers(
+    action: CreateLink,
+    base_address: AnyLinkableHash,
+    target_address: AnyLinkableHash,
+    _tag: LinkTag,
+) -> ExternResult<ValidateCallbackResult> {
+    let happ_hash = base_address
+        .into_action_hash()
+        .ok_or(wasm_error!(WasmErrorInner::Guest(
+            "No action hash associated with link".to_string()
+        )))?;
+    let action_hash =
+        target_address
+            .into_action_hash()
+            .ok_or(wasm_error!(WasmErrorInner::Guest(
+                "No action hash associated with link".to_string()
+            )))?;
+    let record = must_get_valid_record(action_hash)?;
+    let transfer: crate::OwnershipTransfer = record
+        .entry()
+        .to_app_option()
+        .map_err(|e| wasm_error!(e))?
+        .ok_or(wasm_error!(WasmErrorInner::Guest(
+            "Linked action must reference an entry".to_string()
+        )))?;
+    if transfer.happ_hash.ne(&happ_hash) {
+        return Ok(ValidateCallbackResult::Invalid(
+            "HappOwnershipTransfers links must be created from the hApp of the transfer"
+                .to_string(),
+        ));
+    }
+    if action.author.ne(record.action().author()) {
+        return Ok(ValidateCallbackResult::Invalid(
+            "Only the author of an ownership transfer can link it".to_string(),
+        ));
+    }
+    Ok(ValidateCallbackResult::Valid)
+}
+
+pub fn validate_delete_link_happ_ownership_transfers(
+    _action: DeleteLink,
+    _original_action: CreateLink,
+    _base: AnyLinkableHash,
+    _target: AnyLinkableHash,
+    _tag: LinkTag,
+) -> ExternResult<ValidateCallbackResult> {
+    Ok(ValidateCallbackResult::Invalid(
+        "HappOwnershipTransfers links cannot be deleted".to_string(),
+    ))
+}
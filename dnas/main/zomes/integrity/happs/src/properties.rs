@@ -0,0 +1,67 @@
+use hdi::prelude::*;
+
+/// The properties of the DNA, set in its manifest or when installing the app
+///
+/// Agent keys are written in their base64 form, so that they can be set in the YAML manifest
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct HappsProperties {
+    /// The agent that appoints the moderators and issues publisher credentials, who is a moderator as well
+    ///
+    /// Everyone can publish hApps in networks without a progenitor
+    #[serde(default)]
+    pub progenitor: Option<AgentPubKeyB64>,
+    /// The agents that are moderators from the start, without being appointed by the progenitor
+    #[serde(default)]
+    pub initial_moderators: Vec<AgentPubKeyB64>,
+    /// For how long after the action of the owner or the progenitor that a maintainer or moderator claim points to
+    /// the claim grants its rights, which is 30 days if not set
+    #[serde(default)]
+    pub claim_validity_secs: Option<u64>,
+    /// For how long after being made an ownership offer can be accepted, which is 7 days if not set
+    #[serde(default)]
+    pub ownership_offer_validity_secs: Option<u64>,
+}
+
+pub const DEFAULT_CLAIM_VALIDITY_SECS: u64 = 30 * 24 * 60 * 60;
+pub const DEFAULT_OWNERSHIP_OFFER_VALIDITY_SECS: u64 = 7 * 24 * 60 * 60;
+
+impl HappsProperties {
+    pub fn progenitor(&self) -> Option<AgentPubKey> {
+        self.progenitor.clone().map(AgentPubKey::from)
+    }
+
+    pub fn initial_moderators(&self) -> Vec<AgentPubKey> {
+        self.initial_moderators
+            .iter()
+            .cloned()
+            .map(AgentPubKey::from)
+            .collect()
+    }
+
+    pub fn is_progenitor(&self, agent: &AgentPubKey) -> bool {
+        self.progenitor().as_ref() == Some(agent)
+    }
+
+    pub fn claim_validity_secs(&self) -> u64 {
+        self.claim_validity_secs
+            .unwrap_or(DEFAULT_CLAIM_VALIDITY_SECS)
+    }
+
+    pub fn ownership_offer_validity_secs(&self) -> u64 {
+        self.ownership_offer_validity_secs
+            .unwrap_or(DEFAULT_OWNERSHIP_OFFER_VALIDITY_SECS)
+    }
+}
+
+/// Reads the properties of the DNA, which are all empty if the manifest sets none
+pub fn happs_properties() -> ExternResult<HappsProperties> {
+    let properties = dna_info()?.modifiers.properties;
+    let properties: Option<HappsProperties> = ExternIO(properties.bytes().to_vec())
+        .decode()
+        .map_err(|e| {
+            wasm_error!(WasmErrorInner::Guest(format!(
+                "Malformed DNA properties: {e:?}"
+            )))
+        })?;
+    Ok(properties.unwrap_or_default())
+}
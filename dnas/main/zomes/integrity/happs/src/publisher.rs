@@ -0,0 +1,203 @@
+use hdi::prelude::*;
+
+use crate::update_links::check_update_link;
+
+/// The public profile of an agent that publishes hApps
+#[derive(Clone, PartialEq)]
+#[hdk_entry_helper]
+pub struct Publisher {
+    pub display_name: String,
+    pub avatar: Option<EntryHash>,
+    pub url: Option<String>,
+    pub bio: String,
+}
+
+pub const MAX_PUBLISHER_DISPLAY_NAME_LENGTH: usize = 64;
+pub const MAX_PUBLISHER_URL_LENGTH: usize = 2048;
+pub const MAX_PUBLISHER_BIO_LENGTH: usize = 2000;
+
+pub fn validate_create_publisher(
+    _action: EntryCreationAction,
+    publisher: Publisher,
+) -> ExternResult<ValidateCallbackResult> {
+    if let Some(error) = validate_publisher_fields(&publisher) {
+        return Ok(ValidateCallbackResult::Invalid(error));
+    }
+    Ok(ValidateCallbackResult::Valid)
+}
+
+// The returned messages are shown as is to the publisher by the UI
+fn validate_publisher_fields(publisher: &Publisher) -> Option<String> {
+    let display_name = publisher.display_name.trim();
+    if display_name.is_empty() {
+        return Some("The display name of the publisher can't be empty.".to_string());
+    }
+    if display_name.chars().count() > MAX_PUBLISHER_DISPLAY_NAME_LENGTH {
+        return Some(format!(
+            "The display name of the publisher can't be longer than {MAX_PUBLISHER_DISPLAY_NAME_LENGTH} characters."
+        ));
+    }
+    if publisher.display_name.chars().any(char::is_control) {
+        return Some(
+            "The display name of the publisher can't contain control characters.".to_string(),
+        );
+    }
+    if let Some(url) = &publisher.url {
+        if url.chars().count() > MAX_PUBLISHER_URL_LENGTH {
+            return Some(format!(
+                "The URL of the publisher can't be longer than {MAX_PUBLISHER_URL_LENGTH} characters."
+            ));
+        }
+        if !(url.starts_with("https://") || url.starts_with("http://"))
+            || url.chars().any(|c| c.is_whitespace() || c.is_control())
+        {
+            return Some("The URL of the publisher must be a valid http or https URL.".to_string());
+        }
+    }
+    if publisher.bio.chars().count() > MAX_PUBLISHER_BIO_LENGTH {
+        return Some(format!(
+            "The bio of the publisher can't be longer than {MAX_PUBLISHER_BIO_LENGTH} characters."
+        ));
+    }
+    if publisher
+        .bio
+        .chars()
+        .any(|c| c.is_control() && !matches!(c, '\n' | '\r' | '\t'))
+    {
+        return Some("The bio of the publisher can't contain control characters.".to_string());
+    }
+    None
+}
+
+pub fn validate_update_publisher(
+    action: Update,
+    _publisher: Publisher,
+    original_action: EntryCreationAction,
+    _original_publisher: Publisher,
+) -> ExternResult<ValidateCallbackResult> {
+    if action.author.ne(original_action.author()) {
+        return Ok(ValidateCallbackResult::Invalid(
+            "Only the author of a publisher profile can update it".to_string(),
+        ));
+    }
+    Ok(ValidateCallbackResult::Valid)
+}
+
+pub fn validate_delete_publisher(
+    _action: Delete,
+    _original_action: EntryCreationAction,
+    _original_publisher: Publisher,
+) -> ExternResult<ValidateCallbackResult> {
+    Ok(ValidateCallbackResult::Invalid(
+        "Publisher profiles cannot be deleted".to_string(),
+    ))
+}
+
+pub fn validate_create_link_agent_to_publisher(
+    action: CreateLink,
+    base_address: AnyLinkableHash,
+    target_address: AnyLinkableHash,
+    _tag: LinkTag,
+) -> ExternResult<ValidateCallbackResult> {
+    let action_hash =
+        target_address
+            .into_action_hash()
+            .ok_or(wasm_error!(WasmErrorInner::Guest(
+                "No action hash associated with link".to_string()
+            )))?;
+    let record = must_get_valid_record(action_hash)?;
+    let _publisher: crate::Publisher = record
+        .entry()
+        .to_app_option()
+        .map_err(|e| wasm_error!(e))?
+        .ok_or(wasm_error!(WasmErrorInner::Guest(
+            "Linked action must reference an entry".to_string()
+        )))?;
+    if base_address
+        .into_agent_pub_key()
+        .as_ref()
+        .ne(&Some(&action.author))
+    {
+        return Ok(ValidateCallbackResult::Invalid(
+            "AgentToPublisher links must be created from the public key of their author"
+                .to_string(),
+        ));
+    }
+    if action.author.ne(record.action().author()) {
+        return Ok(ValidateCallbackResult::Invalid(
+            "Agents can only link to their own publisher profile".to_string(),
+        ));
+    }
+    Ok(ValidateCallbackResult::Valid)
+}
+
+pub fn validate_delete_link_agent_to_publisher(
+    action: DeleteLink,
+    original_action: CreateLink,
+    _base: AnyLinkableHash,
+    _target: AnyLinkableHash,
+    _tag: LinkTag,
+) -> ExternResult<ValidateCallbackResult> {
+    if action.author.ne(&original_action.author) {
+        return Ok(ValidateCallbackResult::Invalid(
+            "Only the author of a publisher profile can unlink it".to_string(),
+        ));
+    }
+    Ok(ValidateCallbackResult::Valid)
+}
+
+pub fn validate_create_link_publisher_updates(
+    action: CreateLink,
+    base_address: AnyLinkableHash,
+    target_address: AnyLinkableHash,
+    _tag: LinkTag,
+) -> ExternResult<ValidateCallbackResult> {
+    let action_hash = base_address
+        .into_action_hash()
+        .ok_or(wasm_error!(WasmErrorInner::Guest(
+            "No action hash associated with link".to_string()
+        )))?;
+    let original_record = must_get_valid_record(action_hash)?;
+    let _publisher: crate::Publisher = original_record
+        .entry()
+        .to_app_option()
+        .map_err(|e| wasm_error!(e))?
+        .ok_or(wasm_error!(WasmErrorInner::Guest(
+            "Linked action must reference an entry".to_string()
+        )))?;
+    let action_hash =
+        target_address
+            .into_action_hash()
+            .ok_or(wasm_error!(WasmErrorInner::Guest(
+                "No action hash associated with link".to_string()
+            )))?;
+    let update_record = must_get_valid_record(action_hash)?;
+    let _publisher: crate::Publisher = update_record
+        .entry()
+        .to_app_option()
+        .map_err(|e| wasm_error!(e))?
+        .ok_or(wasm_error!(WasmErrorInner::Guest(
+            "Linked action must reference an entry".to_string()
+        )))?;
+    if action.author.ne(original_record.action().author()) {
+        return Ok(ValidateCallbackResult::Invalid(
+            "Only the author of a publisher profile can link updates to it".to_string(),
+        ));
+    }
+    if let Some(error) = check_update_link(&action.author, &original_record, &update_record)? {
+        return Ok(ValidateCallbackResult::Invalid(error));
+    }
+    Ok(ValidateCallbackResult::Valid)
+}
+
+pub fn validate_delete_link_publisher_updates(
+    _action: DeleteLink,
+    _original_action: CreateLink,
+    _base: AnyLinkableHash,
+    _target: AnyLinkableHash,
+    _tag: LinkTag,
+) -> ExternResult<ValidateCallbackResult> {
+    Ok(ValidateCallbackResult::Invalid(
+        "PublisherUpdates links cannot be deleted".to_string(),
+    ))
+}
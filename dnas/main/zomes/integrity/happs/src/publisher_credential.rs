@@ -0,0 +1,126 @@
+use hdi::prelude::*;
+
+use crate::{chain_activity::ChainActivity, happs_properties, UnitEntryTypes};
+
+/// Proof that the progenitor vetted an agent to publish hApps: the signature of a `PublisherCredentialPayload`
+/// by the progenitor
+///
+/// Agents can present it as their membrane proof when joining the network, or commit it later as an entry
+#[derive(Clone, PartialEq)]
+#[hdk_entry_helper]
+pub struct PublisherCredential {
+    pub agent: AgentPubKey,
+    pub signature: Signature,
+}
+
+/// The data that the progenitor signs to vet a publisher
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct PublisherCredentialPayload {
+    pub agent: AgentPubKey,
+}
+
+/// Returns the reason why the credential doesn't let the given agent publish, if it doesn't
+fn check_publisher_credential(
+    agent: &AgentPubKey,
+    credential: &PublisherCredential,
+) -> ExternResult<Option<String>> {
+    if credential.agent.ne(agent) {
+        return Ok(Some(
+            "The publisher credential was issued for another agent".to_string(),
+        ));
+    }
+    let Some(progenitor) = happs_properties()?.progenitor() else {
+        return Ok(Some(
+            "There is no progenitor to issue publisher credentials in this network".to_string(),
+        ));
+    };
+    let payload = PublisherCredentialPayload {
+        agent: credential.agent.clone(),
+    };
+    if !verify_signature(progenitor, credential.signature.clone(), payload)? {
+        return Ok(Some(
+            "The publisher credential must be signed by the progenitor".to_string(),
+        ));
+    }
+    Ok(None)
+}
+
+/// Membrane proofs are optional, as agents without one can still browse and review hApps,
+/// but the ones that are present must be publisher credentials for the joining agent
+pub fn validate_membrane_proof(
+    agent: &AgentPubKey,
+    membrane_proof: &Option<MembraneProof>,
+) -> ExternResult<ValidateCallbackResult> {
+    let Some(membrane_proof) = membrane_proof else {
+        return Ok(ValidateCallbackResult::Valid);
+    };
+    let Ok(credential) = PublisherCredential::try_from((**membrane_proof).clone()) else {
+        return Ok(ValidateCallbackResult::Invalid(
+            "The membrane proof must be a publisher credential".to_string(),
+        ));
+    };
+    match check_publisher_credential(agent, &credential)? {
+        Some(error) => Ok(ValidateCallbackResult::Invalid(error)),
+        None => Ok(ValidateCallbackResult::Valid),
+    }
+}
+
+/// Returns whether the given agent could publish hApps when they committed the action after `chain_top`
+///
+/// Everyone can publish in networks without a progenitor. Otherwise, only the progenitor and the agents
+/// with a publisher credential, either as their membrane proof or committed in their source chain, can
+pub fn is_vetted_publisher(agent: &AgentPubKey, chain_top: &ActionHash) -> ExternResult<bool> {
+    let properties = happs_properties()?;
+    if properties.progenitor.is_none() || properties.is_progenitor(agent) {
+        return Ok(true);
+    }
+
+    let credential_entry_type: EntryType = UnitEntryTypes::PublisherCredential.try_into()?;
+    let activity = ChainActivity::get(agent, chain_top)?;
+    for action in activity.actions() {
+        match &action.content {
+            // Membrane proofs were already validated when the agent joined
+            Action::AgentValidationPkg(AgentValidationPkg {
+                membrane_proof: Some(_),
+                ..
+            }) => return Ok(true),
+            // Credentials can't be deleted, and were validated when they were committed
+            Action::Create(create) if create.entry_type.eq(&credential_entry_type) => {
+                return Ok(true)
+            }
+            _ => {}
+        }
+    }
+    Ok(false)
+}
+
+pub fn validate_create_publisher_credential(
+    action: EntryCreationAction,
+    publisher_credential: PublisherCredential,
+) -> ExternResult<ValidateCallbackResult> {
+    match check_publisher_credential(action.author(), &publisher_credential)? {
+        Some(error) => Ok(ValidateCallbackResult::Invalid(error)),
+        None => Ok(ValidateCallbackResult::Valid),
+    }
+}
+
+pub fn validate_update_publisher_credential(
+    _action: Update,
+    _publisher_credential: PublisherCredential,
+    _original_action: EntryCreationAction,
+    _original_publisher_credential: PublisherCredential,
+) -> ExternResult<ValidateCallbackResult> {
+    Ok(ValidateCallbackResult::Invalid(
+        "Publisher credentials cannot be updated".to_string(),
+    ))
+}
+
+pub fn validate_delete_publisher_credential(
+    _action: Delete,
+    _original_action: EntryCreationAction,
+    _original_publisher_credential: PublisherCredential,
+) -> ExternResult<ValidateCallbackResult> {
+    Ok(ValidateCallbackResult::Invalid(
+        "Publisher credentials cannot be deleted".to_string(),
+    ))
+}
@@ -0,0 +1,334 @@
+use hdi::prelude::*;
+
+use crate::{chain_activity::ChainActivity, is_moderator, HappRelease, UnitEntryTypes};
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+pub enum ReportReason {
+    Malware,
+    Spam,
+    Impersonation,
+    InappropriateContent,
+    Other,
+}
+
+/// A flag raised by any agent on a hApp listing, or on one of its releases, for the moderators to review
+#[derive(Clone, PartialEq)]
+#[hdk_entry_helper]
+pub struct Report {
+    pub happ_hash: ActionHash,
+    pub happ_release_hash: Option<ActionHash>,
+    pub reason: ReportReason,
+    pub text: String,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+pub enum ReportDecision {
+    /// The report was found to be unfounded
+    Dismissed,
+    /// The report was found to be legit, and action was taken on the listing
+    Upheld,
+}
+
+/// The decision of a moderator on a report, which closes it
+#[derive(Clone, PartialEq)]
+#[hdk_entry_helper]
+pub struct ReportResolution {
+    pub report_hash: ActionHash,
+    pub decision: ReportDecision,
+    pub notes: String,
+}
+
+pub const MAX_REPORT_TEXT_LENGTH: usize = 2000;
+
+/// The path that links to the reports that were not resolved yet
+pub fn open_reports_path() -> Path {
+    Path::from("open_reports")
+}
+
+// The returned messages are shown as is to the reporter or the moderator by the UI
+fn validate_report_text(text: &str, field: &str) -> Option<String> {
+    if text.chars().count() > MAX_REPORT_TEXT_LENGTH {
+        return Some(format!(
+            "The {field} can't be longer than {MAX_REPORT_TEXT_LENGTH} characters."
+        ));
+    }
+    if text
+        .chars()
+        .any(|c| c.is_control() && !matches!(c, '\n' | '\r' | '\t'))
+    {
+        return Some(format!("The {field} can't contain control characters."));
+    }
+    None
+}
+
+pub fn validate_create_report(
+    _action: EntryCreationAction,
+    report: Report,
+) -> ExternResult<ValidateCallbackResult> {
+    if let Some(error) = validate_report_text(&report.text, "text of a report") {
+        return Ok(ValidateCallbackResult::Invalid(error));
+    }
+    let happ_record = must_get_valid_record(report.happ_hash.clone())?;
+    let _happ: crate::Happ = happ_record
+        .entry()
+        .to_app_option()
+        .map_err(|e| wasm_error!(e))?
+        .ok_or(wasm_error!(WasmErrorInner::Guest(
+            "Dependant action must be accompanied by an entry".to_string()
+        )))?;
+    if !matches!(happ_record.action(), Action::Create(_)) {
+        return Ok(ValidateCallbackResult::Invalid(
+            "Reports must reference the original action of the hApp".to_string(),
+        ));
+    }
+    if let Some(happ_release_hash) = report.happ_release_hash {
+        let happ_release_record = must_get_valid_record(happ_release_hash)?;
+        let happ_release: HappRelease = happ_release_record
+            .entry()
+            .to_app_option()
+            .map_err(|e| wasm_error!(e))?
+            .ok_or(wasm_error!(WasmErrorInner::Guest(
+                "Dependant action must be accompanied by an entry".to_string()
+            )))?;
+        if happ_release.happ_hash.ne(&report.happ_hash) {
+            return Ok(ValidateCallbackResult::Invalid(
+                "The reported release must be a release of the reported hApp".to_string(),
+            ));
+        }
+    }
+    Ok(ValidateCallbackResult::Valid)
+}
+
+pub fn validate_update_report(
+    _action: Update,
+    _report: Report,
+    _original_action: EntryCreationAction,
+    _original_report: Report,
+) -> ExternResult<ValidateCallbackResult> {
+    Ok(ValidateCallbackResult::Invalid(
+        "Reports cannot be updated".to_string(),
+    ))
+}
+
+pub fn validate_delete_report(
+    _action: Delete,
+    _original_action: EntryCreationAction,
+    _original_report: Report,
+) -> ExternResult<ValidateCallbackResult> {
+    Ok(ValidateCallbackResult::Invalid(
+        "Reports cannot be deleted".to_string(),
+    ))
+}
+
+pub fn validate_create_report_resolution(
+    action: EntryCreationAction,
+    report_resolution: ReportResolution,
+) -> ExternResult<ValidateCallbackResult> {
+    if !is_moderator(action.author(), action.prev_action(), action.timestamp())? {
+        return Ok(ValidateCallbackResult::Invalid(
+            "Only moderators can resolve reports".to_string(),
+        ));
+    }
+    if let Some(error) = validate_report_text(&report_resolution.notes, "notes of a resolution") {
+        return Ok(ValidateCallbackResult::Invalid(error));
+    }
+    let report_record = must_get_valid_record(report_resolution.report_hash.clone())?;
+    let _report: crate::Report = report_record
+        .entry()
+        .to_app_option()
+        .map_err(|e| wasm_error!(e))?
+        .ok_or(wasm_error!(WasmErrorInner::Guest(
+            "Dependant action must be accompanied by an entry".to_string()
+        )))?;
+
+    // Validation can't see the resolutions that other moderators committed, which `resolve_report` checks for
+    let already_resolved = ChainActivity::get(action.author(), action.prev_action())?
+        .entries::<ReportResolution>(UnitEntryTypes::ReportResolution)?
+        .iter()
+        .any(|resolution| {
+            resolution
+                .entry
+                .report_hash
+                .eq(&report_resolution.report_hash)
+        });
+    if already_resolved {
+        return Ok(ValidateCallbackResult::Invalid(
+            "A moderator can only resolve a report once".to_string(),
+        ));
+    }
+    Ok(ValidateCallbackResult::Valid)
+}
+
+pub fn validate_update_report_resolution(
+    _action: Update,
+    _report_resolution: ReportResolution,
+    _original_action: EntryCreationAction,
+    _original_report_resolution: ReportResolution,
+) -> ExternResult<ValidateCallbackResult> {
+    Ok(ValidateCallbackResult::Invalid(
+        "Report resolutions cannot be updated".to_string(),
+    ))
+}
+
+pub fn validate_delete_report_resolution(
+    _action: Delete,
+    _original_action: EntryCreationAction,
+    _original_report_resolution: ReportResolution,
+) -> ExternResult<ValidateCallbackResult> {
+    Ok(ValidateCallbackResult::Invalid(
+        "Report resolutions cannot be deleted".to_string(),
+    ))
+}
+
+fn get_report(target_address: AnyLinkableHash) -> ExternResult<(Record, Report)> {
+    let action_hash =
+        target_address
+            .into_action_hash()
+            .ok_or(wasm_error!(WasmErrorInner::Guest(
+                "No action hash associated with link".to_string()
+            )))?;
+    let record = must_get_valid_record(action_hash)?;
+    let report: crate::Report = record
+        .entry()
+        .to_app_option()
+        .map_err(|e| wasm_error!(e))?
+        .ok_or(wasm_error!(WasmErrorInner::Guest(
+            "Linked action must reference an entry".to_string()
+        )))?;
+    Ok((record, report))
+}
+
+pub fn validate_create_link_happ_reports(
+    action: CreateLink,
+    base_address: AnyLinkableHash,
+    target_address: AnyLinkableHash,
+    _tag: LinkTag,
+) -> ExternResult<ValidateCallbackResult> {
+    let (record, report) = get_report(target_address)?;
+    if base_address
+        .into_action_hash()
+        .as_ref()
+        .ne(&Some(&report.happ_hash))
+    {
+        return Ok(ValidateCallbackResult::Invalid(
+            "HappReports links must be created from the reported hApp".to_string(),
+        ));
+    }
+    if action.author.ne(record.action().author()) {
+        return Ok(ValidateCallbackResult::Invalid(
+            "Only the author of a report can link it".to_string(),
+        ));
+    }
+    Ok(ValidateCallbackResult::Valid)
+}
+
+pub fn validate_delete_link_happ_reports(
+    _action: DeleteLink,
+    _original_action: CreateLink,
+    _base: AnyLinkableHash,
+    _target: AnyLinkableHash,
+    _tag: LinkTag,
+) -> ExternResult<ValidateCallbackResult> {
+    Ok(ValidateCallbackResult::Invalid(
+        "HappReports links cannot be deleted".to_string(),
+    ))
+}
+
+pub fn validate_create_link_open_reports(
+    action: CreateLink,
+    base_address: AnyLinkableHash,
+    target_address: AnyLinkableHash,
+    _tag: LinkTag,
+) -> ExternResult<ValidateCallbackResult> {
+    if base_address.ne(&open_reports_path().path_entry_hash()?.into()) {
+        return Ok(ValidateCallbackResult::Invalid(
+            "OpenReports links must be created from the open reports path".to_string(),
+        ));
+    }
+    let (record, _report) = get_report(target_address)?;
+    if action.author.ne(record.action().author()) {
+        return Ok(ValidateCallbackResult::Invalid(
+            "Only the author of a report can open it".to_string(),
+        ));
+    }
+    Ok(ValidateCallbackResult::Valid)
+}
+
+pub fn validate_delete_link_open_reports(
+    action: DeleteLink,
+    _original_action: CreateLink,
+    _base: AnyLinkableHash,
+    target: AnyLinkableHash,
+    _tag: LinkTag,
+) -> ExternResult<ValidateCallbackResult> {
+    if !is_moderator(&action.author, &action.prev_action, &action.timestamp)? {
+        return Ok(ValidateCallbackResult::Invalid(
+            "Only moderators can close reports".to_string(),
+        ));
+    }
+    // Reports are closed by resolving them, so the resolution must come before in the chain of the moderator
+    let report_hash = target
+        .into_action_hash()
+        .ok_or(wasm_error!(WasmErrorInner::Guest(
+            "No action hash associated with link".to_string()
+        )))?;
+    let was_resolved = ChainActivity::get(&action.author, &action.prev_action)?
+        .entries::<ReportResolution>(UnitEntryTypes::ReportResolution)?
+        .iter()
+        .any(|resolution| resolution.entry.report_hash.eq(&report_hash));
+    if !was_resolved {
+        return Ok(ValidateCallbackResult::Invalid(
+            "A report can only be closed after resolving it".to_string(),
+        ));
+    }
+    Ok(ValidateCallbackResult::Valid)
+}
+
+pub fn validate_create_link_report_resolutions(
+    action: CreateLink,
+    base_address: AnyLinkableHash,
+    target_address: AnyLinkableHash,
+    _tag: LinkTag,
+) -> ExternResult<ValidateCallbackResult> {
+    let action_hash =
+        target_address
+            .into_action_hash()
+            .ok_or(wasm_error!(WasmErrorInner::Guest(
+                "No action hash associated with link".to_string()
+            )))?;
+    let record = must_get_valid_record(action_hash)?;
+    let report_resolution: crate::ReportResolution = record
+        .entry()
+        .to_app_option()
+        .map_err(|e| wasm_error!(e))?
+        .ok_or(wasm_error!(WasmErrorInner::Guest(
+            "Linked action must reference an entry".to_string()
+        )))?;
+    if base_address
+        .into_action_hash()
+        .as_ref()
+        .ne(&Some(&report_resolution.report_hash))
+    {
+        return Ok(ValidateCallbackResult::Invalid(
+            "ReportResolutions links must be created from the resolved report".to_string(),
+        ));
+    }
+    if action.author.ne(record.action().author()) {
+        return Ok(ValidateCallbackResult::Invalid(
+            "Only the author of a report resolution can link it".to_string(),
+        ));
+    }
+    Ok(ValidateCallbackResult::Valid)
+}
+
+pub fn validate_delete_link_report_resolutions(
+    _action: DeleteLink,
+    _original_action: CreateLink,
+    _base: AnyLinkableHash,
+    _target: AnyLinkableHash,
+    _tag: LinkTag,
+) -> ExternResult<ValidateCallbackResult> {
+    Ok(ValidateCallbackResult::Invalid(
+        "ReportResolutions links cannot be deleted".to_string(),
+    ))
+}
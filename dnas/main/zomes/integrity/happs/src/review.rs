@@ -0,0 +1,404 @@
+use hdi::prelude::*;
+
+use crate::{
+    chain_activity::{has_active_entry, ChainActivity},
+    is_happ_maintainer, is_happ_owner,
+    update_links::check_update_link,
+    HappRelease, LinkTypes, UnitEntryTypes,
+};
+
+/// A rating of a hApp by one of its users, for the release they were using when they wrote it
+#[derive(Clone, PartialEq)]
+#[hdk_entry_helper]
+pub struct Review {
+    pub happ_hash: ActionHash,
+    pub happ_release_hash: ActionHash,
+    pub rating: u8,
+    pub text: String,
+}
+
+/// The answer of the publisher of a hApp to one of its reviews
+#[derive(Clone, PartialEq)]
+#[hdk_entry_helper]
+pub struct ReviewReply {
+    pub review_hash: ActionHash,
+    pub text: String,
+}
+
+pub const MIN_REVIEW_RATING: u8 = 1;
+pub const MAX_REVIEW_RATING: u8 = 5;
+pub const MAX_REVIEW_TEXT_LENGTH: usize = 5000;
+
+// The returned messages are shown as is to the reviewer by the UI
+fn validate_review_fields(review: &Review) -> Option<String> {
+    if review.rating < MIN_REVIEW_RATING || review.rating > MAX_REVIEW_RATING {
+        return Some(format!(
+            "The rating of a review must be between {MIN_REVIEW_RATING} and {MAX_REVIEW_RATING} stars."
+        ));
+    }
+    validate_review_text(&review.text)
+}
+
+fn validate_review_text(text: &str) -> Option<String> {
+    if text.chars().count() > MAX_REVIEW_TEXT_LENGTH {
+        return Some(format!(
+            "The text of a review can't be longer than {MAX_REVIEW_TEXT_LENGTH} characters."
+        ));
+    }
+    if text
+        .chars()
+        .any(|c| c.is_control() && !matches!(c, '\n' | '\r' | '\t'))
+    {
+        return Some("The text of a review can't contain control characters.".to_string());
+    }
+    None
+}
+
+/// Checks that the reviewed release is a release of the reviewed hApp
+fn validate_reviewed_release(review: &Review) -> ExternResult<Option<String>> {
+    let happ_release_record = must_get_valid_record(review.happ_release_hash.clone())?;
+    let happ_release: HappRelease = happ_release_record
+        .entry()
+        .to_app_option()
+        .map_err(|e| wasm_error!(e))?
+        .ok_or(wasm_error!(WasmErrorInner::Guest(
+            "The reviewed release must reference an entry".to_string()
+        )))?;
+    if happ_release.happ_hash.ne(&review.happ_hash) {
+        return Ok(Some(
+            "The reviewed release must be a release of the reviewed hApp".to_string(),
+        ));
+    }
+    Ok(None)
+}
+
+pub fn validate_create_review(
+    action: EntryCreationAction,
+    review: Review,
+) -> ExternResult<ValidateCallbackResult> {
+    if let Some(error) = validate_review_fields(&review) {
+        return Ok(ValidateCallbackResult::Invalid(error));
+    }
+    let happ_record = must_get_valid_record(review.happ_hash.clone())?;
+    let _happ: crate::Happ = happ_record
+        .entry()
+        .to_app_option()
+        .map_err(|e| wasm_error!(e))?
+        .ok_or(wasm_error!(WasmErrorInner::Guest(
+            "Dependant action must be accompanied by an entry".to_string()
+        )))?;
+    if !matches!(happ_record.action(), Action::Create(_)) {
+        return Ok(ValidateCallbackResult::Invalid(
+            "Reviews must reference the original action of the hApp".to_string(),
+        ));
+    }
+    if let Some(error) = validate_reviewed_release(&review)? {
+        return Ok(ValidateCallbackResult::Invalid(error));
+    }
+    if is_happ_maintainer(
+        action.author(),
+        action.prev_action(),
+        action.timestamp(),
+        &review.happ_hash,
+    )? {
+        return Ok(ValidateCallbackResult::Invalid(
+            "The publisher of a hApp can't review it".to_string(),
+        ));
+    }
+    // Updates are not Create actions, so only other reviews of the same hApp are found
+    if has_active_entry(
+        action.author(),
+        action.prev_action(),
+        UnitEntryTypes::Review,
+        |other_review: &Review| other_review.happ_hash.eq(&review.happ_hash),
+    )? {
+        return Ok(ValidateCallbackResult::Invalid(
+            "An agent can only have one review for each hApp".to_string(),
+        ));
+    }
+    Ok(ValidateCallbackResult::Valid)
+}
+
+pub fn validate_update_review(
+    action: Update,
+    review: Review,
+    original_action: EntryCreationAction,
+    original_review: Review,
+) -> ExternResult<ValidateCallbackResult> {
+    if action.author.ne(original_action.author()) {
+        return Ok(ValidateCallbackResult::Invalid(
+            "Only the author of a review can update it".to_string(),
+        ));
+    }
+    if review.happ_hash.ne(&original_review.happ_hash) {
+        return Ok(ValidateCallbackResult::Invalid(
+            "The reviewed hApp of a review can't be changed".to_string(),
+        ));
+    }
+    if let Some(error) = validate_review_fields(&review) {
+        return Ok(ValidateCallbackResult::Invalid(error));
+    }
+    if let Some(error) = validate_reviewed_release(&review)? {
+        return Ok(ValidateCallbackResult::Invalid(error));
+    }
+    Ok(ValidateCallbackResult::Valid)
+}
+
+pub fn validate_delete_review(
+    action: Delete,
+    original_action: EntryCreationAction,
+    _original_review: Review,
+) -> ExternResult<ValidateCallbackResult> {
+    if action.author.ne(original_action.author()) {
+        return Ok(ValidateCallbackResult::Invalid(
+            "Only the author of a review can delete it".to_string(),
+        ));
+    }
+    // Deleting the original action is what lets its author review the hApp again
+    if !matches!(original_action, EntryCreationAction::Create(_)) {
+        return Ok(ValidateCallbackResult::Invalid(
+            "Reviews must be deleted through their original action".to_string(),
+        ));
+    }
+    Ok(ValidateCallbackResult::Valid)
+}
+
+pub fn validate_create_review_reply(
+    action: EntryCreationAction,
+    review_reply: ReviewReply,
+) -> ExternResult<ValidateCallbackResult> {
+    if let Some(error) = validate_review_text(&review_reply.text) {
+        return Ok(ValidateCallbackResult::Invalid(error));
+    }
+    let review_record = must_get_valid_record(review_reply.review_hash.clone())?;
+    let review: Review = review_record
+        .entry()
+        .to_app_option()
+        .map_err(|e| wasm_error!(e))?
+        .ok_or(wasm_error!(WasmErrorInner::Guest(
+            "Dependant action must be accompanied by an entry".to_string()
+        )))?;
+    if !matches!(review_record.action(), Action::Create(_)) {
+        return Ok(ValidateCallbackResult::Invalid(
+            "Replies must reference the original action of the review".to_string(),
+        ));
+    }
+    if !is_happ_owner(action.author(), action.prev_action(), &review.happ_hash)? {
+        return Ok(ValidateCallbackResult::Invalid(
+            "Only the publisher of a hApp can reply to its reviews".to_string(),
+        ));
+    }
+    if has_active_entry(
+        action.author(),
+        action.prev_action(),
+        UnitEntryTypes::ReviewReply,
+        |other_reply: &ReviewReply| other_reply.review_hash.eq(&review_reply.review_hash),
+    )? {
+        return Ok(ValidateCallbackResult::Invalid(
+            "The publisher can only reply once to each review".to_string(),
+        ));
+    }
+    Ok(ValidateCallbackResult::Valid)
+}
+
+pub fn validate_update_review_reply(
+    _action: Update,
+    _review_reply: ReviewReply,
+    _original_action: EntryCreationAction,
+    _original_review_reply: ReviewReply,
+) -> ExternResult<ValidateCallbackResult> {
+    Ok(ValidateCallbackResult::Invalid(
+        "Review replies cannot be updated, delete them and reply again instead".to_string(),
+    ))
+}
+
+pub fn validate_delete_review_reply(
+    action: Delete,
+    original_action: EntryCreationAction,
+    _original_review_reply: ReviewReply,
+) -> ExternResult<ValidateCallbackResult> {
+    if action.author.ne(original_action.author()) {
+        return Ok(ValidateCallbackResult::Invalid(
+            "Only the author of a reply can delete it".to_string(),
+        ));
+    }
+    Ok(ValidateCallbackResult::Valid)
+}
+
+pub fn validate_create_link_happ_reviews(
+    action: CreateLink,
+    base_address: AnyLinkableHash,
+    target_address: AnyLinkableHash,
+    _tag: LinkTag,
+) -> ExternResult<ValidateCallbackResult> {
+    let action_hash =
+        target_address
+            .into_action_hash()
+            .ok_or(wasm_error!(WasmErrorInner::Guest(
+                "No action hash associated with link".to_string()
+            )))?;
+    let record = must_get_valid_record(action_hash)?;
+    let review: crate::Review = record
+        .entry()
+        .to_app_option()
+        .map_err(|e| wasm_error!(e))?
+        .ok_or(wasm_error!(WasmErrorInner::Guest(
+            "Linked action must reference an entry".to_string()
+        )))?;
+    if base_address
+        .clone()
+        .into_action_hash()
+        .as_ref()
+        .ne(&Some(&review.happ_hash))
+    {
+        return Ok(ValidateCallbackResult::Invalid(
+            "HappReviews links must be created from the reviewed hApp".to_string(),
+        ));
+    }
+    if action.author.ne(record.action().author()) {
+        return Ok(ValidateCallbackResult::Invalid(
+            "Only the author of a review can link it".to_string(),
+        ));
+    }
+    // The ratings of a hApp are summarized from these links, so each agent can only have one of them at a time
+    let activity = ChainActivity::get(&action.author, &action.prev_action)?;
+    let has_active_review_link = activity.actions().any(|chain_action| {
+        let Action::CreateLink(create_link) = &chain_action.content else {
+            return false;
+        };
+        matches!(
+            LinkTypes::from_type(create_link.zome_index, create_link.link_type),
+            Ok(Some(LinkTypes::HappReviews))
+        ) && create_link.base_address.eq(&base_address)
+            && !activity.is_link_deleted(&chain_action.hash)
+    });
+    if has_active_review_link {
+        return Ok(ValidateCallbackResult::Invalid(
+            "An agent can only link one review to each hApp at a time".to_string(),
+        ));
+    }
+    Ok(ValidateCallbackResult::Valid)
+}
+
+pub fn validate_delete_link_happ_reviews(
+    action: DeleteLink,
+    original_action: CreateLink,
+    _base: AnyLinkableHash,
+    _target: AnyLinkableHash,
+    _tag: LinkTag,
+) -> ExternResult<ValidateCallbackResult> {
+    if action.author.ne(&original_action.author) {
+        return Ok(ValidateCallbackResult::Invalid(
+            "Only the author of a review can unlink it".to_string(),
+        ));
+    }
+    Ok(ValidateCallbackResult::Valid)
+}
+
+pub fn validate_create_link_review_updates(
+    action: CreateLink,
+    base_address: AnyLinkableHash,
+    target_address: AnyLinkableHash,
+    _tag: LinkTag,
+) -> ExternResult<ValidateCallbackResult> {
+    let action_hash = base_address
+        .into_action_hash()
+        .ok_or(wasm_error!(WasmErrorInner::Guest(
+            "No action hash associated with link".to_string()
+        )))?;
+    let original_record = must_get_valid_record(action_hash)?;
+    let _review: crate::Review = original_record
+        .entry()
+        .to_app_option()
+        .map_err(|e| wasm_error!(e))?
+        .ok_or(wasm_error!(WasmErrorInner::Guest(
+            "Linked action must reference an entry".to_string()
+        )))?;
+    let action_hash =
+        target_address
+            .into_action_hash()
+            .ok_or(wasm_error!(WasmErrorInner::Guest(
+                "No action hash associated with link".to_string()
+            )))?;
+    let update_record = must_get_valid_record(action_hash)?;
+    let _review: crate::Review = update_record
+        .entry()
+        .to_app_option()
+        .map_err(|e| wasm_error!(e))?
+        .ok_or(wasm_error!(WasmErrorInner::Guest(
+            "Linked action must reference an entry".to_string()
+        )))?;
+    if action.author.ne(original_record.action().author()) {
+        return Ok(ValidateCallbackResult::Invalid(
+            "Only the author of a review can link updates to it".to_string(),
+        ));
+    }
+    if let Some(error) = check_update_link(&action.author, &original_record, &update_record)? {
+        return Ok(ValidateCallbackResult::Invalid(error));
+    }
+    Ok(ValidateCallbackResult::Valid)
+}
+
+pub fn validate_delete_link_review_updates(
+    _action: DeleteLink,
+    _original_action: CreateLink,
+    _base: AnyLinkableHash,
+    _target: AnyLinkableHash,
+    _tag: LinkTag,
+) -> ExternResult<ValidateCallbackResult> {
+    Ok(ValidateCallbackResult::Invalid(
+        "ReviewUpdates links cannot be deleted".to_string(),
+    ))
+}
+
+pub fn validate_create_link_review_replies(
+    action: CreateLink,
+    base_address: AnyLinkableHash,
+    target_address: AnyLinkableHash,
+    _tag: LinkTag,
+) -> ExternResult<ValidateCallbackResult> {
+    let action_hash =
+        target_address
+            .into_action_hash()
+            .ok_or(wasm_error!(WasmErrorInner::Guest(
+                "No action hash associated with link".to_string()
+            )))?;
+    let record = must_get_valid_record(action_hash)?;
+    let review_reply: crate::ReviewReply = record
+        .entry()
+        .to_app_option()
+        .map_err(|e| wasm_error!(e))?
+        .ok_or(wasm_error!(WasmErrorInner::Guest(
+            "Linked action must reference an entry".to_string()
+        )))?;
+    if base_address
+        .into_action_hash()
+        .as_ref()
+        .ne(&Some(&review_reply.review_hash))
+    {
+        return Ok(ValidateCallbackResult::Invalid(
+            "ReviewReplies links must be created from the review that was replied to".to_string(),
+        ));
+    }
+    if action.author.ne(record.action().author()) {
+        return Ok(ValidateCallbackResult::Invalid(
+            "Only the author of a reply can link it".to_string(),
+        ));
+    }
+    Ok(ValidateCallbackResult::Valid)
+}
+
+pub fn validate_delete_link_review_replies(
+    action: DeleteLink,
+    original_action: CreateLink,
+    _base: AnyLinkableHash,
+    _target: AnyLinkableHash,
+    _tag: LinkTag,
+) -> ExternResult<ValidateCallbackResult> {
+    if action.author.ne(&original_action.author) {
+        return Ok(ValidateCallbackResult::Invalid(
+            "Only the author of a reply can unlink it".to_string(),
+        ));
+    }
+    Ok(ValidateCallbackResult::Valid)
+}
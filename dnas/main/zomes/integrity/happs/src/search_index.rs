@@ -0,0 +1,241 @@
+use hdi::prelude::*;
+
+use crate::{happ_categories::get_indexed_happ_revision, is_happ_maintainer, Happ};
+
+/// Number of characters of a token that make up the path under which it's indexed
+pub const SEARCH_PREFIX_LENGTH: usize = 3;
+/// Keywords shorter than this are not indexed from the description of the hApp
+pub const MIN_KEYWORD_LENGTH: usize = 3;
+/// Maximum number of keywords indexed from the description of a hApp
+pub const MAX_DESCRIPTION_KEYWORDS: usize = 50;
+/// Tokens are truncated to this many bytes, both when indexing and when searching, so that
+/// the link tags that hold them stay well below the 1KB limit of link tags
+pub const MAX_SEARCH_TOKEN_BYTES: usize = 128;
+
+pub const STOP_WORDS: [&str; 32] = [
+    "and", "are", "but", "can", "for", "from", "has", "have", "her", "his", "its", "not", "our",
+    "that", "the", "their", "them", "then", "there", "these", "they", "this", "those", "was",
+    "were", "what", "when", "which", "who", "will", "with", "you",
+];
+
+/// Where a token of the search index comes from
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum SearchField {
+    /// The whole normalized name of the hApp, to find exact matches
+    ExactName,
+    Name,
+    /// The first characters of a word of the name, shorter than `SEARCH_PREFIX_LENGTH`, so that queries
+    /// shorter than the prefix of the paths find the names with words that start with them
+    NamePrefix,
+    Description,
+}
+
+impl SearchField {
+    fn tag_prefix(&self) -> &'static str {
+        match self {
+            SearchField::ExactName => "x:",
+            SearchField::Name => "n:",
+            SearchField::NamePrefix => "p:",
+            SearchField::Description => "d:",
+        }
+    }
+}
+
+/// An entry of the search index: a token that is linked from the path of its prefix to the hApp
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct SearchIndexEntry {
+    pub field: SearchField,
+    pub token: String,
+}
+
+impl SearchIndexEntry {
+    /// The exact name entries are indexed under the prefix of their first word, so that queries find them
+    pub fn path(&self) -> Path {
+        search_index_path(self.token.split(' ').next().unwrap_or_default())
+    }
+
+    pub fn link_tag(&self) -> LinkTag {
+        LinkTag::new(format!("{}{}", self.field.tag_prefix(), self.token))
+    }
+
+    pub fn from_link_tag(tag: &LinkTag) -> Option<SearchIndexEntry> {
+        let tag = String::from_utf8(tag.clone().into_inner()).ok()?;
+        [
+            SearchField::ExactName,
+            SearchField::Name,
+            SearchField::NamePrefix,
+            SearchField::Description,
+        ]
+        .into_iter()
+        .find_map(|field| {
+            tag.strip_prefix(field.tag_prefix())
+                .map(|token| SearchIndexEntry {
+                    field,
+                    token: token.to_string(),
+                })
+        })
+    }
+}
+
+/// Splits the text in lowercase alphanumeric tokens, truncated to `MAX_SEARCH_TOKEN_BYTES`
+pub fn normalize_tokens(text: &str) -> Vec<String> {
+    text.split(|c: char| !c.is_alphanumeric())
+        .filter(|token| !token.is_empty())
+        .map(|token| truncate_search_token(&token.to_lowercase()))
+        .collect()
+}
+
+/// Truncates the token to at most `MAX_SEARCH_TOKEN_BYTES`, without splitting a character
+pub fn truncate_search_token(token: &str) -> String {
+    let mut end = token.len().min(MAX_SEARCH_TOKEN_BYTES);
+    while !token.is_char_boundary(end) {
+        end -= 1;
+    }
+    token[..end].to_string()
+}
+
+/// The path under which the tokens that start like the given one are indexed
+pub fn search_index_path(token: &str) -> Path {
+    let prefix: String = token.chars().take(SEARCH_PREFIX_LENGTH).collect();
+    Path::from(format!("search.{prefix}"))
+}
+
+/// All the entries of the search index for the given hApp
+pub fn happ_search_entries(happ: &Happ) -> Vec<SearchIndexEntry> {
+    let name_tokens = normalize_tokens(&happ.name);
+    let mut entries: Vec<SearchIndexEntry> = vec![];
+    if !name_tokens.is_empty() {
+        entries.push(SearchIndexEntry {
+            field: SearchField::ExactName,
+            token: truncate_search_token(&name_tokens.join(" ")),
+        });
+    }
+    for token in &name_tokens {
+        for length in 1..SEARCH_PREFIX_LENGTH.min(token.chars().count()) {
+            let entry = SearchIndexEntry {
+                field: SearchField::NamePrefix,
+                token: token.chars().take(length).collect(),
+            };
+            if !entries.contains(&entry) {
+                entries.push(entry);
+            }
+        }
+    }
+    for token in name_tokens {
+        let entry = SearchIndexEntry {
+            field: SearchField::Name,
+            token,
+        };
+        if !entries.contains(&entry) {
+            entries.push(entry);
+        }
+    }
+    let mut keywords = 0;
+    for token in normalize_tokens(&happ.description) {
+        if keywords == MAX_DESCRIPTION_KEYWORDS {
+            break;
+        }
+        if token.chars().count() < MIN_KEYWORD_LENGTH || STOP_WORDS.contains(&token.as_str()) {
+            continue;
+        }
+        let entry = SearchIndexEntry {
+            field: SearchField::Description,
+            token,
+        };
+        if !entries.contains(&entry) {
+            entries.push(entry);
+            keywords += 1;
+        }
+    }
+    entries
+}
+
+pub fn validate_create_link_search_index(
+    action: CreateLink,
+    base_address: AnyLinkableHash,
+    target_address: AnyLinkableHash,
+    tag: LinkTag,
+) -> ExternResult<ValidateCallbackResult> {
+    let Some(entry) = SearchIndexEntry::from_link_tag(&tag) else {
+        return Ok(ValidateCallbackResult::Invalid(
+            "The tag of a SearchIndex link must be a search index entry".to_string(),
+        ));
+    };
+    if entry.token.len() > MAX_SEARCH_TOKEN_BYTES {
+        return Ok(ValidateCallbackResult::Invalid(format!(
+            "The tokens of the search index can't be longer than {MAX_SEARCH_TOKEN_BYTES} bytes"
+        )));
+    }
+    if entry.field == SearchField::NamePrefix && entry.token.chars().count() >= SEARCH_PREFIX_LENGTH
+    {
+        return Ok(ValidateCallbackResult::Invalid(
+            "The name prefixes of the search index must be shorter than the prefixes of the paths"
+                .to_string(),
+        ));
+    }
+    if base_address.ne(&entry.path().path_entry_hash()?.into()) {
+        return Ok(ValidateCallbackResult::Invalid(
+            "SearchIndex links must be created from the path of the prefix of their token"
+                .to_string(),
+        ));
+    }
+    let happ_hash = target_address
+        .into_action_hash()
+        .ok_or(wasm_error!(WasmErrorInner::Guest(
+            "No action hash associated with link".to_string()
+        )))?;
+    let record = must_get_valid_record(happ_hash.clone())?;
+    let _happ: crate::Happ = record
+        .entry()
+        .to_app_option()
+        .map_err(|e| wasm_error!(e))?
+        .ok_or(wasm_error!(WasmErrorInner::Guest(
+            "Linked action must reference an entry".to_string()
+        )))?;
+    if !is_happ_maintainer(
+        &action.author,
+        &action.prev_action,
+        &action.timestamp,
+        &happ_hash,
+    )? {
+        return Ok(ValidateCallbackResult::Invalid(
+            "Only the maintainers of a hApp can index it".to_string(),
+        ));
+    }
+    let Some(happ) = get_indexed_happ_revision(&action, &happ_hash)? else {
+        return Ok(ValidateCallbackResult::Invalid(
+            "A hApp can only be indexed after creating or updating it".to_string(),
+        ));
+    };
+    if !happ_search_entries(&happ).contains(&entry) {
+        return Ok(ValidateCallbackResult::Invalid(
+            "A hApp can only be indexed under the search tokens of its latest revision".to_string(),
+        ));
+    }
+    Ok(ValidateCallbackResult::Valid)
+}
+
+pub fn validate_delete_link_search_index(
+    action: DeleteLink,
+    _original_action: CreateLink,
+    _base: AnyLinkableHash,
+    target: AnyLinkableHash,
+    _tag: LinkTag,
+) -> ExternResult<ValidateCallbackResult> {
+    let happ_hash = target
+        .into_action_hash()
+        .ok_or(wasm_error!(WasmErrorInner::Guest(
+            "No action hash associated with link".to_string()
+        )))?;
+    if !is_happ_maintainer(
+        &action.author,
+        &action.prev_action,
+        &action.timestamp,
+        &happ_hash,
+    )? {
+        return Ok(ValidateCallbackResult::Invalid(
+            "Only the maintainers of a hApp can remove it from the search index".to_string(),
+        ));
+    }
+    Ok(ValidateCallbackResult::Valid)
+}
@@ -0,0 +1,41 @@
+use hdi::prelude::*;
+
+/// Checks that a link from the original record of an entry to one of its updates is legit:
+/// the target must be an update whose chain of updates leads back to the original record,
+/// and the link must be authored by the author of the update
+///
+/// Whether the author is allowed to update the record at all is checked by the caller
+///
+/// Returns the reason why the link is invalid, if it is
+pub fn check_update_link(
+    link_author: &AgentPubKey,
+    original_record: &Record,
+    update_record: &Record,
+) -> ExternResult<Option<String>> {
+    let original_action_hash = original_record.action_address();
+    let Action::Update(update) = update_record.action() else {
+        return Ok(Some(
+            "The target of an update link must be an update action".to_string(),
+        ));
+    };
+    if link_author.ne(&update.author) {
+        return Ok(Some(
+            "The author of an update link must be the author of the update".to_string(),
+        ));
+    }
+
+    // Every update points to the revision it updates, walk back until we reach the original record
+    let mut previous_action_hash = update.original_action_address.clone();
+    loop {
+        if previous_action_hash.eq(original_action_hash) {
+            return Ok(None);
+        }
+        let previous_record = must_get_valid_record(previous_action_hash)?;
+        let Action::Update(previous_update) = previous_record.action() else {
+            return Ok(Some(
+                "The target of an update link must be an update of the original record".to_string(),
+            ));
+        };
+        previous_action_hash = previous_update.original_action_address.clone();
+    }
+}
@@ -0,0 +1,52 @@
+import { msg } from '@lit/localize';
+
+import { HappCategory } from './types.js';
+
+export const HAPP_CATEGORIES: Array<HappCategory> = [
+	'Productivity',
+	'Social',
+	'Communication',
+	'Finance',
+	'Games',
+	'Education',
+	'DeveloperTools',
+	'Media',
+	'Other',
+];
+
+export const MAX_HAPP_TAGS = 10;
+
+export function happCategoryLabel(category: HappCategory): string {
+	switch (category) {
+		case 'Productivity':
+			return msg('Productivity');
+		case 'Social':
+			return msg('Social');
+		case 'Communication':
+			return msg('Communication');
+		case 'Finance':
+			return msg('Finance');
+		case 'Games':
+			return msg('Games');
+		case 'Education':
+			return msg('Education');
+		case 'DeveloperTools':
+			return msg('Developer tools');
+		case 'Media':
+			return msg('Media');
+		case 'Other':
+			return msg('Other');
+	}
+}
+
+/**
+ * Parses the comma separated tags typed by the publisher into the normalized form that the zome accepts
+ */
+export function parseHappTags(input: string | undefined): Array<string> {
+	if (!input) return [];
+	const tags = input
+		.split(',')
+		.map(tag => tag.trim().toLowerCase().replace(/\s+/g, '-'))
+		.filter(tag => tag.length > 0);
+	return Array.from(new Set(tags));
+}
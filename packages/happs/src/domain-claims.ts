@@ -0,0 +1,64 @@
+import { encodeHashToBase64 } from '@holochain/client';
+import { EntryRecord } from '@darksoil-studio/holochain-utils';
+
+import { DomainClaim } from './types.js';
+
+/** Path of the file in which domains list the signatures of the publishers that control them */
+export const DOMAIN_CLAIM_WELL_KNOWN_PATH =
+	'/.well-known/holochain-happs-publisher.json';
+
+export interface DomainClaimWellKnownFile {
+	publishers: Array<{
+		agent_pub_key: string;
+		signature: string;
+	}>;
+}
+
+function encodeSignature(signature: Uint8Array): string {
+	return btoa(String.fromCharCode(...signature));
+}
+
+/**
+ * Builds the entry of the `.well-known` file that the domain of the claim must serve for it to be verified
+ */
+export function domainClaimWellKnownEntry(
+	domainClaim: EntryRecord<DomainClaim>,
+): DomainClaimWellKnownFile['publishers'][number] {
+	return {
+		agent_pub_key: encodeHashToBase64(domainClaim.action.author),
+		signature: encodeSignature(domainClaim.entry.signature),
+	};
+}
+
+async function fetchWellKnownFile(domain: string): Promise<unknown> {
+	const response = await fetch(`https://${domain}${DOMAIN_CLAIM_WELL_KNOWN_PATH}`);
+	if (!response.ok) return undefined;
+	return response.json();
+}
+
+/**
+ * Checks that the domain of the claim serves the signature of the claim in its `.well-known` file
+ *
+ * The signature itself was already validated by the DHT, so this only proves that the publisher controls the domain
+ */
+export async function verifyDomainClaim(
+	domainClaim: EntryRecord<DomainClaim>,
+	fetchFile: (domain: string) => Promise<unknown> = fetchWellKnownFile,
+): Promise<boolean> {
+	let file: Partial<DomainClaimWellKnownFile> | undefined;
+	try {
+		file = (await fetchFile(domainClaim.entry.domain)) as
+			| Partial<DomainClaimWellKnownFile>
+			| undefined;
+	} catch (e) {
+		return false;
+	}
+	if (!file || !Array.isArray(file.publishers)) return false;
+
+	const expected = domainClaimWellKnownEntry(domainClaim);
+	return file.publishers.some(
+		publisher =>
+			publisher.agent_pub_key === expected.agent_pub_key &&
+			publisher.signature === expected.signature,
+	);
+}
@@ -7,17 +7,20 @@ import '@shoelace-style/shoelace/dist/components/icon/icon.js';
 import '@shoelace-style/shoelace/dist/components/spinner/spinner.js';
 import { wrapPathInSvg } from '@darksoil-studio/holochain-elements';
 import '@darksoil-studio/holochain-elements/dist/elements/display-error.js';
-import { SignalWatcher, joinAsync, joinAsyncMap } from '@darksoil-studio/holochain-signals';
-import { mapValues, pickBy } from '@darksoil-studio/holochain-utils';
-import { LitElement, css, html } from 'lit';
+import { SignalWatcher, joinAsyncMap } from '@darksoil-studio/holochain-signals';
+import { mapValues, pickBy, slice } from '@darksoil-studio/holochain-utils';
+import { LitElement, PropertyValues, css, html } from 'lit';
 import { customElement, property, state } from 'lit/decorators.js';
 import { styleMap } from 'lit/directives/style-map.js';
 
 import { happsStoreContext } from '../context.js';
 import { HappsStore } from '../happs-store.js';
 import { happsStyles } from '../styles.js';
+import { HappsPageCursor, HappsSortKey } from '../types.js';
 import './manage-happ.js';
 
+const PAGE_SIZE = 20;
+
 /**
  * @element all-happs
  */
@@ -30,9 +33,95 @@ export class AllHapps extends SignalWatcher(LitElement) {
 	@consume({ context: happsStoreContext, subscribe: true })
 	happsStore!: HappsStore;
 
+	/**
+	 * The order in which to show the hApps
+	 */
+	@property({ attribute: 'sort-key' })
+	sortKey: HappsSortKey = 'LatestReleaseTime';
+
 	@state()
 	layout: 'rows' | 'single-column' = 'rows';
 
+	/**
+	 * @internal
+	 */
+	@state()
+	happHashes: Array<ActionHash> = [];
+
+	/**
+	 * @internal
+	 */
+	@state()
+	nextCursor: HappsPageCursor | undefined;
+
+	/**
+	 * @internal
+	 */
+	@state()
+	allPagesLoaded = false;
+
+	/**
+	 * @internal
+	 */
+	@state()
+	loadingPage = false;
+
+	/**
+	 * @internal
+	 */
+	@state()
+	pageError: unknown;
+
+	willUpdate(changedProperties: PropertyValues<this>) {
+		if (changedProperties.has('sortKey')) {
+			this.happHashes = [];
+			this.nextCursor = undefined;
+			this.allPagesLoaded = false;
+			this.pageError = undefined;
+			this.loadNextPage();
+		}
+	}
+
+	updated() {
+		// Keep loading pages until the list overflows, so that it can be scrolled
+		const scrollable = this.shadowRoot?.querySelector('.flex-scrollable-y');
+		if (!scrollable || scrollable.scrollHeight <= scrollable.clientHeight) {
+			this.loadNextPage();
+		}
+	}
+
+	async loadNextPage() {
+		if (this.loadingPage || this.allPagesLoaded || this.pageError) return;
+		this.loadingPage = true;
+		try {
+			const page = await this.happsStore.client.getHappsPage(
+				this.sortKey,
+				PAGE_SIZE,
+				this.nextCursor,
+			);
+			this.happHashes = [
+				...this.happHashes,
+				...page.happs.map(happ => happ.original_happ_hash),
+			];
+			this.nextCursor = page.next_cursor;
+			this.allPagesLoaded = !page.next_cursor;
+		} catch (e) {
+			console.error(e);
+			this.pageError = e;
+		}
+		this.loadingPage = false;
+	}
+
+	onScroll(e: Event) {
+		const scrollable = e.target as HTMLElement;
+		if (
+			scrollable.scrollTop + scrollable.clientHeight >=
+			scrollable.scrollHeight - 200
+		) {
+			this.loadNextPage();
+		}
+	}
+
 	firstUpdated() {
 		new ResizeController(this, {
 			callback: () => {
@@ -59,7 +148,7 @@ export class AllHapps extends SignalWatcher(LitElement) {
 		return html`
 			<div class="flex-scrollable-parent">
 				<div class="flex-scrollable-container">
-					<div class="flex-scrollable-y">
+					<div class="flex-scrollable-y" @scroll=${this.onScroll}>
 						<div
 							class="row"
 							style="margin: 16px; gap: 16px; flex: 1; flex-wrap: wrap"
@@ -75,6 +164,11 @@ export class AllHapps extends SignalWatcher(LitElement) {
 									></manage-happ>`,
 							)}
 						</div>
+						${this.loadingPage
+							? html`<div class="row center-content" style="margin: 16px">
+									<sl-spinner style="font-size: 2rem;"></sl-spinner>
+								</div>`
+							: html``}
 					</div>
 				</div>
 			</div>
@@ -82,21 +176,22 @@ export class AllHapps extends SignalWatcher(LitElement) {
 	}
 
 	happsWithVersions() {
-		const allHapps = this.happsStore.allHapps.get();
-		if (allHapps.status !== 'completed') return allHapps;
+		if (this.pageError)
+			return { status: 'error' as const, error: this.pageError };
+		if (this.happHashes.length === 0 && !this.allPagesLoaded)
+			return { status: 'pending' as const };
+
+		const loadedHapps = slice(this.happsStore.happs, this.happHashes);
 
-		const happsVersionsAndUnpublishedLinks = joinAsyncMap(
-			mapValues(allHapps.value, happ =>
-				joinAsync([happ.happReleases.get(), happ.unpublishedLinks.get()]),
-			),
+		// Unpublished Happs are already left out of the pages by the zome
+		const happsVersions = joinAsyncMap(
+			mapValues(loadedHapps, happ => happ.happReleases.get()),
 		);
-		if (happsVersionsAndUnpublishedLinks.status !== 'completed')
-			return happsVersionsAndUnpublishedLinks;
+		if (happsVersions.status !== 'completed') return happsVersions;
 
 		const happsWithVersions = pickBy(
-			happsVersionsAndUnpublishedLinks.value,
-			([versions, unpublishedLinks]) =>
-				versions.size > 0 && unpublishedLinks.length == 0,
+			happsVersions.value,
+			versions => versions.size > 0,
 		);
 
 		return {
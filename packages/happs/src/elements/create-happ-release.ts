@@ -16,6 +16,9 @@ import '@shoelace-style/shoelace/dist/components/card/card.js';
 import '@shoelace-style/shoelace/dist/components/icon-button/icon-button.js';
 import '@shoelace-style/shoelace/dist/components/icon/icon.js';
 import '@shoelace-style/shoelace/dist/components/input/input.js';
+import '@shoelace-style/shoelace/dist/components/option/option.js';
+import '@shoelace-style/shoelace/dist/components/select/select.js';
+import SlSelect from '@shoelace-style/shoelace/dist/components/select/select.js';
 import '@shoelace-style/shoelace/dist/components/textarea/textarea.js';
 import {
 	hashProperty,
@@ -35,7 +38,7 @@ import { repeat } from 'lit/directives/repeat.js';
 import { happsStoreContext } from '../context.js';
 import { HappsStore } from '../happs-store.js';
 import { happsStyles } from '../styles.js';
-import { HappRelease } from '../types.js';
+import { HappRelease, ReleaseChannel } from '../types.js';
 
 /**
  * @element create-happ-release
@@ -68,7 +71,15 @@ export class CreateHappRelease extends SignalWatcher(LitElement) {
 	@query('#create-form')
 	form!: HTMLFormElement;
 
-	async createHappRelease(fields: Partial<HappRelease>) {
+	/**
+	 * @internal
+	 */
+	@state()
+	channelType: ReleaseChannel['type'] = 'Stable';
+
+	async createHappRelease(
+		fields: Partial<HappRelease> & { custom_channel?: string },
+	) {
 		if (this.happHash === undefined)
 			throw new Error(
 				'Cannot create a new hApp Release without its happ_hash field',
@@ -79,6 +90,10 @@ export class CreateHappRelease extends SignalWatcher(LitElement) {
 			version: fields.version!,
 			changes: fields.changes!,
 			web_happ_bundle_hash: fields.web_happ_bundle_hash!,
+			channel:
+				this.channelType === 'Custom'
+					? { type: 'Custom', value: fields.custom_channel!.trim() }
+					: { type: this.channelType },
 		};
 
 		try {
@@ -97,6 +112,7 @@ export class CreateHappRelease extends SignalWatcher(LitElement) {
 			);
 
 			this.form.reset();
+			this.channelType = 'Stable';
 		} catch (e: unknown) {
 			console.error(e);
 			notifyError(msg('Error creating the happ version'));
@@ -119,7 +135,41 @@ export class CreateHappRelease extends SignalWatcher(LitElement) {
 					accepted-files=".webhapp"
 					required
 				></upload-files>
-				<sl-input name="version" .label=${msg('Version')} required></sl-input>
+				<sl-input
+					name="version"
+					.label=${msg('Version')}
+					.helpText=${msg(
+						'Must follow semantic versioning (e.g. 1.0.0) and be greater than all the previous releases.',
+					)}
+					placeholder="1.0.0"
+					required
+				></sl-input>
+				<sl-select
+					.label=${msg('Channel')}
+					.helpText=${msg(
+						'Users only get the releases of the channel they follow.',
+					)}
+					.value=${this.channelType}
+					@sl-change=${(e: CustomEvent) => {
+						this.channelType = (e.target as SlSelect)
+							.value as ReleaseChannel['type'];
+					}}
+				>
+					<sl-option value="Stable">${msg('Stable')}</sl-option>
+					<sl-option value="Beta">${msg('Beta')}</sl-option>
+					<sl-option value="Nightly">${msg('Nightly')}</sl-option>
+					<sl-option value="Custom">${msg('Custom')}</sl-option>
+				</sl-select>
+				${this.channelType === 'Custom'
+					? html`<sl-input
+							name="custom_channel"
+							.label=${msg('Channel name')}
+							.helpText=${msg('Lowercase letters, digits and dashes.')}
+							pattern="[a-z0-9-]+"
+							maxlength="32"
+							required
+						></sl-input>`
+					: html``}
 				<sl-textarea
 					name="changes"
 					.label=${msg('Changes')}
@@ -7,7 +7,7 @@ import {
 	Record,
 } from '@holochain/client';
 import { consume } from '@lit/context';
-import { localized, msg } from '@lit/localize';
+import { localized, msg, str } from '@lit/localize';
 import { mdiAlertCircleOutline, mdiDelete } from '@mdi/js';
 import '@shoelace-style/shoelace/dist/components/alert/alert.js';
 import SlAlert from '@shoelace-style/shoelace/dist/components/alert/alert.js';
@@ -16,6 +16,8 @@ import '@shoelace-style/shoelace/dist/components/card/card.js';
 import '@shoelace-style/shoelace/dist/components/icon-button/icon-button.js';
 import '@shoelace-style/shoelace/dist/components/icon/icon.js';
 import '@shoelace-style/shoelace/dist/components/input/input.js';
+import '@shoelace-style/shoelace/dist/components/option/option.js';
+import '@shoelace-style/shoelace/dist/components/select/select.js';
 import '@shoelace-style/shoelace/dist/components/textarea/textarea.js';
 import {
 	hashProperty,
@@ -31,10 +33,17 @@ import { EntryRecord } from '@darksoil-studio/holochain-utils';
 import { LitElement, html } from 'lit';
 import { customElement, property, query, state } from 'lit/decorators.js';
 
+import {
+	HAPP_CATEGORIES,
+	MAX_HAPP_TAGS,
+	happCategoryLabel,
+	parseHappTags,
+} from '../categories.js';
 import { happsStoreContext } from '../context.js';
 import { HappsStore } from '../happs-store.js';
 import { happsStyles } from '../styles.js';
-import { Happ } from '../types.js';
+import { Happ, HappCategory } from '../types.js';
+import { validationErrorMessage } from '../utils.js';
 
 /**
  * @element create-happ
@@ -55,21 +64,30 @@ export class CreateHapp extends SignalWatcher(LitElement) {
 	@state()
 	committing = false;
 
+	/**
+	 * @internal
+	 */
+	@state()
+	validationError: string | undefined;
+
 	/**
 	 * @internal
 	 */
 	@query('#create-form')
 	form!: HTMLFormElement;
 
-	async createHapp(fields: Partial<Happ>) {
+	async createHapp(fields: any) {
 		const happ: Happ = {
 			name: fields.name!,
 			description: fields.description!,
 			icon: fields.icon!,
+			category: fields.category as HappCategory,
+			tags: parseHappTags(fields.tags),
 		};
 
 		try {
 			this.committing = true;
+			this.validationError = undefined;
 			const record: EntryRecord<Happ> =
 				await this.happsStore.client.createHapp(happ);
 
@@ -86,7 +104,8 @@ export class CreateHapp extends SignalWatcher(LitElement) {
 			this.form.reset();
 		} catch (e: unknown) {
 			console.error(e);
-			notifyError(msg('Error creating the happ'));
+			this.validationError = validationErrorMessage(e);
+			if (!this.validationError) notifyError(msg('Error creating the happ'));
 		}
 		this.committing = false;
 	}
@@ -109,12 +128,48 @@ export class CreateHapp extends SignalWatcher(LitElement) {
 						required
 					></upload-files>
 				</div>
-				<sl-input name="name" .label=${msg('Name')} required></sl-input>
+				<sl-input
+					name="name"
+					.label=${msg('Name')}
+					maxlength="64"
+					required
+				></sl-input>
 				<sl-textarea
 					name="description"
 					.label=${msg('Description')}
+					maxlength="5000"
 					required
 				></sl-textarea>
+				<sl-select
+					name="category"
+					.label=${msg('Category')}
+					value="Other"
+					required
+				>
+					${HAPP_CATEGORIES.map(
+						category =>
+							html`<sl-option value=${category}
+								>${happCategoryLabel(category)}</sl-option
+							>`,
+					)}
+				</sl-select>
+				<sl-input
+					name="tags"
+					.label=${msg('Tags')}
+					.helpText=${msg(
+						str`Separated by commas, up to ${MAX_HAPP_TAGS} tags.`,
+					)}
+				></sl-input>
+
+				${this.validationError
+					? html`<sl-alert variant="danger" open>
+							<sl-icon
+								slot="icon"
+								.src=${wrapPathInSvg(mdiAlertCircleOutline)}
+							></sl-icon>
+							${this.validationError}
+						</sl-alert>`
+					: html``}
 
 				<sl-button variant="primary" type="submit" .loading=${this.committing}
 					>${msg('Create hApp')}</sl-button
@@ -73,6 +73,7 @@ export class EditHappRelease extends SignalWatcher(LitElement) {
 			version: currentRecord.entry.version!,
 			changes: fields.changes!,
 			web_happ_bundle_hash: currentRecord.entry.web_happ_bundle_hash!,
+			channel: currentRecord.entry.channel ?? { type: 'Stable' },
 		};
 
 		try {
@@ -1,7 +1,7 @@
 import '@darksoil-studio/file-storage-zome/dist/elements/upload-files.js';
 import { ActionHash, AgentPubKey, EntryHash, Record } from '@holochain/client';
 import { consume } from '@lit/context';
-import { localized, msg } from '@lit/localize';
+import { localized, msg, str } from '@lit/localize';
 import { mdiAlertCircleOutline, mdiDelete } from '@mdi/js';
 import '@shoelace-style/shoelace/dist/components/alert/alert.js';
 import SlAlert from '@shoelace-style/shoelace/dist/components/alert/alert.js';
@@ -10,6 +10,8 @@ import '@shoelace-style/shoelace/dist/components/card/card.js';
 import '@shoelace-style/shoelace/dist/components/icon-button/icon-button.js';
 import '@shoelace-style/shoelace/dist/components/icon/icon.js';
 import '@shoelace-style/shoelace/dist/components/input/input.js';
+import '@shoelace-style/shoelace/dist/components/option/option.js';
+import '@shoelace-style/shoelace/dist/components/select/select.js';
 import '@shoelace-style/shoelace/dist/components/textarea/textarea.js';
 import {
 	hashProperty,
@@ -25,10 +27,18 @@ import { LitElement, html } from 'lit';
 import { customElement, property, state } from 'lit/decorators.js';
 import { repeat } from 'lit/directives/repeat.js';
 
+import {
+	HAPP_CATEGORIES,
+	MAX_HAPP_TAGS,
+	happCategoryLabel,
+	parseHappTags,
+} from '../categories.js';
 import { happsStoreContext } from '../context.js';
 import { HappsStore } from '../happs-store.js';
 import { happsStyles } from '../styles.js';
-import { Happ } from '../types.js';
+import { Happ, HappCategory } from '../types.js';
+import { UpdateConflictError } from '../update-conflict.js';
+import { validationErrorMessage } from '../utils.js';
 
 /**
  * @element edit-happ
@@ -55,6 +65,21 @@ export class EditHapp extends SignalWatcher(LitElement) {
 	@state()
 	committing = false;
 
+	/**
+	 * @internal
+	 */
+	@state()
+	validationError: string | undefined;
+
+	/**
+	 * @internal
+	 * Set when the Happ was updated by someone else while it was being edited
+	 */
+	@state()
+	conflict:
+		| { currentHead: EntryRecord<Happ>; pendingHapp: Happ }
+		| undefined;
+
 	async firstUpdated() {
 		const currentRecord = await toPromise(
 			this.happsStore.happs.get(this.happHash).latestVersion,
@@ -64,15 +89,23 @@ export class EditHapp extends SignalWatcher(LitElement) {
 		});
 	}
 
-	async updateHapp(currentRecord: EntryRecord<Happ>, fields: Partial<Happ>) {
+	async updateHapp(currentRecord: EntryRecord<Happ>, fields: any) {
 		const happ: Happ = {
 			name: fields.name!,
 			description: fields.description!,
 			icon: fields.icon!,
+			category: fields.category as HappCategory,
+			tags: parseHappTags(fields.tags),
 		};
 
+		await this.commitUpdate(currentRecord, happ);
+	}
+
+	async commitUpdate(currentRecord: EntryRecord<Happ>, happ: Happ) {
 		try {
 			this.committing = true;
+			this.validationError = undefined;
+			this.conflict = undefined;
 			const updateRecord = await this.happsStore.client.updateHapp(
 				this.happHash,
 				currentRecord.actionHash,
@@ -92,12 +125,58 @@ export class EditHapp extends SignalWatcher(LitElement) {
 			);
 		} catch (e: unknown) {
 			console.error(e);
-			notifyError(msg('Error updating the happ'));
+			if (e instanceof UpdateConflictError) {
+				this.conflict = { currentHead: e.currentHead, pendingHapp: happ };
+			} else {
+				this.validationError = validationErrorMessage(e);
+				if (!this.validationError)
+					notifyError(msg('Error updating the happ'));
+			}
 		}
 
 		this.committing = false;
 	}
 
+	renderConflict() {
+		if (!this.conflict) return html``;
+		const { currentHead, pendingHapp } = this.conflict;
+
+		return html`<sl-alert variant="warning" open>
+			<sl-icon slot="icon" .src=${wrapPathInSvg(mdiAlertCircleOutline)}>
+			</sl-icon>
+			<div class="column" style="gap: 8px">
+				<span
+					>${msg(
+						'This hApp was edited while you were editing it. Its latest revision is:',
+					)}</span
+				>
+				<span><strong>${currentHead.entry.name}</strong></span>
+				<span style="white-space: pre-line"
+					>${currentHead.entry.description}</span
+				>
+				<div class="row" style="gap: 8px">
+					<sl-button
+						size="small"
+						@click=${() => {
+							this.conflict = undefined;
+							(
+								this.shadowRoot?.getElementById('form') as HTMLFormElement
+							).reset();
+						}}
+						>${msg('Discard my changes')}</sl-button
+					>
+					<sl-button
+						size="small"
+						variant="primary"
+						.loading=${this.committing}
+						@click=${() => this.commitUpdate(currentHead, pendingHapp)}
+						>${msg('Apply my changes on top of it')}</sl-button
+					>
+				</div>
+			</div>
+		</sl-alert>`;
+	}
+
 	renderEditForm(currentRecord: EntryRecord<Happ>) {
 		return html` <sl-card style="flex: 1">
 			<form
@@ -110,15 +189,38 @@ export class EditHapp extends SignalWatcher(LitElement) {
 				<sl-input
 					name="name"
 					.label=${msg('Name')}
+					maxlength="64"
 					required
 					.defaultValue=${currentRecord.entry.name}
 				></sl-input>
 				<sl-textarea
 					name="description"
 					.label=${msg('Description')}
+					maxlength="5000"
 					required
 					.defaultValue=${currentRecord.entry.description}
 				></sl-textarea>
+				<sl-select
+					name="category"
+					.label=${msg('Category')}
+					required
+					.defaultValue=${currentRecord.entry.category ?? 'Other'}
+				>
+					${HAPP_CATEGORIES.map(
+						category =>
+							html`<sl-option value=${category}
+								>${happCategoryLabel(category)}</sl-option
+							>`,
+					)}
+				</sl-select>
+				<sl-input
+					name="tags"
+					.label=${msg('Tags')}
+					.helpText=${msg(
+						str`Separated by commas, up to ${MAX_HAPP_TAGS} tags.`,
+					)}
+					.defaultValue=${(currentRecord.entry.tags ?? []).join(', ')}
+				></sl-input>
 				<upload-files
 					name="icon"
 					one-file
@@ -127,6 +229,17 @@ export class EditHapp extends SignalWatcher(LitElement) {
 					.defaultValue=${currentRecord.entry.icon}
 				></upload-files>
 
+				${this.renderConflict()}
+				${this.validationError
+					? html`<sl-alert variant="danger" open>
+							<sl-icon
+								slot="icon"
+								.src=${wrapPathInSvg(mdiAlertCircleOutline)}
+							></sl-icon>
+							${this.validationError}
+						</sl-alert>`
+					: html``}
+
 				<div class="row" style="gap: 8px;">
 					<sl-button
 						@click=${() =>
@@ -16,6 +16,7 @@ import SlButton from '@shoelace-style/shoelace/dist/components/button/button.js'
 import '@shoelace-style/shoelace/dist/components/card/card.js';
 import '@shoelace-style/shoelace/dist/components/divider/divider.js';
 import '@shoelace-style/shoelace/dist/components/icon-button/icon-button.js';
+import '@shoelace-style/shoelace/dist/components/icon/icon.js';
 import '@shoelace-style/shoelace/dist/components/skeleton/skeleton.js';
 import '@shoelace-style/shoelace/dist/components/spinner/spinner.js';
 import '@shoelace-style/shoelace/dist/components/tag/tag.js';
@@ -29,9 +30,10 @@ import {
 import '@darksoil-studio/holochain-elements/dist/elements/display-error.js';
 import { SignalWatcher } from '@darksoil-studio/holochain-signals';
 import { EntryRecord } from '@darksoil-studio/holochain-utils';
-import { LitElement, html } from 'lit';
+import { LitElement, PropertyValues, html } from 'lit';
 import { customElement, property, state } from 'lit/decorators.js';
 
+import { happCategoryLabel } from '../categories.js';
 import { happsStoreContext } from '../context.js';
 import { HappsStore } from '../happs-store.js';
 import { happsStyles } from '../styles.js';
@@ -66,6 +68,25 @@ export class HappDetail extends SignalWatcher(LitElement) {
 	@state()
 	_editing = false;
 
+	/**
+	 * @internal
+	 */
+	@state()
+	_conflictingHeads: Array<Record> = [];
+
+	willUpdate(changedValues: PropertyValues<this>) {
+		if (changedValues.has('happHash')) this.loadHeads();
+	}
+
+	async loadHeads() {
+		try {
+			const heads = await this.happsStore.client.getHappHeads(this.happHash);
+			this._conflictingHeads = heads ? heads.conflicting_heads : [];
+		} catch (e: unknown) {
+			console.error(e);
+		}
+	}
+
 	async deleteHapp() {
 		try {
 			await this.happsStore.client.deleteHapp(this.happHash);
@@ -224,9 +245,21 @@ export class HappDetail extends SignalWatcher(LitElement) {
 		}
 	}
 
+	renderDivergentEdits() {
+		if (this._conflictingHeads.length === 0) return html``;
+		return html`<sl-alert variant="warning" open>
+			<sl-icon slot="icon" .src=${wrapPathInSvg(mdiAlertCircleOutline)}>
+			</sl-icon>
+			${msg(
+				'This listing has divergent edits: it was edited concurrently, and the latest edit is shown.',
+			)}
+		</sl-alert>`;
+	}
+
 	renderDetail(entryRecord: EntryRecord<Happ>) {
 		return html`
 			<div class="column" style="gap: 16px;">
+				${this.renderDivergentEdits()}
 				<sl-card>
 					<div class="column" style="gap: 16px; flex: 1">
 						<div class="row" style="gap: 16px; flex: 1">
@@ -248,6 +281,17 @@ export class HappDetail extends SignalWatcher(LitElement) {
 						<span style="white-space: pre-line"
 							>${entryRecord.entry.description}</span
 						>
+
+						<div class="row" style="gap: 8px; flex-wrap: wrap">
+							<sl-tag variant="primary"
+								>${happCategoryLabel(
+									entryRecord.entry.category ?? 'Other',
+								)}</sl-tag
+							>
+							${(entryRecord.entry.tags ?? []).map(
+								tag => html`<sl-tag variant="neutral">${tag}</sl-tag>`,
+							)}
+						</div>
 					</div>
 				</sl-card>
 
@@ -300,6 +344,7 @@ export class HappDetail extends SignalWatcher(LitElement) {
 						.happHash=${this.happHash}
 						@happ-updated=${async () => {
 							this._editing = false;
+							this.loadHeads();
 						}}
 						@edit-canceled=${() => {
 							this._editing = false;
@@ -3,9 +3,14 @@ import {
 	fileStorageClientContext,
 } from '@darksoil-studio/file-storage-zome';
 import '@darksoil-studio/file-storage-zome/dist/elements/show-image.js';
-import { ActionHash, EntryHash, Record } from '@holochain/client';
+import {
+	ActionHash,
+	EntryHash,
+	Record,
+	encodeHashToBase64,
+} from '@holochain/client';
 import { consume } from '@lit/context';
-import { localized, msg } from '@lit/localize';
+import { localized, msg, str } from '@lit/localize';
 import {
 	mdiAlertCircleOutline,
 	mdiDelete,
@@ -16,25 +21,31 @@ import '@shoelace-style/shoelace/dist/components/alert/alert.js';
 import '@shoelace-style/shoelace/dist/components/button/button.js';
 import '@shoelace-style/shoelace/dist/components/button/button.js';
 import '@shoelace-style/shoelace/dist/components/card/card.js';
+import '@shoelace-style/shoelace/dist/components/dialog/dialog.js';
 import '@shoelace-style/shoelace/dist/components/icon/icon.js';
+import '@shoelace-style/shoelace/dist/components/option/option.js';
+import '@shoelace-style/shoelace/dist/components/select/select.js';
 import '@shoelace-style/shoelace/dist/components/spinner/spinner.js';
+import '@shoelace-style/shoelace/dist/components/tag/tag.js';
+import '@shoelace-style/shoelace/dist/components/textarea/textarea.js';
 import {
 	hashProperty,
 	notifyError,
+	onSubmit,
 	sharedStyles,
 	wrapPathInSvg,
 } from '@darksoil-studio/holochain-elements';
 import '@darksoil-studio/holochain-elements/dist/elements/display-error.js';
-import { SignalWatcher } from '@darksoil-studio/holochain-signals';
-import { EntryRecord } from '@darksoil-studio/holochain-utils';
+import { SignalWatcher, joinAsyncMap } from '@darksoil-studio/holochain-signals';
+import { EntryRecord, mapValues } from '@darksoil-studio/holochain-utils';
 import { LitElement, html } from 'lit';
 import { customElement, property, state } from 'lit/decorators.js';
 
 import { happsStoreContext } from '../context.js';
 import { HappsStore } from '../happs-store.js';
 import { happsStyles } from '../styles.js';
-import { HappRelease } from '../types.js';
-import { triggerFileDownload } from '../utils.js';
+import { HappRelease, ReleaseChannel, YankSeverity } from '../types.js';
+import { triggerFileDownload, validationErrorMessage } from '../utils.js';
 import './edit-happ-release.js';
 
 /**
@@ -68,13 +79,138 @@ export class HappReleaseDetail extends SignalWatcher(LitElement) {
 	@state()
 	downloading = false;
 
+	@state()
+	yanking = false;
+
+	async yankHappRelease(fields: { reason: string; severity: string }) {
+		try {
+			this.yanking = true;
+			await this.happsStore.client.yankHappRelease(
+				this.happReleaseHash,
+				fields.reason,
+				fields.severity ? (fields.severity as YankSeverity) : undefined,
+			);
+			this.shadowRoot?.querySelector('sl-dialog')!.hide();
+		} catch (e) {
+			notifyError(
+				validationErrorMessage(e) ?? msg('Error yanking the release.'),
+			);
+			console.error(e);
+		}
+		this.yanking = false;
+	}
+
+	renderYankDialog() {
+		return html`<sl-dialog .label=${msg('Yank Release')}>
+			<form
+				id="yank-form"
+				class="column"
+				style="gap: 16px"
+				${onSubmit(fields => this.yankHappRelease(fields))}
+			>
+				<span
+					>${msg(
+						'Yanked releases stay available, but are not offered as the latest release anymore.',
+					)}</span
+				>
+				<sl-textarea
+					name="reason"
+					.label=${msg('Reason')}
+					maxlength="1000"
+					required
+				></sl-textarea>
+				<sl-select name="severity" .label=${msg('Severity')} clearable>
+					<sl-option value="Low">${msg('Low')}</sl-option>
+					<sl-option value="Medium">${msg('Medium')}</sl-option>
+					<sl-option value="High">${msg('High')}</sl-option>
+					<sl-option value="Critical">${msg('Critical')}</sl-option>
+				</sl-select>
+			</form>
+			<sl-button
+				slot="footer"
+				@click=${() => {
+					this.shadowRoot?.querySelector('sl-dialog')!.hide();
+				}}
+				>${msg('Cancel')}
+			</sl-button>
+			<sl-button
+				slot="footer"
+				variant="danger"
+				type="submit"
+				form="yank-form"
+				.loading=${this.yanking}
+				>${msg('Yank')}
+			</sl-button>
+		</sl-dialog>`;
+	}
+
+	renderYanks() {
+		const yankHashes = this.happsStore.happReleases
+			.get(this.happReleaseHash)
+			.yanks.get();
+		if (yankHashes.status !== 'completed') return html``;
+		const yanks = joinAsyncMap(
+			mapValues(yankHashes.value, yank => yank.get()),
+		);
+		if (yanks.status !== 'completed') return html``;
+
+		return Array.from(yanks.value.values()).map(
+			yank =>
+				html`<sl-alert variant="danger" open>
+					<sl-icon slot="icon" .src=${wrapPathInSvg(mdiAlertCircleOutline)}>
+					</sl-icon>
+					<div class="column" style="gap: 8px">
+						<strong
+							>${yank.entry.severity
+								? msg(
+										str`This release was yanked (${yank.entry.severity} severity)`,
+									)
+								: msg('This release was yanked')}</strong
+						>
+						<span style="white-space: pre-line">${yank.entry.reason}</span>
+					</div>
+				</sl-alert>`,
+		);
+	}
+
+	isYanked() {
+		const yanks = this.happsStore.happReleases
+			.get(this.happReleaseHash)
+			.yanks.get();
+		return yanks.status === 'completed' && yanks.value.size > 0;
+	}
+
+	renderChannel(happRelease: HappRelease) {
+		// Releases created before channels existed are stable
+		const channel: ReleaseChannel = happRelease.channel ?? { type: 'Stable' };
+		if (channel.type === 'Stable') return html``;
+		return html`<sl-tag size="small" variant="warning"
+			>${channel.type === 'Custom' ? channel.value : channel.type}</sl-tag
+		>`;
+	}
+
 	renderDetail(entryRecord: EntryRecord<HappRelease>) {
 		return html`
 			<sl-card>
 				<div class="column" style="gap: 16px; flex: 1">
+					${this.renderYanks()}
 					<div class="row" style="gap: 16px; align-items: center">
 						<span class="title">${entryRecord.entry.version}</span>
+						${this.renderChannel(entryRecord.entry)}
 						<span style="flex: 1"> </span>
+						${!this.isYanked() &&
+						encodeHashToBase64(entryRecord.action.author) ===
+							encodeHashToBase64(this.happsStore.client.client.myPubKey)
+							? html`${this.renderYankDialog()}
+									<sl-button
+										outline
+										variant="danger"
+										@click=${() => {
+											this.shadowRoot?.querySelector('sl-dialog')!.show();
+										}}
+										>${msg('Yank')}</sl-button
+									>`
+							: html``}
 						<sl-button
 							.loading=${this.downloading}
 							@click=${async () => {
@@ -87,26 +87,41 @@ export class ManageHapp extends SignalWatcher(LitElement) {
 		return originalVersions;
 	}
 
+	versionsYanks() {
+		const versions = this.happsStore.happs
+			.get(this.happHash)
+			.happReleases.get();
+		if (versions.status !== 'completed') return versions;
+
+		return joinAsyncMap(
+			mapValues(versions.value, version => version.yanks.get()),
+		);
+	}
+
 	installedVersion() {
 		const versions = this.versions();
+		const yanks = this.versionsYanks();
 		const apps = installedApps.signal.get();
 		if (versions.status !== 'completed') return versions;
+		if (yanks.status !== 'completed') return yanks;
 		if (apps.status !== 'completed') return apps;
 
-		const sortedVersions = Array.from(versions.value.entries()).sort(
-			(v1, v2) => v2[1].action.timestamp - v1[1].action.timestamp,
-		);
+		// Yanked releases are not offered for installation anymore
+		const sortedVersions = Array.from(versions.value.entries())
+			.filter(([versionHash]) => yanks.value.get(versionHash)?.size === 0)
+			.sort((v1, v2) => v2[1].action.timestamp - v1[1].action.timestamp);
 
 		const isInstalled = (versionHash: ActionHash) =>
 			!!apps.value.find(app => app.installed_app_id === happId(versionHash));
 
-		const latestVersion = sortedVersions[0];
+		const latestVersion: [ActionHash, EntryRecord<HappRelease>] | undefined =
+			sortedVersions[0];
 
 		return {
 			status: 'completed' as const,
 			value: {
 				latestVersion,
-				isInstalled: isInstalled(latestVersion[0]),
+				isInstalled: latestVersion ? isInstalled(latestVersion[0]) : false,
 			},
 		};
 	}
@@ -156,9 +171,10 @@ export class ManageHapp extends SignalWatcher(LitElement) {
 
 	renderAction(
 		happ: EntryRecord<Happ>,
-		latestVersion: [ActionHash, EntryRecord<HappRelease>],
+		latestVersion: [ActionHash, EntryRecord<HappRelease>] | undefined,
 		isInstalled: boolean,
 	) {
+		if (!latestVersion) return html``;
 		if (isInstalled)
 			return html`
 				<div class="row" style="gap: 8px">
@@ -224,7 +240,7 @@ export class ManageHapp extends SignalWatcher(LitElement) {
 
 	renderSummary(
 		happ: EntryRecord<Happ>,
-		latestVersion: [ActionHash, EntryRecord<HappRelease>],
+		latestVersion: [ActionHash, EntryRecord<HappRelease>] | undefined,
 		isInstalled: boolean,
 	) {
 		return html`
@@ -33,6 +33,12 @@ export class PublisherHapps extends SignalWatcher(LitElement) {
 	@property(hashProperty('author'))
 	author!: AgentPubKey;
 
+	/**
+	 * Whether to also show the Happs that the author unpublished
+	 */
+	@property({ type: Boolean, attribute: 'include-unpublished' })
+	includeUnpublished = false;
+
 	/**
 	 * @internal
 	 */
@@ -96,7 +102,13 @@ export class PublisherHapps extends SignalWatcher(LitElement) {
 	}
 
 	render() {
-		const map = this.happsStore.publisherHapps.get(this.author).get();
+		const map = (
+			this.includeUnpublished
+				? this.happsStore.allPublisherHapps
+				: this.happsStore.publisherHapps
+		)
+			.get(this.author)
+			.get();
 
 		switch (map.status) {
 			case 'pending':
@@ -18,6 +18,21 @@ import {
 
 import { HappRelease } from './types.js';
 import { Happ } from './types.js';
+import { HappOverview } from './types.js';
+import { HappsPage, HappsPageCursor, HappsSortKey } from './types.js';
+import { ReleaseChannel, RevisionHeads } from './types.js';
+import { HappReleaseYank, YankSeverity } from './types.js';
+import { OwnershipOffer, OwnershipTransfer } from './types.js';
+import { DomainClaim, Publisher, PublisherCredential } from './types.js';
+import { RatingSummary, Review, ReviewReply } from './types.js';
+import { Report, ReportDecision, ReportResolution } from './types.js';
+import { HappCategory } from './types.js';
+import { InstalledHapp, UpdateCheck } from './types.js';
+import {
+	UpdateConflictError,
+	decodeUpdateConflict,
+} from './update-conflict.js';
+import { rethrowStoredFileError } from './stored-file-error.js';
 import { HappsSignal } from './types.js';
 
 export class HappsClient extends ZomeClient<HappsSignal> {
@@ -31,7 +46,9 @@ export class HappsClient extends ZomeClient<HappsSignal> {
 	/** Happ */
 
 	async createHapp(happ: Happ): Promise<EntryRecord<Happ>> {
-		const record: Record = await this.callZome('create_happ', happ);
+		const record: Record = await this.callZome('create_happ', happ).catch(
+			rethrowStoredFileError,
+		);
 		return new EntryRecord(record);
 	}
 
@@ -42,6 +59,12 @@ export class HappsClient extends ZomeClient<HappsSignal> {
 		return record ? new EntryRecord(record) : undefined;
 	}
 
+	async getHappHeads(
+		happHash: ActionHash,
+	): Promise<RevisionHeads | undefined> {
+		return this.callZome('get_happ_heads', happHash);
+	}
+
 	async getOriginalHapp(
 		happHash: ActionHash,
 	): Promise<EntryRecord<Happ> | undefined> {
@@ -64,12 +87,18 @@ export class HappsClient extends ZomeClient<HappsSignal> {
 		previousHappHash: ActionHash,
 		updatedHapp: Happ,
 	): Promise<EntryRecord<Happ>> {
-		const record: Record = await this.callZome('update_happ', {
-			original_happ_hash: originalHappHash,
-			previous_happ_hash: previousHappHash,
-			updated_happ: updatedHapp,
-		});
-		return new EntryRecord(record);
+		try {
+			const record: Record = await this.callZome('update_happ', {
+				original_happ_hash: originalHappHash,
+				previous_happ_hash: previousHappHash,
+				updated_happ: updatedHapp,
+			});
+			return new EntryRecord(record);
+		} catch (e) {
+			const currentHead = decodeUpdateConflict(e);
+			if (!currentHead) rethrowStoredFileError(e);
+			throw new UpdateConflictError(new EntryRecord<Happ>(currentHead));
+		}
 	}
 
 	deleteHapp(originalHappHash: ActionHash): Promise<ActionHash> {
@@ -88,6 +117,12 @@ export class HappsClient extends ZomeClient<HappsSignal> {
 		return this.callZome('get_oldest_delete_for_happ', originalHappHash);
 	}
 
+	/** Happ overview */
+
+	getHappOverview(happHash: ActionHash): Promise<HappOverview | undefined> {
+		return this.callZome('get_happ_overview', happHash);
+	}
+
 	/** Happ unpublishing */
 
 	async unpublishHapp(happHash: ActionHash): Promise<void> {
@@ -102,6 +137,316 @@ export class HappsClient extends ZomeClient<HappsSignal> {
 		return this.callZome('republish_happ', happHash);
 	}
 
+	/** Moderation */
+
+	/**
+	 * Returns the progenitor, followed by the initial moderators and the ones
+	 * the progenitor appointed
+	 */
+	getModerators(): Promise<Array<AgentPubKey>> {
+		return this.callZome('get_moderators', undefined);
+	}
+
+	addModerator(moderator: AgentPubKey): Promise<void> {
+		return this.callZome('add_moderator', moderator);
+	}
+
+	removeModerator(moderator: AgentPubKey): Promise<void> {
+		return this.callZome('remove_moderator', moderator);
+	}
+
+	hideHapp(happHash: ActionHash, reason: string): Promise<void> {
+		return this.callZome('hide_happ', {
+			happ_hash: happHash,
+			reason,
+		});
+	}
+
+	unhideHapp(happHash: ActionHash): Promise<void> {
+		return this.callZome('unhide_happ', happHash);
+	}
+
+	getHappHiddenLinks(happHash: ActionHash): Promise<Array<Link>> {
+		return this.callZome('get_happ_hidden_links', happHash);
+	}
+
+	/** Maintainers */
+
+	async addMaintainer(
+		happHash: ActionHash,
+		maintainer: AgentPubKey,
+	): Promise<void> {
+		return this.callZome('add_maintainer', {
+			happ_hash: happHash,
+			maintainer,
+		});
+	}
+
+	async removeMaintainer(
+		happHash: ActionHash,
+		maintainer: AgentPubKey,
+	): Promise<void> {
+		return this.callZome('remove_maintainer', {
+			happ_hash: happHash,
+			maintainer,
+		});
+	}
+
+	/** Returns the owner of the hApp followed by the rest of its maintainers */
+	getMaintainers(happHash: ActionHash): Promise<Array<AgentPubKey>> {
+		return this.callZome('get_maintainers', happHash);
+	}
+
+	/** Ownership */
+
+	async offerHappOwnership(
+		happHash: ActionHash,
+		recipient: AgentPubKey,
+	): Promise<EntryRecord<OwnershipOffer>> {
+		const record: Record = await this.callZome('offer_happ_ownership', {
+			happ_hash: happHash,
+			recipient,
+		});
+		return new EntryRecord(record);
+	}
+
+	getHappOwnershipOffers(happHash: ActionHash): Promise<Array<Link>> {
+		return this.callZome('get_happ_ownership_offers', happHash);
+	}
+
+	async cancelHappOwnershipOffer(
+		ownershipOfferHash: ActionHash,
+	): Promise<void> {
+		return this.callZome('cancel_happ_ownership_offer', ownershipOfferHash);
+	}
+
+	async acceptHappOwnershipOffer(
+		ownershipOfferHash: ActionHash,
+	): Promise<EntryRecord<OwnershipTransfer>> {
+		const record: Record = await this.callZome(
+			'accept_happ_ownership_offer',
+			ownershipOfferHash,
+		);
+		return new EntryRecord(record);
+	}
+
+	/** Returns all the ownership transfers of the hApp, from oldest to newest */
+	async getHappOwnershipTransfers(
+		happHash: ActionHash,
+	): Promise<Array<EntryRecord<OwnershipTransfer>>> {
+		const records: Array<Record> = await this.callZome(
+			'get_happ_ownership_transfers',
+			happHash,
+		);
+		return records.map(record => new EntryRecord(record));
+	}
+
+	getHappOwner(happHash: ActionHash): Promise<AgentPubKey | undefined> {
+		return this.callZome('get_happ_owner', happHash);
+	}
+
+	/** Publisher */
+
+	async createPublisher(
+		publisher: Publisher,
+	): Promise<EntryRecord<Publisher>> {
+		const record: Record = await this.callZome(
+			'create_publisher',
+			publisher,
+		).catch(rethrowStoredFileError);
+		return new EntryRecord(record);
+	}
+
+	async getPublisherForAgent(
+		agent: AgentPubKey,
+	): Promise<EntryRecord<Publisher> | undefined> {
+		const record: Record | undefined = await this.callZome(
+			'get_publisher_for_agent',
+			agent,
+		);
+		return record ? new EntryRecord(record) : undefined;
+	}
+
+	async getLatestPublisher(
+		originalPublisherHash: ActionHash,
+	): Promise<EntryRecord<Publisher> | undefined> {
+		const record: Record | undefined = await this.callZome(
+			'get_latest_publisher',
+			originalPublisherHash,
+		);
+		return record ? new EntryRecord(record) : undefined;
+	}
+
+	async updatePublisher(
+		originalPublisherHash: ActionHash,
+		previousPublisherHash: ActionHash,
+		updatedPublisher: Publisher,
+	): Promise<EntryRecord<Publisher>> {
+		const record: Record = await this.callZome('update_publisher', {
+			original_publisher_hash: originalPublisherHash,
+			previous_publisher_hash: previousPublisherHash,
+			updated_publisher: updatedPublisher,
+		}).catch(rethrowStoredFileError);
+		return new EntryRecord(record);
+	}
+
+	/** Publisher credentials */
+
+	/** Only the progenitor can sign publisher credentials */
+	signPublisherCredential(agent: AgentPubKey): Promise<PublisherCredential> {
+		return this.callZome('sign_publisher_credential', agent);
+	}
+
+	async commitPublisherCredential(
+		credential: PublisherCredential,
+	): Promise<EntryRecord<PublisherCredential>> {
+		const record: Record = await this.callZome(
+			'commit_publisher_credential',
+			credential,
+		);
+		return new EntryRecord(record);
+	}
+
+	canPublish(): Promise<boolean> {
+		return this.callZome('can_publish', undefined);
+	}
+
+	/** Domain claims */
+
+	async claimDomain(domain: string): Promise<EntryRecord<DomainClaim>> {
+		const record: Record = await this.callZome('claim_domain', domain);
+		return new EntryRecord(record);
+	}
+
+	async getDomainClaims(
+		agent: AgentPubKey,
+	): Promise<Array<EntryRecord<DomainClaim>>> {
+		const records: Array<Record> = await this.callZome(
+			'get_domain_claims',
+			agent,
+		);
+		return records.map(record => new EntryRecord(record));
+	}
+
+	deleteDomainClaim(domainClaimHash: ActionHash): Promise<ActionHash> {
+		return this.callZome('delete_domain_claim', domainClaimHash);
+	}
+
+	/** Reviews */
+
+	async createReview(review: Review): Promise<EntryRecord<Review>> {
+		const record: Record = await this.callZome('create_review', review);
+		return new EntryRecord(record);
+	}
+
+	getHappReviews(happHash: ActionHash): Promise<Array<Link>> {
+		return this.callZome('get_happ_reviews', happHash);
+	}
+
+	async getLatestReview(
+		originalReviewHash: ActionHash,
+	): Promise<EntryRecord<Review> | undefined> {
+		const record: Record | undefined = await this.callZome(
+			'get_latest_review',
+			originalReviewHash,
+		);
+		return record ? new EntryRecord(record) : undefined;
+	}
+
+	async updateReview(
+		originalReviewHash: ActionHash,
+		previousReviewHash: ActionHash,
+		updatedReview: Review,
+	): Promise<EntryRecord<Review>> {
+		const record: Record = await this.callZome('update_review', {
+			original_review_hash: originalReviewHash,
+			previous_review_hash: previousReviewHash,
+			updated_review: updatedReview,
+		});
+		return new EntryRecord(record);
+	}
+
+	deleteReview(originalReviewHash: ActionHash): Promise<ActionHash> {
+		return this.callZome('delete_review', originalReviewHash);
+	}
+
+	getRatingSummary(happHash: ActionHash): Promise<RatingSummary> {
+		return this.callZome('get_rating_summary', happHash);
+	}
+
+	async createReviewReply(
+		reviewReply: ReviewReply,
+	): Promise<EntryRecord<ReviewReply>> {
+		const record: Record = await this.callZome(
+			'create_review_reply',
+			reviewReply,
+		);
+		return new EntryRecord(record);
+	}
+
+	async getReviewReplies(
+		reviewHash: ActionHash,
+	): Promise<Array<EntryRecord<ReviewReply>>> {
+		const records: Array<Record> = await this.callZome(
+			'get_review_replies',
+			reviewHash,
+		);
+		return records.map(record => new EntryRecord(record));
+	}
+
+	deleteReviewReply(reviewReplyHash: ActionHash): Promise<ActionHash> {
+		return this.callZome('delete_review_reply', reviewReplyHash);
+	}
+
+	/** Reports */
+
+	async createReport(report: Report): Promise<EntryRecord<Report>> {
+		const record: Record = await this.callZome('create_report', report);
+		return new EntryRecord(record);
+	}
+
+	async getReport(
+		reportHash: ActionHash,
+	): Promise<EntryRecord<Report> | undefined> {
+		const record: Record | undefined = await this.callZome(
+			'get_report',
+			reportHash,
+		);
+		return record ? new EntryRecord(record) : undefined;
+	}
+
+	getReportsForHapp(happHash: ActionHash): Promise<Array<Link>> {
+		return this.callZome('get_reports_for_happ', happHash);
+	}
+
+	/** The moderation queue */
+	getOpenReports(): Promise<Array<Link>> {
+		return this.callZome('get_open_reports', undefined);
+	}
+
+	async resolveReport(
+		reportHash: ActionHash,
+		decision: ReportDecision,
+		notes: string,
+	): Promise<EntryRecord<ReportResolution>> {
+		const record: Record = await this.callZome('resolve_report', {
+			report_hash: reportHash,
+			decision,
+			notes,
+		});
+		return new EntryRecord(record);
+	}
+
+	async getReportResolutions(
+		reportHash: ActionHash,
+	): Promise<Array<EntryRecord<ReportResolution>>> {
+		const records: Array<Record> = await this.callZome(
+			'get_report_resolutions',
+			reportHash,
+		);
+		return records.map(record => new EntryRecord(record));
+	}
+
 	/** hApp Release */
 
 	async createHappRelease(
@@ -110,7 +455,7 @@ export class HappsClient extends ZomeClient<HappsSignal> {
 		const record: Record = await this.callZome(
 			'create_happ_release',
 			happRelease,
-		);
+		).catch(rethrowStoredFileError);
 		return new EntryRecord(record);
 	}
 
@@ -124,6 +469,12 @@ export class HappsClient extends ZomeClient<HappsSignal> {
 		return record ? new EntryRecord(record) : undefined;
 	}
 
+	async getHappReleaseHeads(
+		happReleaseHash: ActionHash,
+	): Promise<RevisionHeads | undefined> {
+		return this.callZome('get_happ_release_heads', happReleaseHash);
+	}
+
 	async getOriginalHappRelease(
 		happReleaseHash: ActionHash,
 	): Promise<EntryRecord<HappRelease> | undefined> {
@@ -149,27 +500,156 @@ export class HappsClient extends ZomeClient<HappsSignal> {
 		previousHappReleaseHash: ActionHash,
 		updatedHappRelease: HappRelease,
 	): Promise<EntryRecord<HappRelease>> {
-		const record: Record = await this.callZome('update_happ_release', {
-			original_happ_release_hash: originalHappReleaseHash,
-			previous_happ_release_hash: previousHappReleaseHash,
-			updated_happ_release: updatedHappRelease,
-		});
-		return new EntryRecord(record);
+		try {
+			const record: Record = await this.callZome('update_happ_release', {
+				original_happ_release_hash: originalHappReleaseHash,
+				previous_happ_release_hash: previousHappReleaseHash,
+				updated_happ_release: updatedHappRelease,
+			});
+			return new EntryRecord(record);
+		} catch (e) {
+			const currentHead = decodeUpdateConflict(e);
+			if (!currentHead) rethrowStoredFileError(e);
+			throw new UpdateConflictError(new EntryRecord<HappRelease>(currentHead));
+		}
 	}
 
 	async getHappReleasesForHapp(happHash: ActionHash): Promise<Array<Link>> {
 		return this.callZome('get_happ_releases_for_happ', happHash);
 	}
 
+	/** hApp Release Yanks */
+
+	async yankHappRelease(
+		happReleaseHash: ActionHash,
+		reason: string,
+		severity: YankSeverity | undefined,
+	): Promise<EntryRecord<HappReleaseYank>> {
+		const record: Record = await this.callZome('yank_happ_release', {
+			happ_release_hash: happReleaseHash,
+			reason,
+			severity,
+		});
+		return new EntryRecord(record);
+	}
+
+	async getHappReleaseYank(
+		happReleaseYankHash: ActionHash,
+	): Promise<EntryRecord<HappReleaseYank> | undefined> {
+		const record: Record = await this.callZome(
+			'get_happ_release_yank',
+			happReleaseYankHash,
+		);
+		return record ? new EntryRecord(record) : undefined;
+	}
+
+	async getHappReleaseYankLinks(
+		happReleaseHash: ActionHash,
+	): Promise<Array<Link>> {
+		return this.callZome('get_happ_release_yank_links', happReleaseHash);
+	}
+
+	async getHappReleasesInChannel(
+		happHash: ActionHash,
+		channel: ReleaseChannel,
+	): Promise<Array<Link>> {
+		return this.callZome('get_happ_releases_in_channel', {
+			happ_hash: happHash,
+			channel,
+		});
+	}
+
+	async getLatestReleaseInChannel(
+		happHash: ActionHash,
+		channel: ReleaseChannel,
+	): Promise<EntryRecord<HappRelease> | undefined> {
+		const record: Record | undefined = await this.callZome(
+			'get_latest_release_in_channel',
+			{
+				happ_hash: happHash,
+				channel,
+			},
+		);
+		return record ? new EntryRecord(record) : undefined;
+	}
+
+	/** Update checks */
+
+	async checkForUpdates(
+		installedHapps: Array<InstalledHapp>,
+	): Promise<Array<UpdateCheck>> {
+		return this.callZome('check_for_updates', installedHapps);
+	}
+
 	/** All Happs */
 
-	async getAllHapps(): Promise<Array<Link>> {
-		return this.callZome('get_all_happs', undefined);
+	async getAllHapps(includeUnpublished = false): Promise<Array<Link>> {
+		return this.callZome('get_all_happs', {
+			include_unpublished: includeUnpublished,
+		});
+	}
+
+	async getHappsPage(
+		sortKey: HappsSortKey,
+		pageSize: number,
+		cursor: HappsPageCursor | undefined,
+		includeUnpublished = false,
+	): Promise<HappsPage> {
+		return this.callZome('get_happs_page', {
+			sort_key: sortKey,
+			page_size: pageSize,
+			cursor,
+			include_unpublished: includeUnpublished,
+		});
 	}
 
 	/** Publisher Happs */
 
-	async getPublisherHapps(author: AgentPubKey): Promise<Array<Link>> {
-		return this.callZome('get_publisher_happs', author);
+	async getPublisherHapps(
+		author: AgentPubKey,
+		includeUnpublished = false,
+	): Promise<Array<Link>> {
+		return this.callZome('get_publisher_happs', {
+			publisher: author,
+			include_unpublished: includeUnpublished,
+		});
+	}
+
+	/** Categories and tags */
+
+	async getHappsByCategory(
+		category: HappCategory,
+		includeUnpublished = false,
+	): Promise<Array<Link>> {
+		return this.callZome('get_happs_by_category', {
+			category,
+			include_unpublished: includeUnpublished,
+		});
+	}
+
+	async getHappsByTag(
+		tag: string,
+		includeUnpublished = false,
+	): Promise<Array<Link>> {
+		return this.callZome('get_happs_by_tag', {
+			tag,
+			include_unpublished: includeUnpublished,
+		});
+	}
+
+	/** Search */
+
+	/**
+	 * Returns one link per hApp whose name or description matches the query,
+	 * best matches first
+	 */
+	async searchHapps(
+		query: string,
+		includeUnpublished = false,
+	): Promise<Array<Link>> {
+		return this.callZome('search_happs', {
+			query,
+			include_unpublished: includeUnpublished,
+		});
 	}
 }
@@ -61,8 +61,29 @@ export class HappsStore {
 		allRevisions: allRevisionsOfEntrySignal(this.client, () =>
 			this.client.getAllRevisionsForHappRelease(happReleaseHash),
 		),
+		yanks: pipe(
+			liveLinksSignal(
+				this.client,
+				happReleaseHash,
+				() => this.client.getHappReleaseYankLinks(happReleaseHash),
+				'HappReleaseYanks',
+			),
+			links =>
+				slice(
+					this.happReleaseYanks,
+					links.map(l => l.target),
+				),
+		),
 	}));
 
+	/** hApp Release Yank */
+
+	happReleaseYanks = new MemoHoloHashMap((happReleaseYankHash: ActionHash) =>
+		immutableEntrySignal(() =>
+			this.client.getHappReleaseYank(happReleaseYankHash),
+		),
+	);
+
 	/** All Happs */
 
 	allHapps = pipe(
@@ -95,4 +116,22 @@ export class HappsStore {
 				),
 		),
 	);
+
+	/**
+	 * All the Happs of the given publisher, including the unpublished ones, to let them manage them
+	 */
+	allPublisherHapps = new MemoHoloHashMap((author: AgentPubKey) =>
+		pipe(
+			collectionSignal(
+				this.client,
+				() => this.client.getPublisherHapps(author, true),
+				'PublisherHapps',
+			),
+			publisherHapps =>
+				slice(
+					this.happs,
+					publisherHapps.map(l => l.target),
+				),
+		),
+	);
 }
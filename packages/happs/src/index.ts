@@ -2,3 +2,8 @@ export * from './types.js';
 export * from './context.js';
 export * from './happs-client.js';
 export * from './happs-store.js';
+export * from './update-conflict.js';
+export * from './stored-file-error.js';
+export * from './domain-claims.js';
+export * from './categories.js';
+export * from './publisher-credentials.js';
@@ -34,6 +34,8 @@ import {
 import { HappsClient } from './happs-client.js';
 import { HappRelease } from './types.js';
 import { Happ } from './types.js';
+import { HappsPage, HappsPageCursor, HappsSortKey } from './types.js';
+import { RevisionHeads } from './types.js';
 
 export class HappsZomeMock extends ZomeMock implements AppClient {
 	constructor(myPubKey?: AgentPubKey) {
@@ -68,6 +70,18 @@ export class HappsZomeMock extends ZomeMock implements AppClient {
 		return happ ? happ.revisions[happ.revisions.length - 1] : undefined;
 	}
 
+	async get_happ_heads(
+		happHash: ActionHash,
+	): Promise<RevisionHeads | undefined> {
+		const happ = this.happs.get(happHash);
+		return happ
+			? {
+					canonical_head: happ.revisions[happ.revisions.length - 1],
+					conflicting_heads: [],
+				}
+			: undefined;
+	}
+
 	async get_all_revisions_for_happ(
 		happHash: ActionHash,
 	): Promise<Record[] | undefined> {
@@ -80,6 +94,16 @@ export class HappsZomeMock extends ZomeMock implements AppClient {
 		return happ ? happ.revisions[0] : undefined;
 	}
 
+	async get_happ_owner(happHash: ActionHash): Promise<AgentPubKey | undefined> {
+		const happ = this.happs.get(happHash);
+		return happ?.revisions[0].signed_action.hashed.content.author;
+	}
+
+	async get_maintainers(happHash: ActionHash): Promise<Array<AgentPubKey>> {
+		const happ = this.happs.get(happHash);
+		return happ ? [happ.revisions[0].signed_action.hashed.content.author] : [];
+	}
+
 	async get_all_deletes_for_happ(
 		happHash: ActionHash,
 	): Promise<Array<SignedActionHashed<Delete>> | undefined> {
@@ -157,7 +181,7 @@ export class HappsZomeMock extends ZomeMock implements AppClient {
 				timestamp: Date.now() * 1000,
 				zome_index: 0,
 				link_type: 0,
-				tag: new Uint8Array(),
+				tag: new TextEncoder().encode(happRelease.version),
 				create_link_hash: await fakeActionHash(),
 			},
 		]);
@@ -232,6 +256,12 @@ export class HappsZomeMock extends ZomeMock implements AppClient {
 		return this.happReleasesForHapp.get(happHash) || [];
 	}
 
+	async get_happ_release_yank_links(
+		happReleaseHash: ActionHash,
+	): Promise<Array<Link>> {
+		return [];
+	}
+
 	async get_all_happs(): Promise<Array<Link>> {
 		const records: Record[] = Array.from(this.happs.values()).map(
 			r => r.revisions[r.revisions.length - 1],
@@ -251,7 +281,44 @@ export class HappsZomeMock extends ZomeMock implements AppClient {
 		);
 	}
 
-	async get_publisher_happs(author: AgentPubKey): Promise<Array<Link>> {
+	async get_happs_page(input: {
+		sort_key: HappsSortKey;
+		page_size: number;
+		cursor: HappsPageCursor | undefined;
+	}): Promise<HappsPage> {
+		const happs = Array.from(this.happs.entries()).reverse();
+		const start = input.cursor
+			? happs.findIndex(
+					([hash]) =>
+						hash.toString() === input.cursor!.happ_hash.toString(),
+				) + 1
+			: 0;
+		const page = happs.slice(start, start + input.page_size);
+		const last = page[page.length - 1];
+		return {
+			happs: page.map(([original_happ_hash, happ]) => ({
+				original_happ_hash,
+				latest_happ: happ.revisions[happ.revisions.length - 1],
+			})),
+			next_cursor:
+				start + input.page_size < happs.length
+					? {
+							happ_hash: last[0],
+							sort_value: {
+								type: 'Timestamp',
+								value: last[1].revisions[0].signed_action.hashed.content
+									.timestamp,
+							},
+						}
+					: undefined,
+		};
+	}
+
+	async get_publisher_happs(input: {
+		publisher: AgentPubKey;
+		include_unpublished: boolean;
+	}): Promise<Array<Link>> {
+		const author = input.publisher;
 		const records: Record[] = Array.from(this.happs.values())
 			.map(r => r.revisions[r.revisions.length - 1])
 			.filter(
@@ -283,6 +350,8 @@ export async function sampleHapp(
 			name: 'Lorem ipsum 2',
 			description: 'Lorem ipsum 2',
 			icon: await fakeEntryHash(),
+			category: 'Other',
+			tags: [],
 		},
 		...partialHapp,
 	};
@@ -297,9 +366,10 @@ export async function sampleHappRelease(
 			happ_hash:
 				partialHappRelease.happ_hash ||
 				(await client.createHapp(await sampleHapp(client))).actionHash,
-			version: 'Lorem ipsum 2',
+			version: '0.1.0',
 			changes: 'Lorem ipsum 2',
 			web_happ_bundle_hash: await fakeEntryHash(),
+			channel: { type: 'Stable' },
 		},
 		...partialHappRelease,
 	};
@@ -0,0 +1,14 @@
+import { encode } from '@msgpack/msgpack';
+
+import { PublisherCredential } from './types.js';
+
+/**
+ * Encodes the publisher credential to be used as the membrane proof of the
+ * `main` role when installing the app, so that the agent can publish hApps
+ * from the start
+ */
+export function publisherCredentialMembraneProof(
+	credential: PublisherCredential,
+): Uint8Array {
+	return encode(credential);
+}
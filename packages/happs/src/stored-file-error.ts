@@ -0,0 +1,43 @@
+import { EntryHash } from '@holochain/client';
+
+import { decodeSerializedZomeError } from './zome-errors.js';
+
+export type StoredFileProblem =
+	| { type: 'NotFound'; file_hash: EntryHash }
+	// The file storage couldn't be asked for the file, so it may still be stored
+	| { type: 'Unavailable'; file_hash: EntryHash; reason: string }
+	| { type: 'UnexpectedFileType'; file_hash: EntryHash; file_type: string }
+	| { type: 'TooLarge'; file_hash: EntryHash; size: number; max_size: number };
+
+/**
+ * Thrown when the icon, avatar or bundle of an entry is not a valid file
+ * stored in the file storage
+ */
+export class StoredFileError extends Error {
+	constructor(
+		public problem: StoredFileProblem,
+		message: string,
+	) {
+		super(message);
+		this.name = 'StoredFileError';
+	}
+}
+
+// Must match STORED_FILE_ERROR_PREFIX in the coordinator zome
+const STORED_FILE_ERROR_PREFIX = 'StoredFileError:';
+
+/**
+ * Rethrows the errors about stored files returned by the zome as
+ * `StoredFileError`s, and any other error as is
+ */
+export function rethrowStoredFileError(error: unknown): never {
+	const problem = decodeSerializedZomeError(
+		error,
+		STORED_FILE_ERROR_PREFIX,
+	) as StoredFileProblem | undefined;
+	if (!problem) throw error;
+	throw new StoredFileError(
+		problem,
+		error instanceof Error ? error.message : `${error}`,
+	);
+}
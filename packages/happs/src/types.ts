@@ -1,6 +1,7 @@
 import {
 	ActionHash,
 	AgentPubKey,
+	AgentPubKeyB64,
 	Create,
 	CreateLink,
 	Delete,
@@ -8,6 +9,7 @@ import {
 	DnaHash,
 	EntryHash,
 	Record,
+	Signature,
 	SignedActionHashed,
 	Update,
 } from '@holochain/client';
@@ -16,6 +18,19 @@ import { ActionCommittedSignal } from '@darksoil-studio/holochain-utils';
 export type HappsSignal = ActionCommittedSignal<EntryTypes, LinkTypes>;
 
 export type EntryTypes =
+	| ({ type: 'OwnershipOfferCancellation' } & OwnershipOfferCancellation)
+	| ({ type: 'PublisherCredential' } & PublisherCredential)
+	| ({ type: 'ModeratorClaim' } & ModeratorClaim)
+	| ({ type: 'ReportResolution' } & ReportResolution)
+	| ({ type: 'Report' } & Report)
+	| ({ type: 'ReviewReply' } & ReviewReply)
+	| ({ type: 'Review' } & Review)
+	| ({ type: 'DomainClaim' } & DomainClaim)
+	| ({ type: 'Publisher' } & Publisher)
+	| ({ type: 'OwnershipTransfer' } & OwnershipTransfer)
+	| ({ type: 'OwnershipOffer' } & OwnershipOffer)
+	| ({ type: 'MaintainerClaim' } & MaintainerClaim)
+	| ({ type: 'HappReleaseYank' } & HappReleaseYank)
 	| ({ type: 'HappRelease' } & HappRelease)
 	| ({ type: 'Happ' } & Happ);
 
@@ -27,8 +42,23 @@ export interface Happ {
 	description: string;
 
 	icon: EntryHash;
+
+	category: HappCategory;
+
+	tags: Array<string>;
 }
 
+export type HappCategory =
+	| 'Productivity'
+	| 'Social'
+	| 'Communication'
+	| 'Finance'
+	| 'Games'
+	| 'Education'
+	| 'DeveloperTools'
+	| 'Media'
+	| 'Other';
+
 export interface HappRelease {
 	happ_hash: ActionHash;
 
@@ -37,4 +67,238 @@ export interface HappRelease {
 	changes: string;
 
 	web_happ_bundle_hash: EntryHash;
+
+	// Releases created before channels existed don't have it, and are stable
+	channel: ReleaseChannel;
+}
+
+export interface MaintainerClaim {
+	happ_hash: ActionHash;
+
+	maintainer_link_hash: ActionHash;
+
+	owner_chain_top: ActionHash;
+}
+
+export interface Publisher {
+	display_name: string;
+
+	avatar: EntryHash | undefined;
+
+	url: string | undefined;
+
+	bio: string;
+}
+
+// Signed by the progenitor to let the agent publish hApps
+export interface PublisherCredential {
+	agent: AgentPubKey;
+
+	signature: Signature;
+}
+
+export interface DomainClaim {
+	domain: string;
+
+	signature: Signature;
+}
+
+export interface Review {
+	happ_hash: ActionHash;
+
+	happ_release_hash: ActionHash;
+
+	// From 1 to 5 stars
+	rating: number;
+
+	text: string;
+}
+
+export interface ReviewReply {
+	review_hash: ActionHash;
+
+	text: string;
+}
+
+export interface RatingSummary {
+	reviews_count: number;
+
+	// Undefined if the hApp has no reviews yet
+	average_rating: number | undefined;
+
+	// The number of reviews with each rating, from 1 star to 5 stars
+	histogram: [number, number, number, number, number];
+}
+
+export type ReportReason =
+	| 'Malware'
+	| 'Spam'
+	| 'Impersonation'
+	| 'InappropriateContent'
+	| 'Other';
+
+export interface Report {
+	happ_hash: ActionHash;
+
+	happ_release_hash: ActionHash | undefined;
+
+	reason: ReportReason;
+
+	text: string;
+}
+
+export type ReportDecision = 'Dismissed' | 'Upheld';
+
+export interface ReportResolution {
+	report_hash: ActionHash;
+
+	decision: ReportDecision;
+
+	notes: string;
+}
+
+// The properties of the DNA, agent keys are in their base64 form
+export interface ModeratorClaim {
+	moderator_link_hash: ActionHash;
+
+	progenitor_chain_top: ActionHash;
+}
+
+export interface HappsProperties {
+	// Appoints the moderators and vets the publishers, everyone can publish if
+	// it's not set
+	progenitor?: AgentPubKeyB64;
+
+	initial_moderators?: Array<AgentPubKeyB64>;
+
+	// For how long the claims of maintainers and moderators grant their rights
+	// after the action of the owner or the progenitor they point to, 30 days if
+	// it's not set
+	claim_validity_secs?: number;
+	// For how long after being made an ownership offer can be accepted, after
+	// which the owner can cancel it, 7 days if it's not set
+	ownership_offer_validity_secs?: number;
+}
+
+export interface OwnershipOffer {
+	happ_hash: ActionHash;
+
+	recipient: AgentPubKey;
+}
+
+export interface OwnershipTransfer {
+	happ_hash: ActionHash;
+
+	offer_hash: ActionHash;
+
+	offerer_chain_top: ActionHash;
+}
+
+export interface OwnershipOfferCancellation {
+	offer_hash: ActionHash;
+
+	recipient_chain_top: ActionHash;
+}
+
+export type YankSeverity = 'Low' | 'Medium' | 'High' | 'Critical';
+
+export interface HappReleaseYank {
+	happ_release_hash: ActionHash;
+
+	reason: string;
+
+	severity: YankSeverity | undefined;
+}
+
+export type ReleaseChannel =
+	| { type: 'Stable' }
+	| { type: 'Beta' }
+	| { type: 'Nightly' }
+	| { type: 'Custom'; value: string };
+
+export interface HappOverview {
+	original_happ_hash: ActionHash;
+
+	publisher: AgentPubKey;
+
+	latest_happ: Record;
+
+	unpublished: boolean;
+
+	// Whether a moderator hid the hApp from the catalog
+	hidden: boolean;
+
+	// Latest revision of every release, sorted by version from newest to oldest
+	releases: Array<Record>;
+}
+
+export type HappsSortKey =
+	| 'CreatedTime'
+	| 'LastUpdated'
+	| 'LatestReleaseTime'
+	| 'Name';
+
+export type HappSortValue =
+	| { type: 'Timestamp'; value: number }
+	| { type: 'Name'; value: string };
+
+export interface HappsPageCursor {
+	happ_hash: ActionHash;
+
+	sort_value: HappSortValue;
+
+	// Whether the page ended among the hApps that are missing from the sort index
+	unindexed?: boolean;
+}
+
+export interface HappsPageItem {
+	original_happ_hash: ActionHash;
+
+	latest_happ: Record;
+}
+
+export interface HappsPage {
+	happs: Array<HappsPageItem>;
+
+	next_cursor: HappsPageCursor | undefined;
+}
+
+export interface RevisionHeads {
+	canonical_head: Record;
+
+	// Other revisions that were not updated further, because of concurrent edits
+	conflicting_heads: Array<Record>;
+}
+
+export type InstalledRelease =
+	| { type: 'Version'; value: string }
+	| { type: 'WebHappBundleHash'; value: EntryHash };
+
+export interface InstalledHapp {
+	happ_hash: ActionHash;
+
+	installed: InstalledRelease;
+
+	// Defaults to the stable channel
+	channel: ReleaseChannel | undefined;
+}
+
+export interface AvailableUpdate {
+	happ_release_hash: ActionHash;
+
+	version: string;
+
+	changes: string;
+
+	web_happ_bundle_hash: EntryHash;
+}
+
+export interface UpdateCheck {
+	happ_hash: ActionHash;
+
+	// The newest release that is not yanked and is newer than the installed one
+	available_update: AvailableUpdate | undefined;
+
+	// Why the updates of this hApp couldn't be checked, like an invalid installed version
+	error: string | undefined;
 }
@@ -0,0 +1,29 @@
+import { Record } from '@holochain/client';
+import { EntryRecord } from '@darksoil-studio/holochain-utils';
+
+import { decodeSerializedZomeError } from './zome-errors.js';
+
+/**
+ * Thrown when updating a revision that is not the latest one anymore,
+ * because someone else updated it in the meantime
+ */
+export class UpdateConflictError<T> extends Error {
+	constructor(public currentHead: EntryRecord<T>) {
+		super('Update conflict: the revision being updated is not the latest one');
+		this.name = 'UpdateConflictError';
+	}
+}
+
+// Must match UPDATE_CONFLICT_ERROR_PREFIX in the coordinator zome
+const UPDATE_CONFLICT_ERROR_PREFIX = 'UpdateConflictError:';
+
+/**
+ * Decodes the current head from an update conflict error returned by the zome
+ */
+export function decodeUpdateConflict(error: unknown): Record | undefined {
+	const conflict = decodeSerializedZomeError(
+		error,
+		UPDATE_CONFLICT_ERROR_PREFIX,
+	) as { current_head: Record } | undefined;
+	return conflict?.current_head;
+}
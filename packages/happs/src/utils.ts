@@ -19,6 +19,17 @@ export async function triggerFileDownload(
 	link.click();
 }
 
+/**
+ * Extracts the message of the validation rule that rejected a zome call, if any,
+ * so that it can be shown directly to the user
+ */
+export function validationErrorMessage(error: unknown): string | undefined {
+	const message = error instanceof Error ? error.message : `${error}`;
+	const match = message.match(/InvalidCommit error: (.+)/);
+	if (!match) return undefined;
+	return match[1].replace(/["')}\s]+$/, '');
+}
+
 export async function decodeBundle<T>(file: File): Promise<T> {
 	const bytes = await file.arrayBuffer();
 	const expanded = gunzipSync(new Uint8Array(bytes));
@@ -0,0 +1,22 @@
+import { decode } from '@msgpack/msgpack';
+
+/**
+ * Decodes an error returned by the zome that carries the MessagePack
+ * serialization of a typed error, encoded as hex after the given prefix
+ */
+export function decodeSerializedZomeError(
+	error: unknown,
+	prefix: string,
+): unknown | undefined {
+	const message = error instanceof Error ? error.message : `${error}`;
+	const start = message.indexOf(prefix);
+	if (start === -1) return undefined;
+	const hex = message.slice(start + prefix.length).match(/^(?:[0-9a-f]{2})+/);
+	if (!hex) return undefined;
+
+	const bytes = new Uint8Array(hex[0].length / 2);
+	for (let i = 0; i < bytes.length; i++) {
+		bytes[i] = parseInt(hex[0].slice(i * 2, i * 2 + 2), 16);
+	}
+	return decode(bytes);
+}
@@ -2,3 +2,5 @@ import { dirname } from "path";
 import { fileURLToPath } from "url";
 
 export const appPath = dirname(fileURLToPath(import.meta.url)) + "/../../workdir/happ-store.happ";
+
+export const fileStorageProviderAppPath = dirname(fileURLToPath(import.meta.url)) + "/../../workdir/file-storage-provider.happ";
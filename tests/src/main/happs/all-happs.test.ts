@@ -5,8 +5,7 @@ import { dhtSync, runScenario } from '@holochain/tryorama';
 import { decode } from '@msgpack/msgpack';
 import { assert, test } from 'vitest';
 
-import { sampleHapp } from '../../../../packages/happs/src/mocks.js';
-import { Happ } from '../../../../packages/happs/src/types.js';
+import { Happ, HappsPage } from '../../../../packages/happs/src/types.js';
 import { setup } from './setup.js';
 
 test('create a Happ and get all happs', async () => {
@@ -19,7 +18,7 @@ test('create a Happ and get all happs', async () => {
 
 		// Alice creates a Happ
 		const happ: EntryRecord<Happ> = await alice.store.client.createHapp(
-			await sampleHapp(alice.store.client),
+			await alice.sampleHapp(),
 		);
 		assert.ok(happ);
 
@@ -31,3 +30,123 @@ test('create a Happ and get all happs', async () => {
 		assert.deepEqual(happ.actionHash, Array.from(collectionOutput.keys())[0]);
 	});
 });
+
+test('get the catalog in sorted pages', async () => {
+	await runScenario(async scenario => {
+		const [alice, bob] = await setup(scenario);
+
+		// Alice creates three Happs
+		for (const name of ['Charlie', 'alpha', 'Bravo']) {
+			await alice.store.client.createHapp(await alice.sampleHapp({ name }));
+		}
+
+		await dhtSync([alice.player, bob.player], alice.player.cells[0].cell_id[0]);
+
+		// Bob gets the catalog sorted by name, two Happs at a time
+		const firstPage = await bob.store.client.getHappsPage('Name', 2, undefined);
+		assert.deepEqual(
+			firstPage.happs.map(
+				h => new EntryRecord<Happ>(h.latest_happ).entry.name,
+			),
+			['alpha', 'Bravo'],
+		);
+		assert.ok(firstPage.next_cursor);
+
+		const secondPage = await bob.store.client.getHappsPage(
+			'Name',
+			2,
+			firstPage.next_cursor,
+		);
+		assert.deepEqual(
+			secondPage.happs.map(
+				h => new EntryRecord<Happ>(h.latest_happ).entry.name,
+			),
+			['Charlie'],
+		);
+		assert.notOk(secondPage.next_cursor);
+
+		// Sorted by creation time, the newest Happ comes first
+		const byCreation = await bob.store.client.getHappsPage(
+			'CreatedTime',
+			10,
+			undefined,
+		);
+		assert.deepEqual(
+			byCreation.happs.map(
+				h => new EntryRecord<Happ>(h.latest_happ).entry.name,
+			),
+			['Bravo', 'alpha', 'Charlie'],
+		);
+	});
+});
+
+test('updates and releases move a Happ in the sorted pages', async () => {
+	await runScenario(async scenario => {
+		const [alice, bob] = await setup(scenario);
+
+		// Alice creates two Happs
+		const first = await alice.store.client.createHapp(
+			await alice.sampleHapp({ name: 'alpha' }),
+		);
+		await alice.store.client.createHapp(
+			await alice.sampleHapp({ name: 'bravo' }),
+		);
+
+		// Alice renames the first one and releases it
+		await alice.store.client.updateHapp(
+			first.actionHash,
+			first.actionHash,
+			await alice.sampleHapp({ name: 'charlie' }),
+		);
+		await alice.store.client.createHappRelease(
+			await alice.sampleHappRelease({
+				happ_hash: first.actionHash,
+				version: '0.1.0',
+			}),
+		);
+
+		await dhtSync([alice.player, bob.player], alice.player.cells[0].cell_id[0]);
+
+		const names = (page: HappsPage) =>
+			page.happs.map(h => new EntryRecord<Happ>(h.latest_happ).entry.name);
+
+		// The renamed Happ is only listed under its new name
+		const byName = await bob.store.client.getHappsPage('Name', 10, undefined);
+		assert.deepEqual(names(byName), ['bravo', 'charlie']);
+
+		// The released Happ comes first by its latest release
+		const byRelease = await bob.store.client.getHappsPage(
+			'LatestReleaseTime',
+			10,
+			undefined,
+		);
+		assert.deepEqual(names(byRelease), ['charlie', 'bravo']);
+	});
+});
+
+test('all happs are listed across the catalog shards', async () => {
+	await runScenario(async scenario => {
+		const [alice, bob] = await setup(scenario);
+
+		// Alice creates enough Happs to land in different shards
+		const happs: Array<EntryRecord<Happ>> = [];
+		for (let i = 0; i < 5; i++) {
+			happs.push(
+				await alice.store.client.createHapp(
+					await alice.sampleHapp({ name: `Happ ${i}` }),
+				),
+			);
+		}
+
+		// Alice deletes one of them
+		await alice.store.client.deleteHapp(happs[0].actionHash);
+
+		await dhtSync([alice.player, bob.player], alice.player.cells[0].cell_id[0]);
+
+		const links = await bob.store.client.getAllHapps();
+		assert.sameDeepMembers(
+			links.map(l => l.target),
+			happs.slice(1).map(h => h.actionHash),
+		);
+	});
+});
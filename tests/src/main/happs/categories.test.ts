@@ -0,0 +1,101 @@
+import { dhtSync, runScenario } from '@holochain/tryorama';
+import { assert, expect, test } from 'vitest';
+
+import { setup } from './setup.js';
+
+test('happs are indexed by category and tag', async () => {
+	await runScenario(async scenario => {
+		const [alice, bob] = await setup(scenario);
+
+		const happ = await alice.store.client.createHapp(
+			await alice.sampleHapp({
+				category: 'Games',
+				tags: ['chess', 'multiplayer'],
+			}),
+		);
+		await alice.store.client.createHapp(
+			await alice.sampleHapp({
+				category: 'Productivity',
+				tags: ['notes'],
+			}),
+		);
+
+		await dhtSync([alice.player, bob.player], alice.player.cells[0].cell_id[0]);
+
+		let links = await bob.store.client.getHappsByCategory('Games');
+		assert.equal(links.length, 1);
+		assert.deepEqual(links[0].target, happ.actionHash);
+
+		links = await bob.store.client.getHappsByTag('chess');
+		assert.equal(links.length, 1);
+		links = await bob.store.client.getHappsByTag('Multiplayer');
+		assert.equal(links.length, 1);
+		links = await bob.store.client.getHappsByTag('notes');
+		assert.equal(links.length, 1);
+
+		// Changing the category and the tags removes the stale index links
+		await alice.store.client.updateHapp(
+			happ.actionHash,
+			happ.actionHash,
+			await alice.sampleHapp({
+				category: 'Social',
+				tags: ['chess', 'friends'],
+			}),
+		);
+
+		await dhtSync([alice.player, bob.player], alice.player.cells[0].cell_id[0]);
+
+		links = await bob.store.client.getHappsByCategory('Games');
+		assert.equal(links.length, 0);
+		links = await bob.store.client.getHappsByCategory('Social');
+		assert.equal(links.length, 1);
+		links = await bob.store.client.getHappsByTag('multiplayer');
+		assert.equal(links.length, 0);
+		links = await bob.store.client.getHappsByTag('chess');
+		assert.equal(links.length, 1);
+		links = await bob.store.client.getHappsByTag('friends');
+		assert.equal(links.length, 1);
+
+		// Unpublished happs are left out unless asked for
+		await alice.store.client.unpublishHapp(happ.actionHash);
+
+		await dhtSync([alice.player, bob.player], alice.player.cells[0].cell_id[0]);
+
+		links = await bob.store.client.getHappsByCategory('Social');
+		assert.equal(links.length, 0);
+		links = await bob.store.client.getHappsByCategory('Social', true);
+		assert.equal(links.length, 1);
+	});
+});
+
+test('Happ tags are validated', async () => {
+	await runScenario(async scenario => {
+		const [alice] = await setup(scenario);
+
+		await expect(
+			alice.store.client.createHapp(
+				await alice.sampleHapp({
+					tags: Array.from({ length: 11 }, (_, i) => `tag-${i}`),
+				}),
+			),
+		).rejects.toThrow("A hApp can't have more than 10 tags.");
+
+		await expect(
+			alice.store.client.createHapp(
+				await alice.sampleHapp({ tags: ['a'.repeat(33)] }),
+			),
+		).rejects.toThrow();
+
+		await expect(
+			alice.store.client.createHapp(
+				await alice.sampleHapp({ tags: ['with.dot'] }),
+			),
+		).rejects.toThrow();
+
+		await expect(
+			alice.store.client.createHapp(
+				await alice.sampleHapp({ tags: ['chess', 'chess'] }),
+			),
+		).rejects.toThrow();
+	});
+});
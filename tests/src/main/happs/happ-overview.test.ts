@@ -0,0 +1,60 @@
+import { EntryRecord } from '@darksoil-studio/holochain-utils';
+import { dhtSync, runScenario } from '@holochain/tryorama';
+import { assert, test } from 'vitest';
+
+import { Happ, HappRelease } from '../../../../packages/happs/src/types.js';
+import { setup } from './setup.js';
+
+test('get the overview of a Happ in one call', async () => {
+	await runScenario(async scenario => {
+		const [alice, bob] = await setup(scenario);
+
+		// Alice creates a Happ with two releases
+		const happ = await alice.store.client.createHapp(await alice.sampleHapp());
+		const updatedHapp = await alice.store.client.updateHapp(
+			happ.actionHash,
+			happ.actionHash,
+			await alice.sampleHapp({ name: 'Updated name' }),
+		);
+		await alice.store.client.createHappRelease(
+			await alice.sampleHappRelease({
+				happ_hash: happ.actionHash,
+				version: '0.1.0',
+			}),
+		);
+		const release = await alice.store.client.createHappRelease(
+			await alice.sampleHappRelease({
+				happ_hash: happ.actionHash,
+				version: '0.2.0',
+			}),
+		);
+		const updatedRelease = await alice.store.client.updateHappRelease(
+			release.actionHash,
+			release.actionHash,
+			{ ...release.entry, changes: 'Fixed the changelog' },
+		);
+		await alice.store.client.unpublishHapp(happ.actionHash);
+
+		await dhtSync([alice.player, bob.player], alice.player.cells[0].cell_id[0]);
+
+		// Bob gets the overview of the Happ
+		const overview = await bob.store.client.getHappOverview(happ.actionHash);
+		assert.ok(overview);
+		assert.deepEqual(overview!.original_happ_hash, happ.actionHash);
+		assert.deepEqual(overview!.publisher, alice.player.agentPubKey);
+		assert.deepEqual(
+			new EntryRecord<Happ>(overview!.latest_happ).actionHash,
+			updatedHapp.actionHash,
+		);
+		assert.ok(overview!.unpublished);
+
+		const releases = overview!.releases.map(
+			r => new EntryRecord<HappRelease>(r),
+		);
+		assert.deepEqual(
+			releases.map(r => r.entry.version),
+			['0.2.0', '0.1.0'],
+		);
+		assert.deepEqual(releases[0].actionHash, updatedRelease.actionHash);
+	});
+});
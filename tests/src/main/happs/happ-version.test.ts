@@ -1,5 +1,4 @@
-import { HappRelease } from '@darksoil-studio/happs-zome';
-import { sampleHappRelease } from '@darksoil-studio/happs-zome/dist/mocks.js';
+import { Happ, HappRelease } from '@darksoil-studio/happs-zome';
 import {
 	ActionHash,
 	Delete,
@@ -11,9 +10,9 @@ import { decode } from '@msgpack/msgpack';
 import { toPromise } from '@darksoil-studio/holochain-signals';
 import { EntryRecord } from '@darksoil-studio/holochain-utils';
 import { cleanNodeDecoding } from '@darksoil-studio/holochain-utils/dist/clean-node-decoding.js';
-import { assert, test } from 'vitest';
+import { assert, expect, test } from 'vitest';
 
-import { setup } from './setup.js';
+import { setup, uploadIcon, uploadWebHappBundle } from './setup.js';
 
 test('create HappRelease', async () => {
 	await runScenario(async scenario => {
@@ -22,7 +21,7 @@ test('create HappRelease', async () => {
 		// Alice creates a HappRelease
 		const happRelease: EntryRecord<HappRelease> =
 			await alice.store.client.createHappRelease(
-				await sampleHappRelease(alice.store.client),
+				await alice.sampleHappRelease(),
 			);
 		assert.ok(happRelease);
 	});
@@ -32,7 +31,7 @@ test('create and read HappRelease', async () => {
 	await runScenario(async scenario => {
 		const [alice, bob] = await setup(scenario);
 
-		const sample = await sampleHappRelease(alice.store.client);
+		const sample = await alice.sampleHappRelease();
 
 		// Alice creates a HappRelease
 		const happRelease: EntryRecord<HappRelease> =
@@ -57,14 +56,16 @@ test('create and update HappRelease', async () => {
 		// Alice creates a HappRelease
 		const happRelease: EntryRecord<HappRelease> =
 			await alice.store.client.createHappRelease(
-				await sampleHappRelease(alice.store.client),
+				await alice.sampleHappRelease(),
 			);
 		assert.ok(happRelease);
 
 		const originalActionHash = happRelease.actionHash;
 
 		// Alice updates the HappRelease
-		let contentUpdate = await sampleHappRelease(alice.store.client);
+		let contentUpdate = await alice.sampleHappRelease({
+			happ_hash: happRelease.entry.happ_hash,
+		});
 
 		let updatedHappRelease: EntryRecord<HappRelease> =
 			await alice.store.client.updateHappRelease(
@@ -87,7 +88,9 @@ test('create and update HappRelease', async () => {
 		);
 
 		// Alice updates the HappRelease again
-		contentUpdate = await sampleHappRelease(alice.store.client);
+		contentUpdate = await alice.sampleHappRelease({
+			happ_hash: happRelease.entry.happ_hash,
+		});
 
 		updatedHappRelease = await alice.store.client.updateHappRelease(
 			originalActionHash,
@@ -109,3 +112,382 @@ test('create and update HappRelease', async () => {
 		);
 	});
 });
+
+test("another agent cannot create a HappRelease for someone else's Happ", async () => {
+	await runScenario(async scenario => {
+		const [alice, bob] = await setup(scenario);
+
+		// Alice creates a Happ
+		const happ = await alice.store.client.createHapp(await alice.sampleHapp());
+
+		await dhtSync([alice.player, bob.player], alice.player.cells[0].cell_id[0]);
+
+		// Bob tries to publish a release for Alice's Happ
+		await expect(
+			bob.store.client.createHappRelease(
+				await bob.sampleHappRelease({
+					happ_hash: happ.actionHash,
+				}),
+			),
+		).rejects.toThrow();
+	});
+});
+
+test('a HappRelease cannot be linked as a release of another Happ', async () => {
+	await runScenario(async scenario => {
+		const [alice] = await setup(scenario);
+
+		// Alice creates two Happs and releases the first one
+		const first = await alice.store.client.createHapp(await alice.sampleHapp());
+		const second = await alice.store.client.createHapp(
+			await alice.sampleHapp(),
+		);
+		const release = await alice.store.client.createHappRelease(
+			await alice.sampleHappRelease({
+				happ_hash: first.actionHash,
+				version: '0.1.0',
+			}),
+		);
+
+		// The release can't be listed under the second Happ
+		await expect(
+			alice.player.cells[0].callZome({
+				zome_name: 'happs',
+				fn_name: 'create_happ_to_happ_releases_link',
+				payload: {
+					happ_hash: second.actionHash,
+					happ_release_hash: release.actionHash,
+				},
+			}),
+		).rejects.toThrow('must be created from the hApp of the release');
+	});
+});
+
+test('HappRelease versions must be valid semver and strictly increasing', async () => {
+	await runScenario(async scenario => {
+		const [alice, bob] = await setup(scenario);
+
+		const happ = await alice.store.client.createHapp(await alice.sampleHapp());
+
+		// Malformed versions are rejected
+		await expect(
+			alice.store.client.createHappRelease(
+				await alice.sampleHappRelease({
+					happ_hash: happ.actionHash,
+					version: 'v1',
+				}),
+			),
+		).rejects.toThrow();
+
+		await alice.store.client.createHappRelease(
+			await alice.sampleHappRelease({
+				happ_hash: happ.actionHash,
+				version: '1.0.0-beta.1+build.5',
+			}),
+		);
+		await alice.store.client.createHappRelease(
+			await alice.sampleHappRelease({
+				happ_hash: happ.actionHash,
+				version: '1.0.0',
+			}),
+		);
+
+		// Versions that are not greater than the highest release are rejected.
+		// Validation only sees the releases of the same maintainer, see
+		// maintainers.test.ts for the releases of other maintainers
+		await expect(
+			alice.store.client.createHappRelease(
+				await alice.sampleHappRelease({
+					happ_hash: happ.actionHash,
+					version: '1.0.0+build.6',
+				}),
+			),
+		).rejects.toThrow();
+		await expect(
+			alice.store.client.createHappRelease(
+				await alice.sampleHappRelease({
+					happ_hash: happ.actionHash,
+					version: '0.9.0',
+				}),
+			),
+		).rejects.toThrow();
+
+		await alice.store.client.createHappRelease(
+			await alice.sampleHappRelease({
+				happ_hash: happ.actionHash,
+				version: '1.2.0',
+			}),
+		);
+
+		await dhtSync([alice.player, bob.player], alice.player.cells[0].cell_id[0]);
+
+		// Bob gets the releases sorted by version, newest first
+		const links = await bob.store.client.getHappReleasesForHapp(
+			happ.actionHash,
+		);
+		assert.deepEqual(
+			links.map(l => new TextDecoder().decode(l.tag)),
+			['1.2.0', '1.0.0', '1.0.0-beta.1+build.5'],
+		);
+	});
+});
+
+test('HappRelease bundles must be webhapps stored in the file storage', async () => {
+	await runScenario(async scenario => {
+		const [alice] = await setup(scenario);
+
+		await expect(
+			alice.store.client.createHappRelease(
+				await alice.sampleHappRelease({
+					web_happ_bundle_hash: await uploadIcon(alice.fileStorage),
+				}),
+			),
+		).rejects.toThrow('has an unexpected type');
+	});
+});
+
+test('releases are indexed by channel', async () => {
+	await runScenario(async scenario => {
+		const [alice, bob] = await setup(scenario);
+
+		const happ: EntryRecord<Happ> = await alice.store.client.createHapp(
+			await alice.sampleHapp(),
+		);
+
+		await alice.store.client.createHappRelease(
+			await alice.sampleHappRelease({
+				happ_hash: happ.actionHash,
+				version: '1.0.0',
+			}),
+		);
+		await alice.store.client.createHappRelease(
+			await alice.sampleHappRelease({
+				happ_hash: happ.actionHash,
+				version: '2.0.0-beta.1',
+				channel: { type: 'Beta' },
+			}),
+		);
+		// Versions only need to increase within each channel
+		await alice.store.client.createHappRelease(
+			await alice.sampleHappRelease({
+				happ_hash: happ.actionHash,
+				version: '1.0.1',
+			}),
+		);
+		await alice.store.client.createHappRelease(
+			await alice.sampleHappRelease({
+				happ_hash: happ.actionHash,
+				version: '0.1.0',
+				channel: { type: 'Custom', value: 'testers' },
+			}),
+		);
+
+		// Custom channels can't reuse the predefined names
+		await expect(
+			alice.store.client.createHappRelease(
+				await alice.sampleHappRelease({
+					happ_hash: happ.actionHash,
+					version: '3.0.0',
+					channel: { type: 'Custom', value: 'stable' },
+				}),
+			),
+		).rejects.toThrow();
+
+		await dhtSync([alice.player, bob.player], alice.player.cells[0].cell_id[0]);
+
+		const latestStable = await bob.store.client.getLatestReleaseInChannel(
+			happ.actionHash,
+			{ type: 'Stable' },
+		);
+		assert.equal(latestStable!.entry.version, '1.0.1');
+
+		const latestBeta = await bob.store.client.getLatestReleaseInChannel(
+			happ.actionHash,
+			{ type: 'Beta' },
+		);
+		assert.equal(latestBeta!.entry.version, '2.0.0-beta.1');
+
+		const testers = await bob.store.client.getHappReleasesInChannel(
+			happ.actionHash,
+			{ type: 'Custom', value: 'testers' },
+		);
+		assert.equal(testers.length, 1);
+
+		const nightly = await bob.store.client.getLatestReleaseInChannel(
+			happ.actionHash,
+			{ type: 'Nightly' },
+		);
+		assert.notOk(nightly);
+	});
+});
+
+test('yanked releases are skipped by the latest release queries', async () => {
+	await runScenario(async scenario => {
+		const [alice, bob] = await setup(scenario);
+
+		const happ: EntryRecord<Happ> = await alice.store.client.createHapp(
+			await alice.sampleHapp(),
+		);
+		await alice.store.client.createHappRelease(
+			await alice.sampleHappRelease({
+				happ_hash: happ.actionHash,
+				version: '1.0.0',
+			}),
+		);
+		const broken = await alice.store.client.createHappRelease(
+			await alice.sampleHappRelease({
+				happ_hash: happ.actionHash,
+				version: '1.1.0',
+			}),
+		);
+
+		await dhtSync([alice.player, bob.player], alice.player.cells[0].cell_id[0]);
+
+		// Bob can't yank Alice's release
+		await expect(
+			bob.store.client.yankHappRelease(
+				broken.actionHash,
+				'Not mine to yank',
+				undefined,
+			),
+		).rejects.toThrow();
+
+		// Alice yanks it
+		await alice.store.client.yankHappRelease(
+			broken.actionHash,
+			'Corrupts the local data',
+			'Critical',
+		);
+
+		await dhtSync([alice.player, bob.player], alice.player.cells[0].cell_id[0]);
+
+		const yankLinks = await bob.store.client.getHappReleaseYankLinks(
+			broken.actionHash,
+		);
+		assert.equal(yankLinks.length, 1);
+		const yank = await bob.store.client.getHappReleaseYank(
+			yankLinks[0].target,
+		);
+		assert.equal(yank!.entry.reason, 'Corrupts the local data');
+		assert.equal(yank!.entry.severity, 'Critical');
+
+		const latest = await bob.store.client.getLatestReleaseInChannel(
+			happ.actionHash,
+			{ type: 'Stable' },
+		);
+		assert.equal(latest!.entry.version, '1.0.0');
+	});
+});
+
+test('check for updates of installed happs', async () => {
+	await runScenario(async scenario => {
+		const [alice, bob] = await setup(scenario);
+
+		const happ: EntryRecord<Happ> = await alice.store.client.createHapp(
+			await alice.sampleHapp(),
+		);
+		const installed = await alice.store.client.createHappRelease(
+			await alice.sampleHappRelease({
+				happ_hash: happ.actionHash,
+				version: '1.0.0',
+				changes: 'First release',
+				web_happ_bundle_hash: await uploadWebHappBundle(
+					alice.fileStorage,
+					new Uint8Array([1, 0, 0]),
+				),
+			}),
+		);
+		await alice.store.client.createHappRelease(
+			await alice.sampleHappRelease({
+				happ_hash: happ.actionHash,
+				version: '1.1.0',
+				changes: 'New features',
+			}),
+		);
+		const yanked = await alice.store.client.createHappRelease(
+			await alice.sampleHappRelease({
+				happ_hash: happ.actionHash,
+				version: '1.2.0',
+			}),
+		);
+		await alice.store.client.yankHappRelease(
+			yanked.actionHash,
+			'Broken',
+			undefined,
+		);
+		await alice.store.client.createHappRelease(
+			await alice.sampleHappRelease({
+				happ_hash: happ.actionHash,
+				version: '2.0.0-beta.1',
+				channel: { type: 'Beta' },
+			}),
+		);
+
+		await dhtSync([alice.player, bob.player], alice.player.cells[0].cell_id[0]);
+
+		const checks = await bob.store.client.checkForUpdates([
+			{
+				happ_hash: happ.actionHash,
+				installed: {
+					type: 'WebHappBundleHash',
+					value: installed.entry.web_happ_bundle_hash,
+				},
+				channel: undefined,
+			},
+			{
+				happ_hash: happ.actionHash,
+				installed: { type: 'Version', value: '1.1.0' },
+				channel: undefined,
+			},
+			{
+				happ_hash: happ.actionHash,
+				installed: { type: 'Version', value: '1.1.0' },
+				channel: { type: 'Beta' },
+			},
+		]);
+
+		// The yanked release is skipped
+		assert.equal(checks[0].available_update!.version, '1.1.0');
+		assert.equal(checks[0].available_update!.changes, 'New features');
+		assert.notOk(checks[1].available_update);
+		assert.equal(checks[2].available_update!.version, '2.0.0-beta.1');
+		assert.ok(checks.every(check => !check.error));
+	});
+});
+
+test('an invalid installed version only fails its own update check', async () => {
+	await runScenario(async scenario => {
+		const [alice, bob] = await setup(scenario);
+
+		const happ: EntryRecord<Happ> = await alice.store.client.createHapp(
+			await alice.sampleHapp(),
+		);
+		await alice.store.client.createHappRelease(
+			await alice.sampleHappRelease({
+				happ_hash: happ.actionHash,
+				version: '1.1.0',
+			}),
+		);
+
+		await dhtSync([alice.player, bob.player], alice.player.cells[0].cell_id[0]);
+
+		const checks = await bob.store.client.checkForUpdates([
+			{
+				happ_hash: happ.actionHash,
+				installed: { type: 'Version', value: 'not-a-version' },
+				channel: undefined,
+			},
+			{
+				happ_hash: happ.actionHash,
+				installed: { type: 'Version', value: '1.0.0' },
+				channel: undefined,
+			},
+		]);
+
+		assert.equal(checks.length, 2);
+		assert.ok(checks[0].error);
+		assert.notOk(checks[0].available_update);
+		assert.notOk(checks[1].error);
+		assert.equal(checks[1].available_update!.version, '1.1.0');
+	});
+});
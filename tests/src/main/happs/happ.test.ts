@@ -6,14 +6,16 @@ import {
 	Delete,
 	Record,
 	SignedActionHashed,
+	fakeEntryHash,
 } from '@holochain/client';
 import { dhtSync, runScenario } from '@holochain/tryorama';
 import { decode } from '@msgpack/msgpack';
-import { assert, test } from 'vitest';
+import { assert, expect, test } from 'vitest';
 
-import { sampleHapp } from '../../../../packages/happs/src/mocks.js';
+import { StoredFileError } from '../../../../packages/happs/src/stored-file-error.js';
 import { Happ } from '../../../../packages/happs/src/types.js';
-import { setup } from './setup.js';
+import { UpdateConflictError } from '../../../../packages/happs/src/update-conflict.js';
+import { setup, uploadWebHappBundle } from './setup.js';
 
 test('create Happ', async () => {
 	await runScenario(async scenario => {
@@ -21,7 +23,7 @@ test('create Happ', async () => {
 
 		// Alice creates a Happ
 		const happ: EntryRecord<Happ> = await alice.store.client.createHapp(
-			await sampleHapp(alice.store.client),
+			await alice.sampleHapp(),
 		);
 		assert.ok(happ);
 	});
@@ -31,7 +33,7 @@ test('create and read Happ', async () => {
 	await runScenario(async scenario => {
 		const [alice, bob] = await setup(scenario);
 
-		const sample = await sampleHapp(alice.store.client);
+		const sample = await alice.sampleHapp();
 
 		// Alice creates a Happ
 		const happ: EntryRecord<Happ> = await alice.store.client.createHapp(sample);
@@ -54,14 +56,14 @@ test('create and update Happ', async () => {
 
 		// Alice creates a Happ
 		const happ: EntryRecord<Happ> = await alice.store.client.createHapp(
-			await sampleHapp(alice.store.client),
+			await alice.sampleHapp(),
 		);
 		assert.ok(happ);
 
 		const originalActionHash = happ.actionHash;
 
 		// Alice updates the Happ
-		let contentUpdate = await sampleHapp(alice.store.client);
+		let contentUpdate = await alice.sampleHapp();
 
 		let updatedHapp: EntryRecord<Happ> = await alice.store.client.updateHapp(
 			originalActionHash,
@@ -83,7 +85,7 @@ test('create and update Happ', async () => {
 		);
 
 		// Alice updates the Happ again
-		contentUpdate = await sampleHapp(alice.store.client);
+		contentUpdate = await alice.sampleHapp();
 
 		updatedHapp = await alice.store.client.updateHapp(
 			originalActionHash,
@@ -112,7 +114,7 @@ test('create and delete Happ', async () => {
 
 		// Alice creates a Happ
 		const happ: EntryRecord<Happ> = await alice.store.client.createHapp(
-			await sampleHapp(alice.store.client),
+			await alice.sampleHapp(),
 		);
 		assert.ok(happ);
 
@@ -132,3 +134,313 @@ test('create and delete Happ', async () => {
 		assert.equal(deletes.length, 1);
 	});
 });
+
+test("another agent cannot update someone else's Happ", async () => {
+	await runScenario(async scenario => {
+		const [alice, bob] = await setup(scenario);
+
+		// Alice creates a Happ
+		const happ: EntryRecord<Happ> = await alice.store.client.createHapp(
+			await alice.sampleHapp(),
+		);
+		assert.ok(happ);
+
+		await dhtSync([alice.player, bob.player], alice.player.cells[0].cell_id[0]);
+
+		// Bob tries to update Alice's Happ
+		await expect(
+			bob.store.client.updateHapp(
+				happ.actionHash,
+				happ.actionHash,
+				await bob.sampleHapp({ name: 'Hijacked' }),
+			),
+		).rejects.toThrow();
+	});
+});
+
+test("another agent cannot delete someone else's Happ", async () => {
+	await runScenario(async scenario => {
+		const [alice, bob] = await setup(scenario);
+
+		// Alice creates a Happ
+		const happ: EntryRecord<Happ> = await alice.store.client.createHapp(
+			await alice.sampleHapp(),
+		);
+		assert.ok(happ);
+
+		await dhtSync([alice.player, bob.player], alice.player.cells[0].cell_id[0]);
+
+		// Bob tries to delete Alice's Happ
+		await expect(
+			bob.store.client.deleteHapp(happ.actionHash),
+		).rejects.toThrow();
+
+		await dhtSync([alice.player, bob.player], alice.player.cells[0].cell_id[0]);
+
+		// The Happ is still listed
+		const deletes: Array<SignedActionHashed<Delete>> = await toPromise(
+			alice.store.happs.get(happ.actionHash).deletes,
+		);
+		assert.equal(deletes.length, 0);
+		const allHapps = await toPromise(alice.store.allHapps);
+		assert.equal(allHapps.size, 1);
+	});
+});
+
+test('Happ fields are validated on create and update', async () => {
+	await runScenario(async scenario => {
+		const [alice] = await setup(scenario);
+
+		await expect(
+			alice.store.client.createHapp(
+				await alice.sampleHapp({ name: '   ' }),
+			),
+		).rejects.toThrow("The name of the hApp can't be empty.");
+		await expect(
+			alice.store.client.createHapp(
+				await alice.sampleHapp({ name: 'a'.repeat(65) }),
+			),
+		).rejects.toThrow();
+		await expect(
+			alice.store.client.createHapp(
+				await alice.sampleHapp({ name: 'My\u0007hApp' }),
+			),
+		).rejects.toThrow();
+		await expect(
+			alice.store.client.createHapp(
+				await alice.sampleHapp({
+					description: 'a'.repeat(5001),
+				}),
+			),
+		).rejects.toThrow();
+
+		const happ: EntryRecord<Happ> = await alice.store.client.createHapp(
+			await alice.sampleHapp({
+				description: 'First line\nSecond line',
+			}),
+		);
+
+		await expect(
+			alice.store.client.updateHapp(
+				happ.actionHash,
+				happ.actionHash,
+				await alice.sampleHapp({ name: '' }),
+			),
+		).rejects.toThrow();
+	});
+});
+
+test('Happ icons must be images stored in the file storage', async () => {
+	await runScenario(async scenario => {
+		const [alice] = await setup(scenario);
+
+		// The icon doesn't exist
+		const missingIcon = await fakeEntryHash();
+		const error = await alice.store.client
+			.createHapp(await alice.sampleHapp({ icon: missingIcon }))
+			.catch(e => e);
+		assert.ok(error instanceof StoredFileError);
+		assert.equal(error.problem.type, 'NotFound');
+		assert.deepEqual(error.problem.file_hash, missingIcon);
+		assert.include(error.message, 'was not found in the file storage');
+
+		// The icon is not an image
+		await expect(
+			alice.store.client.createHapp(
+				await alice.sampleHapp({
+					icon: await uploadWebHappBundle(alice.fileStorage),
+				}),
+			),
+		).rejects.toThrow('has an unexpected type');
+	});
+});
+
+test("another agent cannot unpublish or republish someone else's Happ", async () => {
+	await runScenario(async scenario => {
+		const [alice, bob] = await setup(scenario);
+
+		// Alice creates a Happ
+		const happ: EntryRecord<Happ> = await alice.store.client.createHapp(
+			await alice.sampleHapp(),
+		);
+
+		await dhtSync([alice.player, bob.player], alice.player.cells[0].cell_id[0]);
+
+		// Bob tries to unpublish Alice's Happ
+		await expect(
+			bob.store.client.unpublishHapp(happ.actionHash),
+		).rejects.toThrow();
+
+		// Alice unpublishes her Happ
+		await alice.store.client.unpublishHapp(happ.actionHash);
+
+		await dhtSync([alice.player, bob.player], alice.player.cells[0].cell_id[0]);
+
+		// Bob tries to republish it
+		await expect(
+			bob.store.client.republishHapp(happ.actionHash),
+		).rejects.toThrow();
+
+		// Alice can republish it
+		await alice.store.client.republishHapp(happ.actionHash);
+	});
+});
+
+test('a Happ cannot be linked as the update of another Happ', async () => {
+	await runScenario(async scenario => {
+		const [alice] = await setup(scenario);
+
+		// Alice creates two Happs
+		const happ: EntryRecord<Happ> = await alice.store.client.createHapp(
+			await alice.sampleHapp({ name: 'First' }),
+		);
+		const otherHapp: EntryRecord<Happ> = await alice.store.client.createHapp(
+			await alice.sampleHapp({ name: 'Second' }),
+		);
+
+		// Alice tries to link an update of the second Happ as a revision of the first one
+		await expect(
+			alice.store.client.updateHapp(
+				happ.actionHash,
+				otherHapp.actionHash,
+				await alice.sampleHapp({ name: 'Second, updated' }),
+			),
+		).rejects.toThrow();
+	});
+});
+
+test('the latest revision of a Happ is at the end of its update chain', async () => {
+	await runScenario(async scenario => {
+		const [alice, bob] = await setup(scenario);
+
+		// Alice creates a Happ
+		const happ: EntryRecord<Happ> = await alice.store.client.createHapp(
+			await alice.sampleHapp({ name: 'Original' }),
+		);
+
+		// Alice updates the Happ twice in a row
+		const first = await alice.store.client.updateHapp(
+			happ.actionHash,
+			happ.actionHash,
+			await alice.sampleHapp({ name: 'First' }),
+		);
+		const second = await alice.store.client.updateHapp(
+			happ.actionHash,
+			first.actionHash,
+			await alice.sampleHapp({ name: 'Second' }),
+		);
+
+		await dhtSync([alice.player, bob.player], alice.player.cells[0].cell_id[0]);
+
+		const heads = await bob.store.client.getHappHeads(happ.actionHash);
+		assert.deepEqual(
+			new EntryRecord<Happ>(heads!.canonical_head).actionHash,
+			second.actionHash,
+		);
+		assert.equal(heads!.conflicting_heads.length, 0);
+	});
+});
+
+test('concurrent edits of a Happ are reported as conflicting heads', async () => {
+	await runScenario(async scenario => {
+		const [alice, bob] = await setup(scenario);
+
+		// Alice creates a Happ and adds Bob as a maintainer
+		const happ: EntryRecord<Happ> = await alice.store.client.createHapp(
+			await alice.sampleHapp({ name: 'Original' }),
+		);
+		await alice.store.client.addMaintainer(
+			happ.actionHash,
+			bob.player.agentPubKey,
+		);
+
+		await dhtSync([alice.player, bob.player], alice.player.cells[0].cell_id[0]);
+
+		// Both update the original revision before seeing each other's update
+		const aliceUpdate = await alice.store.client.updateHapp(
+			happ.actionHash,
+			happ.actionHash,
+			await alice.sampleHapp({ name: 'Alice' }),
+		);
+		const bobUpdate = await bob.store.client.updateHapp(
+			happ.actionHash,
+			happ.actionHash,
+			await bob.sampleHapp({ name: 'Bob' }),
+		);
+
+		await dhtSync([alice.player, bob.player], alice.player.cells[0].cell_id[0]);
+
+		const heads = await bob.store.client.getHappHeads(happ.actionHash);
+		const canonicalHead = new EntryRecord<Happ>(heads!.canonical_head);
+		const allHeads = [
+			heads!.canonical_head,
+			...heads!.conflicting_heads,
+		].map(r => new EntryRecord<Happ>(r).actionHash);
+		assert.sameDeepMembers(allHeads, [
+			aliceUpdate.actionHash,
+			bobUpdate.actionHash,
+		]);
+
+		// Updating the conflicting head fails, pointing to the canonical one
+		const conflictingHead = new EntryRecord<Happ>(
+			heads!.conflicting_heads[0],
+		);
+		try {
+			await alice.store.client.updateHapp(
+				happ.actionHash,
+				conflictingHead.actionHash,
+				await alice.sampleHapp({ name: 'Merged' }),
+			);
+			assert.fail('Updating the conflicting head should have been rejected');
+		} catch (e) {
+			assert.instanceOf(e, UpdateConflictError);
+			assert.deepEqual(
+				(e as UpdateConflictError<Happ>).currentHead.actionHash,
+				canonicalHead.actionHash,
+			);
+		}
+
+		// Updating the canonical head resolves the conflict in its favor
+		await alice.store.client.updateHapp(
+			happ.actionHash,
+			canonicalHead.actionHash,
+			await alice.sampleHapp({ name: 'Merged' }),
+		);
+	});
+});
+
+test('updating a stale revision of a Happ fails with a conflict', async () => {
+	await runScenario(async scenario => {
+		const [alice] = await setup(scenario);
+
+		// Alice creates a Happ and updates it
+		const happ: EntryRecord<Happ> = await alice.store.client.createHapp(
+			await alice.sampleHapp({ name: 'Original' }),
+		);
+		const first = await alice.store.client.updateHapp(
+			happ.actionHash,
+			happ.actionHash,
+			await alice.sampleHapp({ name: 'First' }),
+		);
+
+		// Alice tries to update the original revision again
+		try {
+			await alice.store.client.updateHapp(
+				happ.actionHash,
+				happ.actionHash,
+				await alice.sampleHapp({ name: 'Stale' }),
+			);
+			assert.fail('The stale update should have been rejected');
+		} catch (e) {
+			assert.instanceOf(e, UpdateConflictError);
+			assert.deepEqual(
+				(e as UpdateConflictError<Happ>).currentHead.actionHash,
+				first.actionHash,
+			);
+		}
+
+		// No conflicting head was created
+		const heads = await alice.store.client.getHappHeads(happ.actionHash);
+		assert.equal(heads!.conflicting_heads.length, 0);
+	});
+});
@@ -0,0 +1,165 @@
+import { dhtSync, pause, runScenario } from '@holochain/tryorama';
+import { assert, expect, test } from 'vitest';
+
+import { setup } from './setup.js';
+
+test('maintainers can manage the releases of a Happ until they are removed', async () => {
+	await runScenario(async scenario => {
+		const [alice, bob] = await setup(scenario);
+
+		// Alice creates a Happ
+		const happ = await alice.store.client.createHapp(await alice.sampleHapp());
+
+		await dhtSync([alice.player, bob.player], alice.player.cells[0].cell_id[0]);
+
+		// Bob is not a maintainer yet
+		await expect(
+			bob.store.client.createHappRelease(
+				await bob.sampleHappRelease({
+					happ_hash: happ.actionHash,
+					version: '0.1.0',
+				}),
+			),
+		).rejects.toThrow();
+
+		// Only the owner can add maintainers
+		await expect(
+			bob.store.client.addMaintainer(happ.actionHash, bob.player.agentPubKey),
+		).rejects.toThrow();
+
+		// Alice adds Bob as a maintainer
+		await alice.store.client.addMaintainer(
+			happ.actionHash,
+			bob.player.agentPubKey,
+		);
+
+		await dhtSync([alice.player, bob.player], alice.player.cells[0].cell_id[0]);
+
+		let maintainers = await bob.store.client.getMaintainers(happ.actionHash);
+		assert.deepEqual(maintainers, [
+			alice.player.agentPubKey,
+			bob.player.agentPubKey,
+		]);
+
+		// Bob can now release and update the Happ
+		await bob.store.client.createHappRelease(
+			await bob.sampleHappRelease({
+				happ_hash: happ.actionHash,
+				version: '0.1.0',
+			}),
+		);
+
+		await dhtSync([alice.player, bob.player], alice.player.cells[0].cell_id[0]);
+
+		// Versions are checked against the releases of all maintainers by the
+		// zome before creating the release. Validation can only check them
+		// against the releases in the source chain of each maintainer, so an
+		// agent with a modified zome could still release 0.1.0 again
+		await expect(
+			alice.store.client.createHappRelease(
+				await alice.sampleHappRelease({
+					happ_hash: happ.actionHash,
+					version: '0.1.0',
+				}),
+			),
+		).rejects.toThrow('0.1.0 was already released in the stable channel');
+
+		await bob.store.client.updateHapp(
+			happ.actionHash,
+			happ.actionHash,
+			await bob.sampleHapp(),
+		);
+		await bob.store.client.unpublishHapp(happ.actionHash);
+		await bob.store.client.republishHapp(happ.actionHash);
+
+		// Alice removes Bob as a maintainer
+		await alice.store.client.removeMaintainer(
+			happ.actionHash,
+			bob.player.agentPubKey,
+		);
+
+		await dhtSync([alice.player, bob.player], alice.player.cells[0].cell_id[0]);
+
+		maintainers = await bob.store.client.getMaintainers(happ.actionHash);
+		assert.deepEqual(maintainers, [alice.player.agentPubKey]);
+
+		// Bob can't release anymore
+		await expect(
+			bob.store.client.createHappRelease(
+				await bob.sampleHappRelease({
+					happ_hash: happ.actionHash,
+					version: '0.2.0',
+				}),
+			),
+		).rejects.toThrow();
+	});
+});
+
+test('maintainer claims expire unless the owner keeps being active', async () => {
+	await runScenario(async scenario => {
+		const [alice, bob] = await setup(scenario, 2, () => ({
+			claim_validity_secs: 10,
+		}));
+
+		// Alice creates a Happ and adds Bob as a maintainer
+		const happ = await alice.store.client.createHapp(await alice.sampleHapp());
+		await alice.store.client.addMaintainer(
+			happ.actionHash,
+			bob.player.agentPubKey,
+		);
+
+		await dhtSync([alice.player, bob.player], alice.player.cells[0].cell_id[0]);
+
+		await bob.store.client.createHappRelease(
+			await bob.sampleHappRelease({
+				happ_hash: happ.actionHash,
+				version: '0.1.0',
+			}),
+		);
+
+		// Alice stays inactive for longer than the validity of Bob's claim,
+		// so Bob has no later action of Alice to point his claim to
+		await pause(11_000);
+
+		await expect(
+			bob.store.client.createHappRelease(
+				await bob.sampleHappRelease({
+					happ_hash: happ.actionHash,
+					version: '0.2.0',
+				}),
+			),
+		).rejects.toThrow();
+
+		// Once Alice is active again, Bob can refresh his claim
+		const updatedHapp = await alice.store.client.updateHapp(
+			happ.actionHash,
+			happ.actionHash,
+			await alice.sampleHapp({ name: 'Renamed' }),
+		);
+
+		await dhtSync([alice.player, bob.player], alice.player.cells[0].cell_id[0]);
+
+		await bob.store.client.createHappRelease(
+			await bob.sampleHappRelease({
+				happ_hash: happ.actionHash,
+				version: '0.2.0',
+			}),
+		);
+
+		// After Alice removes him, Bob's last claim points to an action of
+		// Alice from before the removal, which expires as well
+		await alice.store.client.removeMaintainer(
+			happ.actionHash,
+			bob.player.agentPubKey,
+		);
+		await pause(11_000);
+
+		await expect(
+			bob.store.client.updateHapp(
+				happ.actionHash,
+				updatedHapp.actionHash,
+				await bob.sampleHapp({ name: 'Taken over' }),
+			),
+		).rejects.toThrow();
+	});
+});
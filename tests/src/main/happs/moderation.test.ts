@@ -0,0 +1,133 @@
+import { encodeHashToBase64 } from '@holochain/client';
+import { dhtSync, pause, runScenario } from '@holochain/tryorama';
+import { assert, expect, test } from 'vitest';
+
+import { setup } from './setup.js';
+
+test('moderators appointed by the progenitor hide listings from the catalog', async () => {
+	await runScenario(async scenario => {
+		const [alice, bob, carol] = await setup(scenario, 3, agents => ({
+			progenitor: encodeHashToBase64(agents[0]),
+			initial_moderators: [],
+		}));
+
+		// Bob gets vetted by the progenitor and publishes a Happ
+		await bob.store.client.commitPublisherCredential(
+			await alice.store.client.signPublisherCredential(bob.player.agentPubKey),
+		);
+		const happ = await bob.store.client.createHapp(
+			await bob.sampleHapp({ name: 'Totally legit wallet' }),
+		);
+
+		await dhtSync(
+			[alice.player, bob.player, carol.player],
+			alice.player.cells[0].cell_id[0],
+		);
+
+		// Carol is not a moderator yet
+		await expect(
+			carol.store.client.hideHapp(happ.actionHash, 'Phishing'),
+		).rejects.toThrow();
+
+		// Only the progenitor can appoint moderators
+		await expect(
+			bob.store.client.addModerator(bob.player.agentPubKey),
+		).rejects.toThrow();
+
+		await alice.store.client.addModerator(carol.player.agentPubKey);
+
+		await dhtSync(
+			[alice.player, bob.player, carol.player],
+			alice.player.cells[0].cell_id[0],
+		);
+
+		assert.deepEqual(await bob.store.client.getModerators(), [
+			alice.player.agentPubKey,
+			carol.player.agentPubKey,
+		]);
+
+		// The reason is limited in bytes, as it's stored in a link tag
+		await expect(
+			carol.store.client.hideHapp(happ.actionHash, 'é'.repeat(251)),
+		).rejects.toThrow("can't be longer than 500 bytes");
+
+		await carol.store.client.hideHapp(happ.actionHash, 'Phishing');
+
+		await dhtSync(
+			[alice.player, bob.player, carol.player],
+			alice.player.cells[0].cell_id[0],
+		);
+
+		// The hidden Happ is left out of the catalog queries
+		let links = await bob.store.client.getAllHapps();
+		assert.equal(links.length, 0);
+		links = await bob.store.client.searchHapps('wallet');
+		assert.equal(links.length, 0);
+		links = await bob.store.client.getAllHapps(true);
+		assert.equal(links.length, 1);
+
+		// Only the maintainers of the hidden Happ still get it along with the
+		// unpublished ones
+		links = await alice.store.client.getAllHapps(true);
+		assert.equal(links.length, 0);
+		links = await alice.store.client.searchHapps('wallet', true);
+		assert.equal(links.length, 0);
+
+		const overview = await bob.store.client.getHappOverview(happ.actionHash);
+		assert.isTrue(overview!.hidden);
+
+		// The publisher can't unhide it
+		await expect(bob.store.client.unhideHapp(happ.actionHash)).rejects.toThrow();
+
+		// Once removed, Carol can't moderate anymore
+		await alice.store.client.removeModerator(carol.player.agentPubKey);
+
+		await dhtSync(
+			[alice.player, bob.player, carol.player],
+			alice.player.cells[0].cell_id[0],
+		);
+
+		await expect(carol.store.client.unhideHapp(happ.actionHash)).rejects.toThrow();
+
+		// The progenitor is a moderator as well
+		await alice.store.client.unhideHapp(happ.actionHash);
+
+		await dhtSync(
+			[alice.player, bob.player, carol.player],
+			alice.player.cells[0].cell_id[0],
+		);
+
+		links = await bob.store.client.getAllHapps();
+		assert.equal(links.length, 1);
+	});
+});
+
+test('moderator claims expire unless the progenitor keeps being active', async () => {
+	await runScenario(async scenario => {
+		const [alice, bob, carol] = await setup(scenario, 3, agents => ({
+			progenitor: encodeHashToBase64(agents[0]),
+			initial_moderators: [],
+			claim_validity_secs: 10,
+		}));
+
+		await bob.store.client.commitPublisherCredential(
+			await alice.store.client.signPublisherCredential(bob.player.agentPubKey),
+		);
+		const happ = await bob.store.client.createHapp(await bob.sampleHapp());
+		await alice.store.client.addModerator(carol.player.agentPubKey);
+
+		await dhtSync(
+			[alice.player, bob.player, carol.player],
+			alice.player.cells[0].cell_id[0],
+		);
+
+		await carol.store.client.hideHapp(happ.actionHash, 'Phishing');
+
+		// The progenitor removes Carol, who never gets to point a new claim to
+		// the removal, and her last claim expires
+		await alice.store.client.removeModerator(carol.player.agentPubKey);
+		await pause(11_000);
+
+		await expect(carol.store.client.unhideHapp(happ.actionHash)).rejects.toThrow();
+	});
+});
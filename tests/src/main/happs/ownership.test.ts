@@ -0,0 +1,174 @@
+import { dhtSync, pause, runScenario } from '@holochain/tryorama';
+import { assert, expect, test } from 'vitest';
+
+import { setup } from './setup.js';
+
+test('the ownership of a Happ is transferred when the recipient accepts the offer', async () => {
+	await runScenario(async scenario => {
+		const [alice, bob] = await setup(scenario);
+
+		// Alice creates a Happ
+		const happ = await alice.store.client.createHapp(await alice.sampleHapp());
+
+		await dhtSync([alice.player, bob.player], alice.player.cells[0].cell_id[0]);
+
+		// Bob can't offer the ownership of Alice's Happ
+		await expect(
+			bob.store.client.offerHappOwnership(
+				happ.actionHash,
+				alice.player.agentPubKey,
+			),
+		).rejects.toThrow();
+
+		// Alice offers the ownership of her Happ to Bob
+		const offer = await alice.store.client.offerHappOwnership(
+			happ.actionHash,
+			bob.player.agentPubKey,
+		);
+
+		// Only Bob can accept it
+		await expect(
+			alice.store.client.acceptHappOwnershipOffer(offer.actionHash),
+		).rejects.toThrow();
+
+		await dhtSync([alice.player, bob.player], alice.player.cells[0].cell_id[0]);
+
+		let offers = await bob.store.client.getHappOwnershipOffers(happ.actionHash);
+		assert.equal(offers.length, 1);
+
+		await bob.store.client.acceptHappOwnershipOffer(offer.actionHash);
+
+		await dhtSync([alice.player, bob.player], alice.player.cells[0].cell_id[0]);
+
+		offers = await alice.store.client.getHappOwnershipOffers(happ.actionHash);
+		assert.equal(offers.length, 0);
+
+		const owner = await alice.store.client.getHappOwner(happ.actionHash);
+		assert.deepEqual(owner, bob.player.agentPubKey);
+
+		const transfers = await alice.store.client.getHappOwnershipTransfers(
+			happ.actionHash,
+		);
+		assert.equal(transfers.length, 1);
+		assert.deepEqual(transfers[0].action.author, bob.player.agentPubKey);
+		assert.deepEqual(transfers[0].entry.offer_hash, offer.actionHash);
+
+		// The Happ moved from Alice's Happs to Bob's
+		const aliceHapps = await bob.store.client.getPublisherHapps(
+			alice.player.agentPubKey,
+		);
+		assert.equal(aliceHapps.length, 0);
+		const bobHapps = await alice.store.client.getPublisherHapps(
+			bob.player.agentPubKey,
+		);
+		assert.equal(bobHapps.length, 1);
+
+		// Alice can't cancel the offer after it was accepted
+		await expect(
+			alice.store.client.cancelHappOwnershipOffer(offer.actionHash),
+		).rejects.toThrow();
+
+		// Bob can now release the Happ, and Alice can't anymore
+		await bob.store.client.createHappRelease(
+			await bob.sampleHappRelease({
+				happ_hash: happ.actionHash,
+				version: '0.1.0',
+			}),
+		);
+		await expect(
+			alice.store.client.createHappRelease(
+				await alice.sampleHappRelease({
+					happ_hash: happ.actionHash,
+					version: '0.2.0',
+				}),
+			),
+		).rejects.toThrow();
+	});
+});
+
+test('the owner can cancel an ownership offer once it expired', async () => {
+	await runScenario(async scenario => {
+		const [alice, bob] = await setup(scenario, 2, () => ({
+			ownership_offer_validity_secs: 5,
+		}));
+
+		// Alice creates a Happ and offers its ownership to Bob
+		const happ = await alice.store.client.createHapp(await alice.sampleHapp());
+		const offer = await alice.store.client.offerHappOwnership(
+			happ.actionHash,
+			bob.player.agentPubKey,
+		);
+
+		// Bob could still accept the offer, so Alice can't cancel it yet
+		await expect(
+			alice.store.client.cancelHappOwnershipOffer(offer.actionHash),
+		).rejects.toThrow();
+
+		await pause(6_000);
+
+		// Bob is active after the offer expired, without accepting it
+		await bob.store.client.createHapp(await bob.sampleHapp());
+
+		await dhtSync([alice.player, bob.player], alice.player.cells[0].cell_id[0]);
+
+		// Bob can't accept the expired offer
+		await expect(
+			bob.store.client.acceptHappOwnershipOffer(offer.actionHash),
+		).rejects.toThrow('expired');
+
+		// Alice cancels the offer, and she is the owner again
+		await alice.store.client.cancelHappOwnershipOffer(offer.actionHash);
+		await alice.store.client.createHappRelease(
+			await alice.sampleHappRelease({
+				happ_hash: happ.actionHash,
+				version: '0.1.0',
+			}),
+		);
+
+		await dhtSync([alice.player, bob.player], alice.player.cells[0].cell_id[0]);
+
+		const owner = await bob.store.client.getHappOwner(happ.actionHash);
+		assert.deepEqual(owner, alice.player.agentPubKey);
+	});
+});
+
+test('the owner can never cancel an accepted ownership offer', async () => {
+	await runScenario(async scenario => {
+		const [alice, bob] = await setup(scenario, 2, () => ({
+			ownership_offer_validity_secs: 5,
+		}));
+
+		// Alice creates a Happ and Bob accepts its ownership
+		const happ = await alice.store.client.createHapp(await alice.sampleHapp());
+		const offer = await alice.store.client.offerHappOwnership(
+			happ.actionHash,
+			bob.player.agentPubKey,
+		);
+
+		await dhtSync([alice.player, bob.player], alice.player.cells[0].cell_id[0]);
+
+		await bob.store.client.acceptHappOwnershipOffer(offer.actionHash);
+
+		// Even after the offer expired and Bob kept being active, the acceptance
+		// is in his chain before any of his actions that Alice could point to
+		await pause(6_000);
+		await bob.store.client.createHapp(await bob.sampleHapp());
+
+		await dhtSync([alice.player, bob.player], alice.player.cells[0].cell_id[0]);
+
+		await expect(
+			alice.store.client.cancelHappOwnershipOffer(offer.actionHash),
+		).rejects.toThrow();
+
+		const owner = await alice.store.client.getHappOwner(happ.actionHash);
+		assert.deepEqual(owner, bob.player.agentPubKey);
+		await expect(
+			alice.store.client.createHappRelease(
+				await alice.sampleHappRelease({
+					happ_hash: happ.actionHash,
+					version: '0.1.0',
+				}),
+			),
+		).rejects.toThrow();
+	});
+});
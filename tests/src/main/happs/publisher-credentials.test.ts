@@ -0,0 +1,88 @@
+import { encodeHashToBase64 } from '@holochain/client';
+import { dhtSync, runScenario } from '@holochain/tryorama';
+import { assert, expect, test } from 'vitest';
+
+import { publisherCredentialMembraneProof } from '../../../../packages/happs/src/publisher-credentials.js';
+import { HappsProperties } from '../../../../packages/happs/src/types.js';
+import { addPlayerWithMembraneProof, setup } from './setup.js';
+
+test('only agents vetted by the progenitor can publish', async () => {
+	await runScenario(async scenario => {
+		let properties: HappsProperties = {};
+		const [alice, bob] = await setup(scenario, 2, agents => {
+			properties = {
+				progenitor: encodeHashToBase64(agents[0]),
+				initial_moderators: [],
+			};
+			return properties;
+		});
+
+		// The progenitor can always publish
+		assert.isTrue(await alice.store.client.canPublish());
+		const happ = await alice.store.client.createHapp(await alice.sampleHapp());
+		const release = await alice.store.client.createHappRelease(
+			await alice.sampleHappRelease({
+				happ_hash: happ.actionHash,
+				version: '0.1.0',
+			}),
+		);
+
+		// Bob joined without a credential: he can browse and review, but not publish
+		assert.isFalse(await bob.store.client.canPublish());
+		await expect(
+			bob.store.client.createHapp(await bob.sampleHapp()),
+		).rejects.toThrow();
+
+		await dhtSync([alice.player, bob.player], alice.player.cells[0].cell_id[0]);
+
+		const links = await bob.store.client.getAllHapps();
+		assert.equal(links.length, 1);
+		await bob.store.client.createReview({
+			happ_hash: happ.actionHash,
+			happ_release_hash: release.actionHash,
+			rating: 4,
+			text: 'Nice',
+		});
+
+		// Only the progenitor can issue credentials
+		await expect(
+			bob.store.client.signPublisherCredential(bob.player.agentPubKey),
+		).rejects.toThrow();
+
+		// Bob commits the credential that the progenitor issued for him
+		const credential = await alice.store.client.signPublisherCredential(
+			bob.player.agentPubKey,
+		);
+		await bob.store.client.commitPublisherCredential(credential);
+		assert.isTrue(await bob.store.client.canPublish());
+		await bob.store.client.createHapp(await bob.sampleHapp());
+
+		// Credentials are only valid for the agent they were issued for
+		const aliceCredential = await alice.store.client.signPublisherCredential(
+			alice.player.agentPubKey,
+		);
+		await expect(
+			bob.store.client.commitPublisherCredential(aliceCredential),
+		).rejects.toThrow();
+
+		// Carol joins with her credential as membrane proof, and can publish right away
+		const carol = await addPlayerWithMembraneProof(
+			scenario,
+			properties,
+			async agent =>
+				publisherCredentialMembraneProof(
+					await alice.store.client.signPublisherCredential(agent),
+				),
+		);
+		assert.isTrue(await carol.store.client.canPublish());
+		const carolHapp = await carol.store.client.createHapp(
+			await carol.sampleHapp(),
+		);
+		await carol.store.client.createHappRelease(
+			await carol.sampleHappRelease({
+				happ_hash: carolHapp.actionHash,
+				version: '0.1.0',
+			}),
+		);
+	});
+});
@@ -5,7 +5,6 @@ import { dhtSync, runScenario } from '@holochain/tryorama';
 import { decode } from '@msgpack/msgpack';
 import { assert, test } from 'vitest';
 
-import { sampleHapp } from '../../../../packages/happs/src/mocks.js';
 import { Happ } from '../../../../packages/happs/src/types.js';
 import { setup } from './setup.js';
 
@@ -21,7 +20,7 @@ test('create a Happ and get publisher happs', async () => {
 
 		// Alice creates a Happ
 		const happ: EntryRecord<Happ> = await alice.store.client.createHapp(
-			await sampleHapp(alice.store.client),
+			await alice.sampleHapp(),
 		);
 		assert.ok(happ);
 
@@ -35,3 +34,50 @@ test('create a Happ and get publisher happs', async () => {
 		assert.deepEqual(happ.actionHash, Array.from(collectionOutput.keys())[0]);
 	});
 });
+
+test('unpublished happs are left out of the catalog queries', async () => {
+	await runScenario(async scenario => {
+		const [alice, bob] = await setup(scenario);
+
+		// Alice creates two Happs and unpublishes one of them
+		const published: EntryRecord<Happ> = await alice.store.client.createHapp(
+			await alice.sampleHapp({ name: 'Published' }),
+		);
+		const unpublished: EntryRecord<Happ> =
+			await alice.store.client.createHapp(
+				await alice.sampleHapp({ name: 'Unpublished' }),
+			);
+		await alice.store.client.unpublishHapp(unpublished.actionHash);
+
+		await dhtSync([alice.player, bob.player], alice.player.cells[0].cell_id[0]);
+
+		// Bob only sees the published Happ
+		let links = await bob.store.client.getAllHapps();
+		assert.deepEqual(
+			links.map(l => l.target),
+			[published.actionHash],
+		);
+		links = await bob.store.client.getPublisherHapps(alice.player.agentPubKey);
+		assert.deepEqual(
+			links.map(l => l.target),
+			[published.actionHash],
+		);
+		const page = await bob.store.client.getHappsPage('Name', 10, undefined);
+		assert.deepEqual(
+			page.happs.map(h => h.original_happ_hash),
+			[published.actionHash],
+		);
+
+		// Alice still sees all her Happs when managing them
+		links = await alice.store.client.getPublisherHapps(
+			alice.player.agentPubKey,
+			true,
+		);
+		assert.sameDeepMembers(
+			links.map(l => l.target),
+			[published.actionHash, unpublished.actionHash],
+		);
+		links = await alice.store.client.getAllHapps(true);
+		assert.equal(links.length, 2);
+	});
+});
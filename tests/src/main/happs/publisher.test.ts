@@ -0,0 +1,126 @@
+import { cleanNodeDecoding } from '@darksoil-studio/holochain-utils/dist/clean-node-decoding.js';
+import { dhtSync, runScenario } from '@holochain/tryorama';
+import { createServer } from 'http';
+import { AddressInfo } from 'net';
+import { assert, expect, test } from 'vitest';
+
+import {
+	DOMAIN_CLAIM_WELL_KNOWN_PATH,
+	DomainClaimWellKnownFile,
+	domainClaimWellKnownEntry,
+	verifyDomainClaim,
+} from '../../../../packages/happs/src/domain-claims.js';
+import { Publisher } from '../../../../packages/happs/src/types.js';
+import { setup, uploadIcon } from './setup.js';
+
+test('create, update and look up a Publisher', async () => {
+	await runScenario(async scenario => {
+		const [alice, bob] = await setup(scenario);
+
+		const publisher: Publisher = {
+			display_name: 'Alice',
+			avatar: await uploadIcon(alice.fileStorage),
+			url: 'https://alice.example.com',
+			bio: 'I make hApps',
+		};
+		const record = await alice.store.client.createPublisher(publisher);
+
+		// Each agent has only one publisher profile
+		await expect(alice.store.client.createPublisher(publisher)).rejects.toThrow();
+
+		await dhtSync([alice.player, bob.player], alice.player.cells[0].cell_id[0]);
+
+		let found = await bob.store.client.getPublisherForAgent(
+			alice.player.agentPubKey,
+		);
+		assert.deepEqual(cleanNodeDecoding(found!.entry), publisher);
+
+		// Bob can't update Alice's profile
+		await expect(
+			bob.store.client.updatePublisher(record.actionHash, record.actionHash, {
+				...publisher,
+				display_name: 'Not Alice',
+			}),
+		).rejects.toThrow();
+
+		await alice.store.client.updatePublisher(
+			record.actionHash,
+			record.actionHash,
+			{
+				...publisher,
+				bio: 'I make great hApps',
+			},
+		);
+
+		await dhtSync([alice.player, bob.player], alice.player.cells[0].cell_id[0]);
+
+		found = await bob.store.client.getPublisherForAgent(
+			alice.player.agentPubKey,
+		);
+		assert.equal(found!.entry.bio, 'I make great hApps');
+
+		assert.isUndefined(
+			await alice.store.client.getPublisherForAgent(bob.player.agentPubKey),
+		);
+
+		await expect(
+			bob.store.client.createPublisher({
+				...publisher,
+				display_name: '',
+			}),
+		).rejects.toThrow("The display name of the publisher can't be empty.");
+	});
+});
+
+test('domain claims are verified against the .well-known file of the domain', async () => {
+	await runScenario(async scenario => {
+		const [alice, bob] = await setup(scenario);
+
+		const claim = await alice.store.client.claimDomain('alice.example.com');
+
+		await expect(
+			alice.store.client.claimDomain('https://alice.example.com'),
+		).rejects.toThrow();
+
+		await dhtSync([alice.player, bob.player], alice.player.cells[0].cell_id[0]);
+
+		const claims = await bob.store.client.getDomainClaims(
+			alice.player.agentPubKey,
+		);
+		assert.equal(claims.length, 1);
+
+		// Serve the .well-known file of the domain locally
+		let wellKnownFile: DomainClaimWellKnownFile = { publishers: [] };
+		const server = createServer((req, res) => {
+			if (req.url !== DOMAIN_CLAIM_WELL_KNOWN_PATH) {
+				res.writeHead(404).end();
+				return;
+			}
+			res.writeHead(200, { 'Content-Type': 'application/json' });
+			res.end(JSON.stringify(wellKnownFile));
+		});
+		await new Promise<void>(resolve => server.listen(0, resolve));
+		const port = (server.address() as AddressInfo).port;
+		const fetchLocalFile = async (domain: string) => {
+			assert.equal(domain, 'alice.example.com');
+			const response = await fetch(
+				`http://localhost:${port}${DOMAIN_CLAIM_WELL_KNOWN_PATH}`,
+			);
+			return response.json();
+		};
+
+		try {
+			// The domain doesn't list Alice yet
+			assert.isFalse(await verifyDomainClaim(claims[0], fetchLocalFile));
+
+			wellKnownFile = { publishers: [domainClaimWellKnownEntry(claim)] };
+			assert.isTrue(await verifyDomainClaim(claims[0], fetchLocalFile));
+
+			// Bob can't reuse Alice's signature
+			const bobClaim = await bob.store.client.claimDomain('alice.example.com');
+			assert.isFalse(await verifyDomainClaim(bobClaim, fetchLocalFile));
+		} finally {
+			server.close();
+		}
+	});
+});
@@ -0,0 +1,92 @@
+import { encodeHashToBase64 } from '@holochain/client';
+import { dhtSync, runScenario } from '@holochain/tryorama';
+import { assert, expect, test } from 'vitest';
+
+import { setup } from './setup.js';
+
+test('moderators resolve the reports of abuse', async () => {
+	await runScenario(async scenario => {
+		const [alice, bob, carol] = await setup(scenario, 3, agents => ({
+			initial_moderators: [encodeHashToBase64(agents[2])],
+		}));
+
+		// Alice publishes a Happ with a release
+		const happ = await alice.store.client.createHapp(await alice.sampleHapp());
+		const release = await alice.store.client.createHappRelease(
+			await alice.sampleHappRelease({
+				happ_hash: happ.actionHash,
+				version: '0.1.0',
+			}),
+		);
+
+		await dhtSync(
+			[alice.player, bob.player, carol.player],
+			alice.player.cells[0].cell_id[0],
+		);
+
+		// Anyone can report a Happ, or one of its releases
+		const report = await bob.store.client.createReport({
+			happ_hash: happ.actionHash,
+			happ_release_hash: release.actionHash,
+			reason: 'Malware',
+			text: 'It mines crypto in the background',
+		});
+		await bob.store.client.createReport({
+			happ_hash: happ.actionHash,
+			happ_release_hash: undefined,
+			reason: 'Spam',
+			text: '',
+		});
+
+		await dhtSync(
+			[alice.player, bob.player, carol.player],
+			alice.player.cells[0].cell_id[0],
+		);
+
+		assert.deepEqual(await alice.store.client.getModerators(), [
+			carol.player.agentPubKey,
+		]);
+
+		let reports = await carol.store.client.getReportsForHapp(happ.actionHash);
+		assert.equal(reports.length, 2);
+		let openReports = await carol.store.client.getOpenReports();
+		assert.equal(openReports.length, 2);
+
+		// Only moderators can resolve reports
+		await expect(
+			alice.store.client.resolveReport(report.actionHash, 'Dismissed', ''),
+		).rejects.toThrow();
+
+		await carol.store.client.resolveReport(
+			report.actionHash,
+			'Upheld',
+			'Confirmed the miner in the release',
+		);
+
+		await dhtSync(
+			[alice.player, bob.player, carol.player],
+			alice.player.cells[0].cell_id[0],
+		);
+
+		openReports = await bob.store.client.getOpenReports();
+		assert.equal(openReports.length, 1);
+		reports = await bob.store.client.getReportsForHapp(happ.actionHash);
+		assert.equal(reports.length, 2);
+
+		const resolutions = await bob.store.client.getReportResolutions(
+			report.actionHash,
+		);
+		assert.equal(resolutions.length, 1);
+		assert.equal(resolutions[0].entry.decision, 'Upheld');
+		assert.deepEqual(resolutions[0].action.author, carol.player.agentPubKey);
+
+		// A resolved report can't be resolved again
+		await expect(
+			carol.store.client.resolveReport(
+				report.actionHash,
+				'Dismissed',
+				'Changed my mind',
+			),
+		).rejects.toThrow('The report was already resolved');
+	});
+});
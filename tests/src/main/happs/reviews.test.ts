@@ -0,0 +1,143 @@
+import { dhtSync, runScenario } from '@holochain/tryorama';
+import { assert, expect, test } from 'vitest';
+
+import { setup } from './setup.js';
+
+test('users review a Happ and the publisher replies', async () => {
+	await runScenario(async scenario => {
+		const [alice, bob, carol] = await setup(scenario, 3);
+
+		// Alice publishes a Happ with a release
+		const happ = await alice.store.client.createHapp(await alice.sampleHapp());
+		const release = await alice.store.client.createHappRelease(
+			await alice.sampleHappRelease({
+				happ_hash: happ.actionHash,
+				version: '0.1.0',
+			}),
+		);
+
+		await dhtSync(
+			[alice.player, bob.player, carol.player],
+			alice.player.cells[0].cell_id[0],
+		);
+
+		// The publisher can't review their own Happ
+		await expect(
+			alice.store.client.createReview({
+				happ_hash: happ.actionHash,
+				happ_release_hash: release.actionHash,
+				rating: 5,
+				text: 'The best',
+			}),
+		).rejects.toThrow();
+
+		await expect(
+			bob.store.client.createReview({
+				happ_hash: happ.actionHash,
+				happ_release_hash: release.actionHash,
+				rating: 6,
+				text: '',
+			}),
+		).rejects.toThrow('The rating of a review must be between 1 and 5 stars.');
+
+		const bobReview = await bob.store.client.createReview({
+			happ_hash: happ.actionHash,
+			happ_release_hash: release.actionHash,
+			rating: 2,
+			text: 'Crashes a lot',
+		});
+		await carol.store.client.createReview({
+			happ_hash: happ.actionHash,
+			happ_release_hash: release.actionHash,
+			rating: 5,
+			text: 'Love it',
+		});
+
+		// Only one active review per agent
+		await expect(
+			bob.store.client.createReview({
+				happ_hash: happ.actionHash,
+				happ_release_hash: release.actionHash,
+				rating: 1,
+				text: 'Still crashes',
+			}),
+		).rejects.toThrow();
+
+		await dhtSync(
+			[alice.player, bob.player, carol.player],
+			alice.player.cells[0].cell_id[0],
+		);
+
+		let summary = await alice.store.client.getRatingSummary(happ.actionHash);
+		assert.equal(summary.reviews_count, 2);
+		assert.equal(summary.average_rating, 3.5);
+		assert.deepEqual(summary.histogram, [0, 1, 0, 0, 1]);
+
+		// Updating the review updates the summary
+		await bob.store.client.updateReview(
+			bobReview.actionHash,
+			bobReview.actionHash,
+			{ ...bobReview.entry, rating: 4, text: 'Fixed now' },
+		);
+
+		// Alice replies once to Bob's review
+		await alice.store.client.createReviewReply({
+			review_hash: bobReview.actionHash,
+			text: 'Thanks for the report!',
+		});
+		await expect(
+			alice.store.client.createReviewReply({
+				review_hash: bobReview.actionHash,
+				text: 'Again',
+			}),
+		).rejects.toThrow();
+		await expect(
+			carol.store.client.createReviewReply({
+				review_hash: bobReview.actionHash,
+				text: 'Not the publisher',
+			}),
+		).rejects.toThrow();
+
+		await dhtSync(
+			[alice.player, bob.player, carol.player],
+			alice.player.cells[0].cell_id[0],
+		);
+
+		summary = await carol.store.client.getRatingSummary(happ.actionHash);
+		assert.equal(summary.average_rating, 4.5);
+		assert.deepEqual(summary.histogram, [0, 0, 0, 1, 1]);
+
+		const replies = await bob.store.client.getReviewReplies(
+			bobReview.actionHash,
+		);
+		assert.equal(replies.length, 1);
+		assert.equal(replies[0].entry.text, 'Thanks for the report!');
+
+		// Deleting the review lets Bob review again
+		await bob.store.client.deleteReview(bobReview.actionHash);
+		const newBobReview = await bob.store.client.createReview({
+			happ_hash: happ.actionHash,
+			happ_release_hash: release.actionHash,
+			rating: 3,
+			text: 'Meh',
+		});
+
+		await dhtSync(
+			[alice.player, bob.player, carol.player],
+			alice.player.cells[0].cell_id[0],
+		);
+
+		summary = await alice.store.client.getRatingSummary(happ.actionHash);
+		assert.equal(summary.reviews_count, 2);
+		assert.equal(summary.average_rating, 4);
+
+		// Bob can't link his review twice to count it twice
+		await expect(
+			bob.player.cells[0].callZome({
+				zome_name: 'happs',
+				fn_name: 'create_happ_reviews_link',
+				payload: newBobReview.actionHash,
+			}),
+		).rejects.toThrow('one review to each hApp at a time');
+	});
+});
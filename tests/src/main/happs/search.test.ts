@@ -0,0 +1,136 @@
+import { dhtSync, runScenario } from '@holochain/tryorama';
+import { assert, expect, test } from 'vitest';
+
+import { setup } from './setup.js';
+
+test('happs are found by the words in their names and descriptions', async () => {
+	await runScenario(async scenario => {
+		const [alice, bob] = await setup(scenario);
+
+		const chess = await alice.store.client.createHapp(
+			await alice.sampleHapp({
+				name: 'Chess',
+				description: 'Play chess with your friends',
+			}),
+		);
+		const chessClub = await alice.store.client.createHapp(
+			await alice.sampleHapp({
+				name: 'Chess Club',
+				description: 'Organize tournaments for your club',
+			}),
+		);
+		const board = await alice.store.client.createHapp(
+			await alice.sampleHapp({
+				name: 'Board games',
+				description: 'Checkers, chess and more classic games',
+			}),
+		);
+
+		await dhtSync([alice.player, bob.player], alice.player.cells[0].cell_id[0]);
+
+		// The exact name match comes first, then the name matches, and then the
+		// description matches
+		let links = await bob.store.client.searchHapps('Chess');
+		assert.deepEqual(
+			links.map(l => l.target),
+			[chess.actionHash, chessClub.actionHash, board.actionHash],
+		);
+
+		links = await bob.store.client.searchHapps('chess club');
+		assert.deepEqual(links[0].target, chessClub.actionHash);
+
+		// Words are matched by their prefix
+		links = await bob.store.client.searchHapps('tourn');
+		assert.deepEqual(links.map(l => l.target), [chessClub.actionHash]);
+
+		links = await bob.store.client.searchHapps('poker');
+		assert.equal(links.length, 0);
+
+		// Queries shorter than the indexed prefixes match the words of the names
+		links = await bob.store.client.searchHapps('ch');
+		assert.sameDeepMembers(
+			links.map(l => l.target),
+			[chess.actionHash, chessClub.actionHash],
+		);
+		links = await bob.store.client.searchHapps('b');
+		assert.deepEqual(links.map(l => l.target), [board.actionHash]);
+
+		// Updating the hApp removes the stale words from the index
+		await alice.store.client.updateHapp(
+			board.actionHash,
+			board.actionHash,
+			await alice.sampleHapp({
+				name: 'Board games',
+				description: 'Checkers and more classic games',
+			}),
+		);
+
+		await dhtSync([alice.player, bob.player], alice.player.cells[0].cell_id[0]);
+
+		links = await bob.store.client.searchHapps('chess');
+		assert.equal(links.length, 2);
+		links = await bob.store.client.searchHapps('checkers');
+		assert.deepEqual(links.map(l => l.target), [board.actionHash]);
+
+		// Unpublished happs are left out unless asked for
+		await alice.store.client.unpublishHapp(chess.actionHash);
+
+		await dhtSync([alice.player, bob.player], alice.player.cells[0].cell_id[0]);
+
+		links = await bob.store.client.searchHapps('chess');
+		assert.deepEqual(links.map(l => l.target), [chessClub.actionHash]);
+		links = await bob.store.client.searchHapps('chess', true);
+		assert.equal(links.length, 2);
+	});
+});
+
+test('long words are truncated in the search index', async () => {
+	await runScenario(async scenario => {
+		const [alice, bob] = await setup(scenario);
+
+		// 64 characters of 3 bytes each, longer than the indexed tokens
+		const name = '漢'.repeat(64);
+		const happ = await alice.store.client.createHapp(
+			await alice.sampleHapp({ name }),
+		);
+
+		await dhtSync([alice.player, bob.player], alice.player.cells[0].cell_id[0]);
+
+		const links = await bob.store.client.searchHapps(name);
+		assert.deepEqual(links.map(l => l.target), [happ.actionHash]);
+	});
+});
+
+test('maintainers can only index the words of their latest revision', async () => {
+	await runScenario(async scenario => {
+		const [alice] = await setup(scenario);
+
+		const happ = await alice.store.client.createHapp(
+			await alice.sampleHapp({ name: 'Chess' }),
+		);
+
+		// Alice tries to make her Happ show up in searches for another word
+		await expect(
+			alice.player.cells[0].callZome({
+				zome_name: 'happs',
+				fn_name: 'create_search_index_link',
+				payload: {
+					happ_hash: happ.actionHash,
+					field: 'Name',
+					token: 'wallet',
+				},
+			}),
+		).rejects.toThrow('search tokens of its latest revision');
+
+		// The words of the Happ can still be indexed
+		await alice.player.cells[0].callZome({
+			zome_name: 'happs',
+			fn_name: 'create_search_index_link',
+			payload: {
+				happ_hash: happ.actionHash,
+				field: 'Name',
+				token: 'chess',
+			},
+		});
+	});
+});
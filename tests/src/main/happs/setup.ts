@@ -1,4 +1,15 @@
-import { HappsClient, HappsStore } from '@darksoil-studio/happs-zome';
+import { FileStorageClient } from '@darksoil-studio/file-storage-zome';
+import {
+	Happ,
+	HappRelease,
+	HappsClient,
+	HappsProperties,
+	HappsStore,
+} from '@darksoil-studio/happs-zome';
+import {
+	sampleHapp,
+	sampleHappRelease,
+} from '@darksoil-studio/happs-zome/dist/mocks.js';
 import { EntryRecord } from '@darksoil-studio/holochain-utils';
 import {
 	ActionHash,
@@ -8,26 +19,73 @@ import {
 	EntryHash,
 	NewEntryAction,
 	Record,
+	RoleSettingsMap,
 	encodeHashToBase64,
 	fakeActionHash,
 	fakeAgentPubKey,
 	fakeDnaHash,
 	fakeEntryHash,
 } from '@holochain/client';
-import { Player, PlayerApp, Scenario, pause } from '@holochain/tryorama';
+import {
+	Conductor,
+	Player,
+	PlayerApp,
+	Scenario,
+	enableAndGetAgentApp,
+	pause,
+} from '@holochain/tryorama';
 import { encode } from '@msgpack/msgpack';
 
-import { appPath } from '../../app-path.js';
+import { appPath, fileStorageProviderAppPath } from '../../app-path.js';
 
-export async function setup(scenario: Scenario, numPlayers = 2) {
-	const players = await scenario.addPlayersWithSameApp(
-		{
-			appBundleSource: { type: 'path', value: appPath },
-		},
-		numPlayers,
-	);
+/**
+ * Sets up the players of the scenario, along with a file storage provider
+ *
+ * The properties of the DNA can depend on the agent keys of the players, which
+ * are generated before installing the app
+ */
+export async function setup(
+	scenario: Scenario,
+	numPlayers = 2,
+	dnaProperties?: (agents: Array<AgentPubKey>) => HappsProperties,
+) {
+	let players: Array<PlayerApp>;
+	let rolesSettings: RoleSettingsMap | undefined;
+	if (!dnaProperties) {
+		players = await scenario.addPlayersWithSameApp(
+			{
+				appBundleSource: { type: 'path', value: appPath },
+			},
+			numPlayers,
+		);
+	} else {
+		const conductors = await Promise.all(
+			Array.from({ length: numPlayers }, () => scenario.addConductor()),
+		);
+		const agents = await Promise.all(
+			conductors.map(conductor => conductor.adminWs().generateAgentPubKey()),
+		);
+		const settings: RoleSettingsMap = {
+			main: {
+				type: 'provisioned',
+				value: { modifiers: { properties: dnaProperties(agents) } },
+			},
+		};
+		rolesSettings = settings;
+		players = await Promise.all(
+			conductors.map((conductor, i) =>
+				installPlayer(conductor, agents[i], settings),
+			),
+		);
+	}
 	const playersAndStores = await Promise.all(players.map(setupStore));
 
+	// The icons and the bundles of the hApps need a provider to be stored
+	await scenario.addPlayerWithApp({
+		appBundleSource: { type: 'path', value: fileStorageProviderAppPath },
+		options: { rolesSettings },
+	});
+
 	// Shortcut peer discovery through gossip and register all agents in every
 	// conductor of the scenario.
 	await scenario.shareAllAgents();
@@ -35,15 +93,78 @@ export async function setup(scenario: Scenario, numPlayers = 2) {
 	return playersAndStores;
 }
 
+/**
+ * Adds a player that joins the network of the scenario with the membrane proof
+ * built for its agent key, to be called after `setup`
+ */
+export async function addPlayerWithMembraneProof(
+	scenario: Scenario,
+	dnaProperties: HappsProperties,
+	membraneProof: (agent: AgentPubKey) => Promise<Uint8Array>,
+) {
+	const conductor = await scenario.addConductor();
+	const agent = await conductor.adminWs().generateAgentPubKey();
+	const player = await installPlayer(conductor, agent, {
+		main: {
+			type: 'provisioned',
+			value: {
+				membrane_proof: await membraneProof(agent),
+				modifiers: { properties: dnaProperties },
+			},
+		},
+	});
+	const playerAndStore = await setupStore(player);
+	await scenario.shareAllAgents();
+	return playerAndStore;
+}
+
+async function installPlayer(
+	conductor: Conductor,
+	agentPubKey: AgentPubKey,
+	rolesSettings: RoleSettingsMap,
+): Promise<PlayerApp> {
+	const appInfo = await conductor.installApp({
+		appBundleSource: { type: 'path', value: appPath },
+		options: { agentPubKey, rolesSettings },
+	});
+	const adminWs = conductor.adminWs();
+	const port = await conductor.attachAppInterface();
+	const issued = await adminWs.issueAppAuthenticationToken({
+		installed_app_id: appInfo.installed_app_id,
+	});
+	const appWs = await conductor.connectAppWs(issued.token, port);
+	const agentApp = await enableAndGetAgentApp(adminWs, appWs, appInfo);
+	return { conductor, appWs, ...agentApp };
+}
+
 async function setupStore(player: PlayerApp) {
 	patchCallZome(player.appWs as AppWebsocket);
 	await player.conductor
 		.adminWs()
 		.authorizeSigningCredentials(player.cells[0].cell_id);
 	const store = new HappsStore(new HappsClient(player.appWs as any, 'main'));
+	const fileStorage = new FileStorageClient(player.appWs as any, 'main');
+	const sampleHappWithIcon = async (partialHapp: Partial<Happ> = {}) =>
+		sampleHapp(store.client, {
+			icon: await uploadIcon(fileStorage),
+			...partialHapp,
+		});
 	return {
 		store,
 		player,
+		fileStorage,
+		sampleHapp: sampleHappWithIcon,
+		sampleHappRelease: async (
+			partialHappRelease: Partial<HappRelease> = {},
+		) =>
+			sampleHappRelease(store.client, {
+				happ_hash:
+					partialHappRelease.happ_hash ||
+					(await store.client.createHapp(await sampleHappWithIcon()))
+						.actionHash,
+				web_happ_bundle_hash: await uploadWebHappBundle(fileStorage),
+				...partialHappRelease,
+			}),
 		startUp: async () => {
 			await player.conductor.startUp();
 			const port = await player.conductor.attachAppInterface();
@@ -55,10 +176,26 @@ async function setupStore(player: PlayerApp) {
 			const appWs = await player.conductor.connectAppWs(issued.token, port);
 			patchCallZome(appWs);
 			store.client.client = appWs;
+			fileStorage.client = appWs;
 		},
 	};
 }
 
+export function uploadIcon(fileStorage: FileStorageClient): Promise<EntryHash> {
+	return fileStorage.uploadFile(
+		new File([new Uint8Array([137, 80, 78, 71, 13, 10, 26, 10])], 'icon.png', {
+			type: 'image/png',
+		}),
+	);
+}
+
+export function uploadWebHappBundle(
+	fileStorage: FileStorageClient,
+	contents = new Uint8Array(32),
+): Promise<EntryHash> {
+	return fileStorage.uploadFile(new File([contents], 'sample.webhapp'));
+}
+
 function patchCallZome(appWs: AppWebsocket) {
 	const callZome = appWs.callZome;
 	appWs.callZome = async req => {
@@ -65,6 +65,7 @@ export class PublisherDashboard extends SignalWatcher(LitElement) {
 				<publisher-happs
 					style="flex: 1"
 					.author=${this.client.myPubKey}
+					include-unpublished
 				></publisher-happs>
 			</div>
 		`;